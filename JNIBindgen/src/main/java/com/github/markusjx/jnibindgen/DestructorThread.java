@@ -60,7 +60,13 @@ public class DestructorThread {
                         while (true) {
                             try {
                                 Destructor current = (Destructor) sReferenceQueue.remove();
-                                current.destruct();
+                                try {
+                                    current.destruct();
+                                } catch (RuntimeException e) {
+                                    // Keep destructing the other objects, e.g.
+                                    // if a Rust destructor has panicked
+                                    getUncaughtExceptionHandler().uncaughtException(this, e);
+                                }
 
                                 // If current is in the sDestructorStack,
                                 // transfer all the Destructors in the stack to the list.
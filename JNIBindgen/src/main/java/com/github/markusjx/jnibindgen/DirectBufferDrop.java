@@ -0,0 +1,25 @@
+package com.github.markusjx.jnibindgen;
+
+import java.nio.ByteBuffer;
+
+/**
+ * Releases the native memory of direct buffers created from Rust owned memory once the buffer has
+ * been garbage collected.
+ */
+public final class DirectBufferDrop {
+    private DirectBufferDrop() {
+        throw new UnsupportedOperationException("DirectBufferDrop cannot be instantiated");
+    }
+
+    /**
+     * Register a direct buffer whose memory is owned by Rust.
+     *
+     * @param buffer The buffer to watch
+     * @param ptr The pointer to the Rust owner of the memory
+     */
+    public static void register(ByteBuffer buffer, long ptr) {
+        new NativeDrop(buffer, () -> drop(ptr));
+    }
+
+    private static native void drop(long ptr);
+}
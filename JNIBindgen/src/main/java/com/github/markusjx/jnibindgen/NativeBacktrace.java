@@ -0,0 +1,13 @@
+package com.github.markusjx.jnibindgen;
+
+/**
+ * The backtrace of the native code an exception has been created in.
+ * This is added as a suppressed exception to exceptions thrown by the
+ * native code if the {@code backtrace} feature is enabled. The stack
+ * trace of this exception contains the native frames.
+ */
+public class NativeBacktrace extends RuntimeException {
+    public NativeBacktrace(String message) {
+        super(message, null, false, true);
+    }
+}
@@ -0,0 +1,12 @@
+package com.github.markusjx.jnibindgen;
+
+/**
+ * An exception thrown if the native code panicked.
+ * The message contains the panic message and the
+ * location in the native code the panic occurred at.
+ */
+public class NativePanicException extends RuntimeException {
+    public NativePanicException(String message) {
+        super(message);
+    }
+}
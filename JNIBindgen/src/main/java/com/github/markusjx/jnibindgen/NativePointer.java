@@ -0,0 +1,23 @@
+package com.github.markusjx.jnibindgen;
+
+/**
+ * A pointer to a native object which has been created by native code. This is passed to the
+ * generated classes in order to wrap an existing native object, e.g. one which has been returned
+ * from a native method.
+ */
+public final class NativePointer {
+    private final long ptr;
+
+    public NativePointer(long ptr) {
+        this.ptr = ptr;
+    }
+
+    /**
+     * Get the pointer to the native object.
+     *
+     * @return The pointer
+     */
+    public long getPtr() {
+        return ptr;
+    }
+}
@@ -0,0 +1,172 @@
+use crate::class_file::ClassFile;
+use jni_bindgen_codegen::codegen::signature::{jni_symbol, JniSignature, SignatureKind};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+/// A difference between the code generated by the
+/// macros and the compiled java classes.
+pub struct Mismatch {
+    class_name: String,
+    method_name: Option<String>,
+    message: String,
+}
+
+impl Mismatch {
+    fn new(class_name: &str, method_name: Option<&str>, message: String) -> Self {
+        Self {
+            class_name: class_name.replace('/', "."),
+            method_name: method_name.map(str::to_string),
+            message,
+        }
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.method_name {
+            Some(method_name) => write!(f, "{}.{method_name}: {}", self.class_name, self.message),
+            None => write!(f, "{}: {}", self.class_name, self.message),
+        }
+    }
+}
+
+/// Compare the signatures of the generated code with the compiled classes.
+/// Reports every method which is missing or declared differently in the
+/// classes, every exported function which is named differently than the
+/// function the JVM looks up, every native method of a generated class
+/// without an implementation and every overloaded native method.
+pub fn check(signatures: &[JniSignature], classes: &[ClassFile]) -> Vec<Mismatch> {
+    let classes = classes
+        .iter()
+        .map(|c| (c.name.as_str(), c))
+        .collect::<HashMap<_, _>>();
+
+    let mut res = Vec::new();
+    let mut missing_classes = HashSet::new();
+    for signature in signatures {
+        let class_name = signature.class_name.as_str();
+        let method_name = Some(signature.method_name.as_str());
+        let Some(class) = classes.get(class_name) else {
+            if missing_classes.insert(class_name) {
+                res.push(Mismatch::new(class_name, None, "Class not found".into()));
+            }
+
+            continue;
+        };
+
+        let methods = class
+            .methods
+            .iter()
+            .filter(|m| m.name == signature.method_name)
+            .collect::<Vec<_>>();
+        if methods.is_empty() {
+            res.push(Mismatch::new(
+                class_name,
+                method_name,
+                format!("Method with descriptor {} not found", signature.descriptor),
+            ));
+            continue;
+        }
+
+        let Some(method) = methods
+            .iter()
+            .find(|m| m.descriptor == signature.descriptor)
+        else {
+            let found = methods
+                .iter()
+                .map(|m| m.descriptor.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            res.push(Mismatch::new(
+                class_name,
+                method_name,
+                format!(
+                    "Expected descriptor {}, found {found}",
+                    signature.descriptor
+                ),
+            ));
+            continue;
+        };
+
+        let SignatureKind::Native {
+            symbol,
+            static_method,
+        } = &signature.kind
+        else {
+            continue;
+        };
+
+        if !method.is_native() {
+            res.push(Mismatch::new(
+                class_name,
+                method_name,
+                "Method is not declared native".into(),
+            ));
+        } else if method.is_static() != *static_method {
+            let expected = if *static_method {
+                "static"
+            } else {
+                "an instance"
+            };
+            res.push(Mismatch::new(
+                class_name,
+                method_name,
+                format!("Expected {expected} method"),
+            ));
+        }
+
+        let expected = jni_symbol(class_name, &signature.method_name);
+        if *symbol != expected {
+            res.push(Mismatch::new(
+                class_name,
+                method_name,
+                format!("The generated function {symbol} does not match the symbol {expected}"),
+            ));
+        }
+    }
+
+    // Native methods of the generated classes which would
+    // throw an UnsatisfiedLinkError once they are called
+    let implemented = signatures
+        .iter()
+        .filter(|s| matches!(s.kind, SignatureKind::Native { .. }))
+        .map(|s| (s.class_name.as_str(), s.method_name.as_str()))
+        .collect::<HashSet<_>>();
+    let mut checked = Vec::new();
+    for signature in signatures {
+        if !checked.contains(&signature.class_name.as_str()) {
+            checked.push(signature.class_name.as_str());
+        }
+    }
+
+    for class in checked.iter().filter_map(|name| classes.get(name)) {
+        let mut overloads = HashMap::<&str, usize>::new();
+        for method in class.methods.iter().filter(|m| m.is_native()) {
+            *overloads.entry(method.name.as_str()).or_default() += 1;
+            if !implemented.contains(&(class.name.as_str(), method.name.as_str())) {
+                res.push(Mismatch::new(
+                    &class.name,
+                    Some(&method.name),
+                    "Native method is not implemented".into(),
+                ));
+            }
+        }
+
+        // The JVM would look up the same function for all overloads, as
+        // the generated functions are named without the argument types
+        for method in &class.methods {
+            if overloads
+                .remove(method.name.as_str())
+                .is_some_and(|count| count > 1)
+            {
+                res.push(Mismatch::new(
+                    &class.name,
+                    Some(&method.name),
+                    "Overloaded native methods are not supported".into(),
+                ));
+            }
+        }
+    }
+
+    res
+}
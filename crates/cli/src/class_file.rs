@@ -0,0 +1,216 @@
+use anyhow::{bail, Context};
+use std::io::Read;
+use std::path::Path;
+
+const ACC_STATIC: u16 = 0x0008;
+const ACC_NATIVE: u16 = 0x0100;
+
+/// The parts of a compiled java class needed
+/// to check the generated JNI functions.
+#[derive(Debug)]
+pub struct ClassFile {
+    /// The binary name of the class, e.g. `com/example/Foo$FooNative`.
+    pub name: String,
+    pub methods: Vec<MethodInfo>,
+}
+
+#[derive(Debug)]
+pub struct MethodInfo {
+    pub name: String,
+    pub descriptor: String,
+    access_flags: u16,
+}
+
+impl MethodInfo {
+    pub fn is_native(&self) -> bool {
+        self.access_flags & ACC_NATIVE != 0
+    }
+
+    pub fn is_static(&self) -> bool {
+        self.access_flags & ACC_STATIC != 0
+    }
+}
+
+enum Constant {
+    Utf8(String),
+    Class(u16),
+    Other,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+        let Some(res) = self.bytes.get(self.pos..self.pos + len) else {
+            bail!("Unexpected end of class file");
+        };
+
+        self.pos += len;
+        Ok(res)
+    }
+
+    fn u1(&mut self) -> anyhow::Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u2(&mut self) -> anyhow::Result<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u4(&mut self) -> anyhow::Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Skip the attributes of a field or method.
+    fn skip_attributes(&mut self) -> anyhow::Result<()> {
+        for _ in 0..self.u2()? {
+            self.u2()?;
+            let len = self.u4()? as usize;
+            self.take(len)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ClassFile {
+    /// Parse a compiled java class.
+    pub fn parse(bytes: &[u8]) -> anyhow::Result<Self> {
+        let mut reader = Reader { bytes, pos: 0 };
+        if reader.u4()? != 0xCAFEBABE {
+            bail!("Not a class file");
+        }
+
+        // Minor and major version
+        reader.take(4)?;
+
+        let count = reader.u2()?;
+        let mut constants = Vec::with_capacity(count as usize);
+        constants.push(Constant::Other);
+        while constants.len() < count as usize {
+            let tag = reader.u1()?;
+            let constant = match tag {
+                1 => {
+                    let len = reader.u2()? as usize;
+                    // Names only contain characters which are
+                    // encoded the same in modified UTF-8
+                    Constant::Utf8(String::from_utf8_lossy(reader.take(len)?).into_owned())
+                }
+                7 => Constant::Class(reader.u2()?),
+                8 | 16 | 19 | 20 => {
+                    reader.take(2)?;
+                    Constant::Other
+                }
+                15 => {
+                    reader.take(3)?;
+                    Constant::Other
+                }
+                3 | 4 | 9 | 10 | 11 | 12 | 17 | 18 => {
+                    reader.take(4)?;
+                    Constant::Other
+                }
+                5 | 6 => {
+                    // Longs and doubles take up two entries
+                    reader.take(8)?;
+                    constants.push(Constant::Other);
+                    Constant::Other
+                }
+                tag => bail!("Invalid constant pool tag {tag}"),
+            };
+
+            constants.push(constant);
+        }
+
+        let utf8 = |index: u16| match constants.get(index as usize) {
+            Some(Constant::Utf8(value)) => Ok(value.clone()),
+            _ => bail!("Invalid constant pool index {index}"),
+        };
+
+        // Access flags
+        reader.u2()?;
+        let name = match constants.get(reader.u2()? as usize) {
+            Some(Constant::Class(name)) => utf8(*name)?,
+            _ => bail!("Invalid class name"),
+        };
+
+        // Super class and interfaces
+        reader.u2()?;
+        let interfaces = reader.u2()? as usize;
+        reader.take(interfaces * 2)?;
+
+        for _ in 0..reader.u2()? {
+            reader.take(6)?;
+            reader.skip_attributes()?;
+        }
+
+        let mut methods = Vec::new();
+        for _ in 0..reader.u2()? {
+            let access_flags = reader.u2()?;
+            let name = utf8(reader.u2()?)?;
+            let descriptor = utf8(reader.u2()?)?;
+            reader.skip_attributes()?;
+
+            methods.push(MethodInfo {
+                name,
+                descriptor,
+                access_flags,
+            });
+        }
+
+        Ok(Self { name, methods })
+    }
+}
+
+/// Read all classes in the given path, which may be a class file,
+/// a jar file or a directory containing class and jar files.
+pub fn read_classes(path: &Path) -> anyhow::Result<Vec<ClassFile>> {
+    let mut classes = Vec::new();
+    if path.is_dir() {
+        let mut entries = std::fs::read_dir(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort();
+
+        for entry in entries {
+            let extension = entry.extension().and_then(|e| e.to_str());
+            if entry.is_dir() || matches!(extension, Some("class" | "jar")) {
+                classes.extend(read_classes(&entry)?);
+            }
+        }
+    } else if path.extension().is_some_and(|e| e == "jar") {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let mut jar = zip::ZipArchive::new(file)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        for i in 0..jar.len() {
+            let mut entry = jar.by_index(i)?;
+            if !entry.name().ends_with(".class") {
+                continue;
+            }
+
+            let name = entry.name().to_string();
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes)?;
+            classes.push(
+                ClassFile::parse(&bytes)
+                    .with_context(|| format!("Failed to parse {name} in {}", path.display()))?,
+            );
+        }
+    } else {
+        let bytes =
+            std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        classes.push(
+            ClassFile::parse(&bytes)
+                .with_context(|| format!("Failed to parse {}", path.display()))?,
+        );
+    }
+
+    Ok(classes)
+}
@@ -1,9 +1,31 @@
-use clap::Parser;
+mod check;
+mod class_file;
+
+use clap::{Args, Parser, Subcommand};
+use jni_bindgen_build::Builder;
+use jni_bindgen_codegen::util::manifest::Manifest;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Parser, Debug)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum CargoCli {
+    JniBindgen(Cli),
+}
+
+#[derive(Args, Debug)]
 #[command(author, version, about, long_about = None)]
-struct Args {
+#[command(args_conflicts_with_subcommands = true, arg_required_else_help = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Commands>,
+    /// Build the project if no command is given, e.g. `cargo jni-bindgen <java_out_dir>`
+    #[command(flatten)]
+    build: Option<BuildArgs>,
+}
+
+#[derive(Args, Debug)]
+struct BuildArgs {
     /// The output directory
     java_out_dir: String,
     /// Build the project in release mode
@@ -12,27 +34,110 @@ struct Args {
     /// Print the generated code
     #[arg(long, default_value = "false")]
     print_code: bool,
+    /// The language of classes without a `language` attribute
+    #[arg(long, value_parser = ["java", "kotlin"])]
+    language: Option<String>,
     /// The arguments to pass to cargo
     cargo_args: Vec<String>,
 }
 
-fn main() {
-    let args = Args::parse();
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Build the project and generate the java classes
+    Build(BuildArgs),
+    /// Delete all java classes generated into the output directory
+    Clean {
+        /// The output directory
+        java_out_dir: String,
+    },
+    /// Compare the native methods and interfaces of the compiled
+    /// java classes with the code generated by the macros
+    Check {
+        /// The class files, jar files or directories containing the compiled classes
+        #[arg(required = true)]
+        classes: Vec<PathBuf>,
+        /// The root source file of the crate
+        #[arg(long, default_value = "src/lib.rs")]
+        source: PathBuf,
+    },
+}
 
-    let mut cargo_args = vec!["build".to_string()];
-    if args.release {
-        cargo_args.push("--release".to_string());
+fn build(
+    BuildArgs {
+        java_out_dir,
+        release,
+        print_code,
+        language,
+        cargo_args,
+    }: BuildArgs,
+) {
+    let mut args = vec!["build".to_string()];
+    if release {
+        args.push("--release".to_string());
     }
 
     let mut command = Command::new("cargo");
-    if args.print_code {
+    if print_code {
         command.env("DEBUG_JNI_BINDGEN", "true");
     }
+    if let Some(language) = language {
+        command.env("JNI_BINDGEN_LANGUAGE", language);
+    }
 
     command
-        .args(cargo_args)
-        .env("JNI_BINDGEN_OUT_DIR", &args.java_out_dir)
-        .args(&args.cargo_args)
+        .args(args)
+        .env("JNI_BINDGEN_OUT_DIR", &java_out_dir)
+        .args(&cargo_args)
         .status()
         .expect("Failed to build the project");
 }
+
+fn clean(java_out_dir: String) {
+    let _lock = Manifest::lock(Path::new(&java_out_dir)).expect("Failed to lock the manifest");
+    let removed = Manifest::read(Path::new(&java_out_dir))
+        .and_then(Manifest::clean)
+        .expect("Failed to delete the generated classes");
+
+    println!("Removed {} generated classes", removed.len());
+}
+
+fn check(classes: Vec<PathBuf>, source: PathBuf) {
+    let signatures = Builder::new()
+        .source(source)
+        .signatures()
+        .expect("Failed to read the generated signatures");
+    let classes = classes
+        .iter()
+        .map(|path| class_file::read_classes(path))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .expect("Failed to read the java classes")
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mismatches = check::check(&signatures, &classes);
+    for mismatch in &mismatches {
+        println!("{mismatch}");
+    }
+
+    if !mismatches.is_empty() {
+        println!("Found {} mismatches", mismatches.len());
+        std::process::exit(1);
+    }
+
+    println!("All {} signatures match", signatures.len());
+}
+
+fn main() {
+    let CargoCli::JniBindgen(cli) = CargoCli::parse();
+
+    match cli.command {
+        Some(Commands::Build(args)) => build(args),
+        Some(Commands::Clean { java_out_dir }) => clean(java_out_dir),
+        Some(Commands::Check { classes, source }) => check(classes, source),
+        None => build(
+            cli.build
+                .expect("Either a command or an output directory is required"),
+        ),
+    }
+}
@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+}
+
+/// Run `cargo jni-bindgen check` on the given classes of
+/// the fixtures, returning the exit code and the output.
+fn check(classes: &str) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-jni-bindgen"))
+        .args(["jni-bindgen", "check"])
+        .arg(fixtures().join(classes))
+        .arg("--source")
+        .arg(fixtures().join("src").join("lib.rs"))
+        .output()
+        .unwrap();
+
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+const MISMATCHES: &str = "\
+com.example.Checked$CheckedNative.missing: Method with descriptor ()I not found
+com.example.Checked$CheckedNative.descriptor: Expected descriptor (I)I, found (J)I
+com.example.Checked$CheckedNative.instance: Expected an instance method
+com.example.Checked$CheckedNative.unimplemented: Native method is not implemented
+com.example.Checked$CheckedNative.overloaded: Overloaded native methods are not supported
+Found 5 mismatches
+";
+
+#[test]
+fn reports_mismatches_of_class_files() {
+    assert_eq!(check("classes"), (Some(1), MISMATCHES.to_string()));
+}
+
+#[test]
+fn reports_mismatches_of_jar_files() {
+    assert_eq!(check("checked.jar"), (Some(1), MISMATCHES.to_string()));
+}
+
+#[test]
+fn reports_missing_classes() {
+    let (code, output) = check("classes/com/example/Checked.class");
+    assert_eq!(code, Some(1));
+    assert_eq!(
+        output,
+        "com.example.Checked$CheckedNative: Class not found\nFound 1 mismatches\n"
+    );
+}
@@ -0,0 +1,113 @@
+package com.example;
+
+import com.github.markusjx.jnibindgen.NativeClass;
+import com.github.markusjx.jnibindgen.NativeClassImpl;
+import com.github.markusjx.jnibindgen.NativePointer;
+
+public class Checked implements NativeClassImpl<Checked.CheckedNative> {
+    private final CheckedNative inner;
+
+    /**
+     * Wrap an existing native object.
+     * This is called by native code only.
+     */
+    private Checked(NativePointer ptr) {
+        inner = new CheckedNative(ptr, this);
+    }
+
+    public static int matching(int value) {
+        return CheckedNative.matching(value);
+    }
+
+    /**
+     * Declared with a `long` argument in the java class
+     */
+    public int descriptor(long value) {
+        return inner.descriptor(value);
+    }
+
+    /**
+     * Declared static in the java class
+     */
+    public static int instance() {
+        return CheckedNative.instance();
+    }
+
+    /**
+     * Overloaded with a `long` argument in the java class
+     */
+    public int overloaded(int value) {
+        return inner.overloaded(value);
+    }
+
+    public int overloaded(long value) {
+        return inner.overloaded(value);
+    }
+
+    /**
+     * Not implemented in Rust
+     */
+    public int unimplemented() {
+        return inner.unimplemented();
+    }
+
+    /**
+     * Get the type of the underlying native class
+     * in a hashed form. This is used to check if
+     * the native class is the same as the one
+     * that is expected.
+     *
+     * @return The type hash of this class
+     */
+    public static long getTypeHash() {
+        return CheckedNative.getTypeHash();
+    }
+
+    @Override
+    public CheckedNative getInner() {
+        return inner;
+    }
+
+    public static class CheckedNative extends NativeClass {
+        static {
+            System.loadLibrary("checked");
+        }
+
+        private CheckedNative(NativePointer ptr, Object referent) {
+            super(ptr.getPtr(), referent);
+        }
+
+        private static native int matching(int value);
+
+        /**
+         * Declared with a `long` argument in the java class
+         */
+        private native int descriptor(long value);
+
+        /**
+         * Declared static in the java class
+         */
+        private static native int instance();
+
+        /**
+         * Overloaded with a `long` argument in the java class
+         */
+        private native int overloaded(int value);
+
+        private native int overloaded(long value);
+
+        /**
+         * Not implemented in Rust
+         */
+        private native int unimplemented();
+
+        private static native void drop(long self);
+
+        private static native long getTypeHash();
+
+        @Override
+        protected void destruct() {
+            drop(this.ptr);
+        }
+    }
+}
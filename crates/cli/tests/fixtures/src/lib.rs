@@ -0,0 +1,39 @@
+//! The sources checked against the classes in `tests/fixtures/classes`,
+//! which have been compiled from the java sources in `tests/fixtures/java`.
+//! The java classes have been generated from this file and changed
+//! afterwards, so every kind of mismatch is reported once.
+use jni_bindgen::jni;
+
+pub struct Checked;
+
+#[jni(package = "com.example", load_lib = "checked")]
+impl Checked {
+    #[jni]
+    fn matching(value: i32) -> i32 {
+        value
+    }
+
+    /// Removed from the java class
+    #[jni]
+    fn missing(&self) -> i32 {
+        0
+    }
+
+    /// Declared with a `long` argument in the java class
+    #[jni]
+    fn descriptor(&self, value: i32) -> i32 {
+        value
+    }
+
+    /// Declared static in the java class
+    #[jni]
+    fn instance(&self) -> i32 {
+        0
+    }
+
+    /// Overloaded with a `long` argument in the java class
+    #[jni]
+    fn overloaded(&self, value: i32) -> i32 {
+        value
+    }
+}
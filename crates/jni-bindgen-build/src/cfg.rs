@@ -0,0 +1,195 @@
+//! Evaluation of `#[cfg]` attributes using the
+//! configuration cargo passes to build scripts.
+
+use anyhow::bail;
+use quote::ToTokens;
+use std::collections::{HashMap, HashSet};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, ExprLit, Fields, ImplItem, Item, Lit, Meta, Token, TraitItem};
+
+/// The features and configuration options `#[cfg]` attributes are evaluated with.
+#[derive(Debug, Clone, Default)]
+pub struct Cfg {
+    /// The enabled features in lower case, with `-` replaced by `_`.
+    features: HashSet<String>,
+    /// The values of the set configuration options by their name.
+    /// Options without a value, e.g. `unix`, have no values.
+    options: HashMap<String, Vec<String>>,
+}
+
+impl Cfg {
+    /// Read the configuration from the `CARGO_FEATURE_*` and `CARGO_CFG_*`
+    /// environment variables cargo sets for build scripts.
+    /// Returns `None` if not running in a build script.
+    pub fn from_env() -> Option<Self> {
+        std::env::var_os("CARGO_CFG_TARGET_OS")?;
+
+        let mut cfg = Self::default();
+        for (key, value) in std::env::vars_os() {
+            let (Some(key), Some(value)) = (key.to_str(), value.to_str()) else {
+                continue;
+            };
+
+            if let Some(feature) = key.strip_prefix("CARGO_FEATURE_") {
+                cfg.enable_feature(feature);
+            } else if let Some(name) = key.strip_prefix("CARGO_CFG_") {
+                // Values like target_feature are passed as a comma separated list
+                for value in value.split(',').filter(|v| !v.is_empty()) {
+                    cfg.set(&name.to_lowercase(), Some(value));
+                }
+                cfg.set(&name.to_lowercase(), None);
+            }
+        }
+
+        Some(cfg)
+    }
+
+    /// Enable a feature.
+    pub fn enable_feature(&mut self, feature: &str) {
+        self.features
+            .insert(feature.to_lowercase().replace('-', "_"));
+    }
+
+    /// Set a configuration option, optionally with a value.
+    pub fn set(&mut self, name: &str, value: Option<&str>) {
+        let values = self.options.entry(name.to_string()).or_default();
+        if let Some(value) = value {
+            values.push(value.to_string());
+        }
+    }
+
+    /// Evaluate a `cfg` predicate.
+    fn evaluate(&self, predicate: &Meta) -> anyhow::Result<bool> {
+        match predicate {
+            Meta::Path(path) => Ok(self.options.contains_key(&option_name(path)?)),
+            Meta::NameValue(name_value) => {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }) = &name_value.value
+                else {
+                    bail!("Invalid cfg predicate {}", predicate.to_token_stream());
+                };
+
+                if name_value.path.is_ident("feature") {
+                    let feature = value.value().to_lowercase().replace('-', "_");
+                    return Ok(self.features.contains(&feature));
+                }
+
+                Ok(self
+                    .options
+                    .get(&option_name(&name_value.path)?)
+                    .is_some_and(|values| values.contains(&value.value())))
+            }
+            Meta::List(list) => {
+                let predicates = list
+                    .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
+                    .into_iter()
+                    .map(|p| self.evaluate(&p))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+
+                match list.path.get_ident().map(|i| i.to_string()).as_deref() {
+                    Some("all") => Ok(predicates.into_iter().all(|p| p)),
+                    Some("any") => Ok(predicates.into_iter().any(|p| p)),
+                    Some("not") if predicates.len() == 1 => Ok(!predicates[0]),
+                    _ => bail!("Invalid cfg predicate {}", predicate.to_token_stream()),
+                }
+            }
+        }
+    }
+}
+
+/// Get the name of a configuration option.
+fn option_name(path: &syn::Path) -> anyhow::Result<String> {
+    match path.get_ident() {
+        Some(ident) => Ok(ident.to_string()),
+        None => bail!("Invalid cfg option {}", path.to_token_stream()),
+    }
+}
+
+/// Whether all `#[cfg]` attributes of an item are enabled. Without a
+/// configuration, e.g. in the cli tool, the attributes can not be
+/// evaluated, thus the item is assumed to be enabled and a warning
+/// is printed.
+pub fn is_enabled(cfg: Option<&Cfg>, attrs: &[Attribute]) -> anyhow::Result<bool> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("cfg")) {
+        let Some(cfg) = cfg else {
+            eprintln!(
+                "warning: Unable to evaluate {} outside of a build script, assuming it is enabled",
+                attr.to_token_stream()
+            );
+            continue;
+        };
+
+        if !cfg.evaluate(&attr.parse_args()?)? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Keep the elements whose attributes are enabled.
+fn retain<T>(
+    cfg: Option<&Cfg>,
+    elements: impl IntoIterator<Item = T>,
+    attrs: impl Fn(&T) -> &[Attribute],
+) -> anyhow::Result<Vec<T>> {
+    let mut res = Vec::new();
+    for element in elements {
+        if is_enabled(cfg, attrs(&element))? {
+            res.push(element);
+        }
+    }
+
+    Ok(res)
+}
+
+/// Remove the methods, variants and fields of an item which are disabled.
+pub fn strip(cfg: Option<&Cfg>, item: &mut Item) -> anyhow::Result<()> {
+    match item {
+        Item::Impl(impl_) => {
+            impl_.items = retain(cfg, std::mem::take(&mut impl_.items), |item| match item {
+                ImplItem::Fn(f) => &f.attrs,
+                ImplItem::Const(c) => &c.attrs,
+                ImplItem::Type(t) => &t.attrs,
+                _ => &[],
+            })?;
+        }
+        Item::Trait(trait_) => {
+            trait_.items = retain(cfg, std::mem::take(&mut trait_.items), |item| match item {
+                TraitItem::Fn(f) => &f.attrs,
+                TraitItem::Const(c) => &c.attrs,
+                TraitItem::Type(t) => &t.attrs,
+                _ => &[],
+            })?;
+        }
+        Item::Enum(enum_) => {
+            let variants = std::mem::take(&mut enum_.variants);
+            enum_.variants = retain(cfg, variants, |v| &v.attrs)?.into_iter().collect();
+            for variant in &mut enum_.variants {
+                strip_fields(cfg, &mut variant.fields)?;
+            }
+        }
+        Item::Struct(struct_) => strip_fields(cfg, &mut struct_.fields)?,
+        _ => {}
+    }
+
+    Ok(())
+}
+
+fn strip_fields(cfg: Option<&Cfg>, fields: &mut Fields) -> anyhow::Result<()> {
+    match fields {
+        Fields::Named(named) => {
+            let fields = std::mem::take(&mut named.named);
+            named.named = retain(cfg, fields, |f| &f.attrs)?.into_iter().collect();
+        }
+        Fields::Unnamed(unnamed) => {
+            let fields = std::mem::take(&mut unnamed.unnamed);
+            unnamed.unnamed = retain(cfg, fields, |f| &f.attrs)?.into_iter().collect();
+        }
+        Fields::Unit => {}
+    }
+
+    Ok(())
+}
@@ -0,0 +1,539 @@
+//! Generate the java classes of `jni-bindgen` from a build script.
+//!
+//! Instead of writing the java classes while expanding the `#[jni]`
+//! macros, the [`Builder`] parses the sources of the crate and writes
+//! all java classes at once. Thus, the generated classes stay correct
+//! even if the macro expansion is cached, e.g. by incremental builds
+//! or rust-analyzer.
+//!
+//! # Example
+//! ```no_run
+//! // In build.rs
+//! jni_bindgen_build::Builder::new()
+//!     .out_dir("../java/src/main/java")
+//!     .generate()
+//!     .expect("Failed to generate the java classes");
+//! ```
+
+mod cfg;
+
+use crate::cfg::Cfg;
+use anyhow::{anyhow, bail, Context};
+use indexmap::IndexMap;
+use jni_bindgen_codegen::codegen::signature::{JniSignature, SignatureKind};
+use jni_bindgen_codegen::util::attrs::BindgenAttrs;
+use jni_bindgen_codegen::util::context::{with_context, Context as CodegenContext};
+use jni_bindgen_codegen::util::expand::{expand_error_item, expand_item, signatures, JavaFile};
+use jni_bindgen_codegen::util::language::{default_language, with_language};
+use jni_bindgen_codegen::util::manifest::Manifest;
+use jni_bindgen_codegen::util::traits::AnyAttribute;
+use quote::ToTokens;
+use std::path::{Path, PathBuf};
+use syn::{Attribute, DeriveInput, Item, Meta};
+
+pub use jni_bindgen_codegen::util::language::Language;
+
+/// An item annotated with `#[jni]` or deriving `JniError`.
+/// Items annotated with `#[jni]` store the path of their module.
+enum BindgenItem {
+    Jni(Box<Item>, BindgenAttrs, String),
+    Error(Box<DeriveInput>),
+}
+
+/// Generates the java classes for all items annotated with `#[jni]`
+/// and all error types deriving `JniError` in the sources of a crate.
+#[derive(Debug, Clone)]
+pub struct Builder {
+    source: PathBuf,
+    out_dir: Option<PathBuf>,
+    language: Option<Language>,
+    register_natives: bool,
+    natives_file: Option<PathBuf>,
+    class_names: Option<String>,
+    cfg: Option<Cfg>,
+}
+
+impl Builder {
+    /// Create a new builder for the crate the build script is run for.
+    /// The sources are read starting from `src/lib.rs`, the java classes
+    /// are written to `JNI_BINDGEN_OUT_DIR` if set or `$OUT_DIR/java`.
+    /// `#[cfg]` attributes are evaluated using the configuration
+    /// cargo passes to the build script.
+    pub fn new() -> Self {
+        let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+
+        Self {
+            source: Path::new(&manifest_dir).join("src").join("lib.rs"),
+            out_dir: std::env::var("JNI_BINDGEN_OUT_DIR")
+                .ok()
+                .filter(|dir| !dir.is_empty())
+                .map(PathBuf::from),
+            language: None,
+            register_natives: false,
+            natives_file: std::env::var("OUT_DIR")
+                .ok()
+                .map(|dir| Path::new(&dir).join("jni_bindgen_natives.rs")),
+            class_names: None,
+            cfg: Cfg::from_env(),
+        }
+    }
+
+    /// Set the root source file of the crate. All modules declared
+    /// in this file are parsed too.
+    pub fn source(mut self, source: impl AsRef<Path>) -> Self {
+        self.source = source.as_ref().to_path_buf();
+        self
+    }
+
+    /// Set the directory the java classes are written to.
+    /// Relative paths are resolved from the directory of the crate.
+    pub fn out_dir(mut self, out_dir: impl AsRef<Path>) -> Self {
+        self.out_dir = Some(out_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the language of all classes without a `language` attribute.
+    /// Defaults to `JNI_BINDGEN_LANGUAGE` if set or java.
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Register the native methods in a generated `JNI_OnLoad` function
+    /// instead of exporting a function for every native method. The
+    /// function is written to `$OUT_DIR/jni_bindgen_natives.rs`, which
+    /// must be included in the root of the crate:
+    ///
+    /// ```ignore
+    /// include!(concat!(env!("OUT_DIR"), "/jni_bindgen_natives.rs"));
+    /// ```
+    ///
+    /// All modules containing items annotated with `#[jni]`
+    /// must be accessible from the root of the crate. The native
+    /// methods of the `jni-bindgen` runtime classes are registered too.
+    pub fn register_natives(mut self) -> Self {
+        self.register_natives = true;
+        self
+    }
+
+    /// Set the file the `JNI_OnLoad` function is written to
+    /// instead of `$OUT_DIR/jni_bindgen_natives.rs`.
+    pub fn natives_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.natives_file = Some(file.as_ref().to_path_buf());
+        self
+    }
+
+    /// Enable a feature when evaluating `#[cfg]` attributes,
+    /// in addition to the features enabled by cargo.
+    pub fn feature(mut self, feature: &str) -> Self {
+        self.cfg
+            .get_or_insert_with(Cfg::default)
+            .enable_feature(feature);
+        self
+    }
+
+    /// Set a configuration option, e.g. `target_os = "linux"` or `unix`,
+    /// when evaluating `#[cfg]` attributes, in addition to the options
+    /// set by cargo. Outside of a build script, `#[cfg]` attributes are
+    /// only evaluated if a feature or option has been set.
+    pub fn cfg(mut self, name: &str, value: Option<&str>) -> Self {
+        self.cfg.get_or_insert_with(Cfg::default).set(name, value);
+        self
+    }
+
+    /// Set the path of a `fn(&str) -> String` mapping the binary names
+    /// of the generated classes to the names they are loaded with, e.g.
+    /// `crate::obfuscation::class_name`. The function is passed to
+    /// `jni_bindgen::natives::set_class_names` in the generated `JNI_OnLoad`.
+    /// This requires [`register_natives`](Self::register_natives).
+    pub fn class_names(mut self, path: impl Into<String>) -> Self {
+        self.class_names = Some(path.into());
+        self
+    }
+
+    /// Parse the source files of the crate, returning all items
+    /// to generate classes for and the parsed source files.
+    fn parse(&self) -> anyhow::Result<(Vec<BindgenItem>, Vec<PathBuf>)> {
+        let mut items = Vec::new();
+        let mut sources = Vec::new();
+        parse_file(
+            &self.source,
+            module_dir(&self.source),
+            "crate",
+            self.cfg.as_ref(),
+            &mut items,
+            &mut sources,
+        )?;
+
+        Ok((items, sources))
+    }
+
+    /// Parse the sources and get the signatures of all java
+    /// methods the generated rust code depends on, without
+    /// generating any classes.
+    pub fn signatures(&self) -> anyhow::Result<Vec<JniSignature>> {
+        let (items, _) = self.parse()?;
+        let context = build_context(&items)?;
+
+        with_context(context, || {
+            let mut res = Vec::new();
+            for item in items {
+                if let BindgenItem::Jni(item, args, _) = item {
+                    res.extend(
+                        signatures(&item, &args)
+                            .map_err(|e| anyhow!("Failed to read the signatures: {e}"))?,
+                    );
+                }
+            }
+
+            Ok(res)
+        })
+    }
+
+    /// Write the `JNI_OnLoad` function registering the
+    /// native methods of all items into the natives file.
+    fn write_natives(&self, items: &[BindgenItem]) -> anyhow::Result<()> {
+        let mut classes = IndexMap::<String, Vec<String>>::new();
+        let mut on_load = None;
+        for item in items {
+            let BindgenItem::Jni(item, args, module) = item else {
+                continue;
+            };
+
+            if matches!(item.as_ref(), Item::Fn(_)) && args.is_on_load() {
+                if on_load.is_some() {
+                    bail!("Only one function may be annotated with #[jni(on_load)]");
+                }
+
+                // The public wrapper generated by the macro
+                on_load = Some(format!("{module}::jni_bindgen_on_load"));
+            }
+
+            let signatures = signatures(item, args)
+                .map_err(|e| anyhow!("Failed to read the signatures: {e}"))?;
+            for signature in signatures {
+                let SignatureKind::Native { symbol, .. } = &signature.kind else {
+                    continue;
+                };
+
+                classes
+                    .entry(signature.class_name)
+                    .or_default()
+                    .push(format!(
+                    "            .method({:?}, {:?}, {module}::{symbol} as *mut std::ffi::c_void)",
+                    signature.method_name, signature.descriptor
+                ));
+            }
+        }
+
+        let natives = classes
+            .iter()
+            .map(|(class, methods)| {
+                format!(
+                    "        jni_bindgen::natives::Natives::new({class:?})\n{}",
+                    methods.join("\n")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+        let class_names = match &self.class_names {
+            Some(path) => format!("Some({path} as jni_bindgen::natives::ClassNames)"),
+            None => "None".to_string(),
+        };
+
+        let (vm, on_load) = match on_load {
+            Some(path) => ("vm", format!("{path}(vm, env)")),
+            None => ("_vm", "Ok(())".to_string()),
+        };
+
+        let code = format!(
+            r#"// Generated by jni-bindgen-build, do not edit.
+
+/// The native methods of all classes generated by jni-bindgen.
+pub fn jni_bindgen_natives() -> Vec<jni_bindgen::natives::Natives> {{
+    vec![
+{natives}
+    ]
+}}
+
+#[no_mangle]
+pub extern "system" fn JNI_OnLoad(
+    vm: jni::JavaVM,
+    _reserved: *mut std::ffi::c_void,
+) -> jni::sys::jint {{
+    jni_bindgen::vm::on_load(vm, |{vm}, env| {{
+        jni_bindgen::natives::init(env, {class_names}, jni_bindgen_natives())?;
+        {on_load}
+    }})
+}}
+"#
+        );
+
+        let file = self.natives_file.as_ref().context("OUT_DIR is not set")?;
+        std::fs::write(file, code).with_context(|| format!("Failed to write {}", file.display()))
+    }
+
+    fn get_out_dir(&self) -> anyhow::Result<PathBuf> {
+        match &self.out_dir {
+            Some(out_dir) => Ok(out_dir.clone()),
+            None => std::env::var("OUT_DIR")
+                .map(|dir| Path::new(&dir).join("java"))
+                .context("No output directory set"),
+        }
+    }
+
+    /// Parse the sources and write the java classes into the output directory.
+    /// Classes generated by a previous run which are not generated anymore,
+    /// e.g. because the rust type has been renamed, are deleted.
+    /// Instructs cargo to rerun the build script if any source file changes.
+    pub fn generate(self) -> anyhow::Result<()> {
+        let out_dir = self.get_out_dir()?;
+        let out_dir = match std::env::var("CARGO_MANIFEST_DIR") {
+            Ok(manifest_dir) => Path::new(&manifest_dir).join(out_dir),
+            Err(_) => out_dir,
+        };
+
+        let (items, sources) = self.parse()?;
+        for source in &sources {
+            println!("cargo:rerun-if-changed={}", source.display());
+        }
+
+        // The classes are generated here, the macros only generate the rust code
+        println!("cargo:rustc-env=JNI_BINDGEN_BUILD=1");
+
+        if self.register_natives {
+            println!("cargo:rustc-env=JNI_BINDGEN_REGISTER_NATIVES=1");
+            with_context(build_context(&items)?, || self.write_natives(&items))?;
+        } else if self.class_names.is_some() {
+            bail!("Setting the class names requires register_natives");
+        }
+        println!("cargo:rerun-if-env-changed=JNI_BINDGEN_OUT_DIR");
+        println!("cargo:rerun-if-env-changed=JNI_BINDGEN_LANGUAGE");
+
+        let _lock = Manifest::lock(&out_dir).context("Failed to lock the manifest")?;
+        let previous = Manifest::read(&out_dir).context("Failed to read the manifest")?;
+
+        let language = self.language.unwrap_or_else(default_language);
+        let context = build_context(&items)?;
+        let java_files = with_context(context, || {
+            with_language(language, || {
+                let mut java_files = IndexMap::<PathBuf, JavaFile>::new();
+                for item in items {
+                    let files = match item {
+                        BindgenItem::Jni(item, args, _) => expand_item(*item, &args)?.java_files,
+                        BindgenItem::Error(input) => expand_error_item(&input)?.1,
+                    };
+
+                    // Utility classes are generated again for every function
+                    for file in files {
+                        java_files.insert(file.path(), file);
+                    }
+                }
+
+                Ok::<_, syn::Error>(java_files)
+            })
+        })
+        .map_err(|e| anyhow!("Failed to generate the java classes: {e}"))?;
+
+        let mut manifest = Manifest::new(&out_dir);
+        for (path, file) in java_files {
+            file.write(&out_dir)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            manifest.add(path);
+        }
+
+        // Remove the classes of renamed or deleted types
+        manifest
+            .remove_stale(&previous)
+            .context("Failed to remove stale classes")?;
+        manifest.write().context("Failed to write the manifest")
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collect the items other items depend on.
+fn build_context(items: &[BindgenItem]) -> anyhow::Result<CodegenContext> {
+    let mut context = CodegenContext::new();
+    for item in items {
+        match item {
+            BindgenItem::Jni(item, args, _) => context.add_item(item, args),
+            BindgenItem::Error(input) => context.add_error(input),
+        }
+        .map_err(|e| anyhow!("Failed to read the items: {e}"))?;
+    }
+
+    Ok(context)
+}
+
+/// Get the directory the modules declared in the given file are located in.
+fn module_dir(file: &Path) -> PathBuf {
+    let parent = file.parent().unwrap_or(Path::new("")).to_path_buf();
+    match file.file_stem().and_then(|s| s.to_str()) {
+        Some("lib" | "main" | "mod") | None => parent,
+        Some(stem) => parent.join(stem),
+    }
+}
+
+fn parse_file(
+    file: &Path,
+    dir: PathBuf,
+    module: &str,
+    cfg: Option<&Cfg>,
+    items: &mut Vec<BindgenItem>,
+    sources: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let code = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let parsed =
+        syn::parse_file(&code).with_context(|| format!("Failed to parse {}", file.display()))?;
+
+    sources.push(file.to_path_buf());
+    parse_items(parsed.items, &dir, module, cfg, items, sources)
+}
+
+fn parse_items(
+    file_items: Vec<Item>,
+    dir: &Path,
+    module_path: &str,
+    cfg: Option<&Cfg>,
+    items: &mut Vec<BindgenItem>,
+    sources: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    for item in file_items {
+        match item {
+            Item::Mod(module) => {
+                if !cfg::is_enabled(cfg, &module.attrs)? {
+                    continue;
+                }
+
+                let path = path_attr(&module.attrs);
+                let submodule_path = format!("{module_path}::{}", module.ident);
+                match module.content {
+                    Some((_, content)) => {
+                        let dir = match path {
+                            Some(path) => dir.join(path),
+                            None => dir.join(module.ident.to_string()),
+                        };
+
+                        parse_items(content, &dir, &submodule_path, cfg, items, sources)?;
+                    }
+                    None => {
+                        let file = match path {
+                            Some(path) => dir.join(path),
+                            None => {
+                                let file = dir.join(format!("{}.rs", module.ident));
+                                if file.exists() {
+                                    file
+                                } else {
+                                    dir.join(module.ident.to_string()).join("mod.rs")
+                                }
+                            }
+                        };
+
+                        let module_dir = match file.file_name().and_then(|s| s.to_str()) {
+                            Some("mod.rs") => module_dir(&file),
+                            _ => dir.join(module.ident.to_string()),
+                        };
+
+                        parse_file(&file, module_dir, &submodule_path, cfg, items, sources)?;
+                    }
+                }
+            }
+            mut item => {
+                let Some(attrs) = item_attrs(&mut item) else {
+                    continue;
+                };
+
+                // The cfg attributes of other items are not evaluated, so
+                // they don't cause warnings outside of a build script
+                if derives_jni_error(attrs) {
+                    if cfg::is_enabled(cfg, attrs)? {
+                        cfg::strip(cfg, &mut item)?;
+                        items.push(BindgenItem::Error(Box::new(syn::parse2(
+                            item.to_token_stream(),
+                        )?)));
+                    }
+                } else if let Some(args) = take_jni_attr(attrs)? {
+                    if !cfg::is_enabled(cfg, attrs)? {
+                        continue;
+                    }
+
+                    cfg::strip(cfg, &mut item)?;
+                    items.push(BindgenItem::Jni(
+                        Box::new(item),
+                        args,
+                        module_path.to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the attributes of an item which may be annotated with `#[jni]`.
+fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Impl(i) => Some(&mut i.attrs),
+        Item::Trait(i) => Some(&mut i.attrs),
+        Item::Struct(i) => Some(&mut i.attrs),
+        Item::Enum(i) => Some(&mut i.attrs),
+        Item::Fn(i) => Some(&mut i.attrs),
+        _ => None,
+    }
+}
+
+/// Get the value of a `#[path = "..."]` attribute.
+fn path_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(value) if value.path.is_ident("path") => match &value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Whether the item has a `#[derive(JniError)]` attribute.
+fn derives_jni_error(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("derive"))
+        .any(|attr| {
+            let mut derives = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                derives |= meta
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|s| s.ident == "JniError");
+                Ok(())
+            });
+
+            derives
+        })
+}
+
+/// Remove the `#[jni]` attribute from the item and parse its arguments,
+/// as the attribute macro would receive them.
+fn take_jni_attr(attrs: &mut Vec<Attribute>) -> anyhow::Result<Option<BindgenAttrs>> {
+    let Some(pos) = attrs.iter().position(|a| a.is_jni()) else {
+        return Ok(None);
+    };
+
+    let args = match attrs.remove(pos).meta {
+        Meta::List(list) => syn::parse2(list.tokens)?,
+        _ => syn::parse2(Default::default())?,
+    };
+
+    Ok(Some(args))
+}
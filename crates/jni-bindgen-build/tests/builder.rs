@@ -0,0 +1,376 @@
+use jni_bindgen_build::{Builder, Language};
+use std::path::{Path, PathBuf};
+
+/// A crate with a single source file in a new temporary directory.
+struct TestCrate {
+    dir: PathBuf,
+}
+
+impl TestCrate {
+    fn new(name: &str, source: &str) -> Self {
+        let dir =
+            std::env::temp_dir().join(format!("jni-bindgen-build-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+
+        let test_crate = Self { dir };
+        test_crate.write(source);
+        test_crate
+    }
+
+    fn write(&self, source: &str) {
+        std::fs::write(self.dir.join("src").join("lib.rs"), source).unwrap();
+    }
+
+    fn out_dir(&self) -> PathBuf {
+        self.dir.join("java")
+    }
+
+    fn generate(&self) -> anyhow::Result<()> {
+        Builder::new()
+            .source(self.dir.join("src").join("lib.rs"))
+            .out_dir(self.out_dir())
+            .generate()
+    }
+
+    fn read(&self, class: &str) -> String {
+        std::fs::read_to_string(self.out_dir().join(class)).unwrap()
+    }
+
+    fn exists(&self, class: &str) -> bool {
+        self.out_dir().join(class).exists()
+    }
+}
+
+impl Drop for TestCrate {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[test]
+fn imports_classes_of_other_packages() {
+    let test_crate = TestCrate::new(
+        "imports",
+        r#"
+        #[jni(package = "com.example.shapes", data)]
+        pub struct Size {
+            width: f64,
+            height: f64,
+        }
+
+        #[jni(package = "com.example", class = "Util")]
+        fn area(size: Size) -> f64 {
+            size.width * size.height
+        }
+        "#,
+    );
+
+    test_crate.generate().unwrap();
+    let util = test_crate.read("com/example/Util.java");
+    assert!(util.contains("import com.example.shapes.Size;"));
+    assert!(util.contains("public static double area(Size size)"));
+}
+
+#[test]
+fn rejects_undeclared_types() {
+    let test_crate = TestCrate::new(
+        "undeclared",
+        r#"
+        #[jni(package = "com.example", class = "Util")]
+        fn area(size: Size) -> f64 {
+            size.width * size.height
+        }
+        "#,
+    );
+
+    let err = test_crate.generate().unwrap_err().to_string();
+    assert!(err.contains("Unsupported type: 'Size'"), "{err}");
+    assert!(!test_crate.exists("com/example/Util.java"));
+}
+
+#[test]
+fn accepts_types_with_class_name() {
+    let test_crate = TestCrate::new(
+        "class-name",
+        r#"
+        #[jni(package = "com.example", class = "Util")]
+        fn area(#[jni(class_name = "com.example.shapes.Size")] size: Size) -> f64 {
+            size.width * size.height
+        }
+        "#,
+    );
+
+    test_crate.generate().unwrap();
+    let util = test_crate.read("com/example/Util.java");
+    assert!(util.contains("import com.example.shapes.Size;"));
+}
+
+#[test]
+fn removes_classes_of_renamed_types() {
+    let test_crate = TestCrate::new(
+        "rename",
+        r#"
+        #[jni(package = "com.example", data)]
+        pub struct Point {
+            x: f64,
+            y: f64,
+        }
+        "#,
+    );
+
+    test_crate.generate().unwrap();
+    assert!(test_crate.exists("com/example/Point.java"));
+
+    test_crate.write(
+        r#"
+        #[jni(package = "com.example", data)]
+        pub struct Vector {
+            x: f64,
+            y: f64,
+        }
+        "#,
+    );
+    test_crate.generate().unwrap();
+    assert!(test_crate.exists("com/example/Vector.java"));
+    assert!(!test_crate.exists("com/example/Point.java"));
+    assert!(!test_crate
+        .read(".jni-bindgen/manifest")
+        .contains("Point.java"));
+}
+
+#[test]
+fn registers_natives_on_load() {
+    let test_crate = TestCrate::new(
+        "register",
+        r#"
+        #[jni(on_load)]
+        fn on_load(_vm: &JavaVM, _env: &mut JNIEnv) -> jni_bindgen::Result<()> {
+            Ok(())
+        }
+
+        #[jni(package = "com.example", data)]
+        pub struct Point {
+            x: f64,
+            y: f64,
+        }
+
+        #[jni(package = "com.example.util", class = "Util")]
+        fn length(point: Point) -> f64 {
+            point.x.hypot(point.y)
+        }
+        "#,
+    );
+
+    Builder::new()
+        .source(test_crate.dir.join("src").join("lib.rs"))
+        .out_dir(test_crate.out_dir())
+        .register_natives()
+        .natives_file(test_crate.dir.join("jni_bindgen_natives.rs"))
+        .class_names("crate::class_name")
+        .generate()
+        .unwrap();
+
+    let natives = std::fs::read_to_string(test_crate.dir.join("jni_bindgen_natives.rs")).unwrap();
+    assert!(natives.contains(r#"Natives::new("com/example/util/Util$UtilNative")"#));
+    assert!(natives.contains(
+        r#".method("length", "(Lcom/example/Point;)D", crate::Java_com_example_util_Util_00024UtilNative_length as *mut std::ffi::c_void)"#
+    ));
+    assert!(natives.contains("Some(crate::class_name as jni_bindgen::natives::ClassNames)"));
+    assert!(natives.contains("crate::jni_bindgen_on_load(vm, env)"));
+}
+
+#[test]
+fn class_names_require_register_natives() {
+    let test_crate = TestCrate::new(
+        "class-names",
+        r#"
+        #[jni(package = "com.example", data)]
+        pub struct Point {
+            x: f64,
+            y: f64,
+        }
+        "#,
+    );
+
+    let err = Builder::new()
+        .source(test_crate.dir.join("src").join("lib.rs"))
+        .out_dir(test_crate.out_dir())
+        .class_names("crate::class_name")
+        .generate()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Setting the class names requires register_natives"
+    );
+}
+
+/// Get all files in the given directory and its subdirectories,
+/// relative to the given directory.
+fn files(dir: &Path) -> Vec<PathBuf> {
+    let mut res = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(next) = dirs.pop() {
+        for entry in std::fs::read_dir(next).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                res.push(path.strip_prefix(dir).unwrap().to_path_buf());
+            }
+        }
+    }
+
+    res.sort();
+    res
+}
+
+/// Compare the Kotlin classes generated from `tests/kotlin/src/lib.rs`
+/// with the classes in `tests/kotlin/expected`. Set `JNI_BINDGEN_BLESS=1`
+/// to replace the expected classes with the generated ones.
+#[test]
+fn generates_kotlin_classes() {
+    let kotlin = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("kotlin");
+    let expected = kotlin.join("expected");
+    let test_crate = TestCrate::new(
+        "kotlin",
+        &std::fs::read_to_string(kotlin.join("src").join("lib.rs")).unwrap(),
+    );
+
+    Builder::new()
+        .source(test_crate.dir.join("src").join("lib.rs"))
+        .out_dir(test_crate.out_dir())
+        .language(Language::Kotlin)
+        .generate()
+        .unwrap();
+
+    let generated = files(&test_crate.out_dir())
+        .into_iter()
+        .filter(|file| file.extension().is_some_and(|ext| ext == "kt"))
+        .collect::<Vec<_>>();
+    if std::env::var("JNI_BINDGEN_BLESS").is_ok_and(|v| v == "1") {
+        let _ = std::fs::remove_dir_all(&expected);
+        for file in &generated {
+            std::fs::create_dir_all(expected.join(file).parent().unwrap()).unwrap();
+            std::fs::write(expected.join(file), test_crate.read(file.to_str().unwrap())).unwrap();
+        }
+    }
+
+    assert_eq!(generated, files(&expected));
+    for file in &generated {
+        assert_eq!(
+            test_crate.read(file.to_str().unwrap()),
+            std::fs::read_to_string(expected.join(file)).unwrap(),
+            "{} differs from the expected class",
+            file.display()
+        );
+    }
+}
+
+#[test]
+fn rejects_overloaded_methods() {
+    let test_crate = TestCrate::new(
+        "overloads",
+        r#"
+        #[jni(package = "com.example", class = "Util")]
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+
+        #[jni(package = "com.example", class = "Util", rename = "add")]
+        fn add_long(a: i64, b: i64) -> i64 {
+            a + b
+        }
+        "#,
+    );
+
+    let err = test_crate.generate().unwrap_err().to_string();
+    assert!(
+        err.contains("Overloaded native methods are not supported, 'add' is already declared"),
+        "{err}"
+    );
+}
+
+#[test]
+fn rejects_duplicate_type_names() {
+    let test_crate = TestCrate::new(
+        "duplicates",
+        r#"
+        mod shapes {
+            #[jni(package = "com.example.shapes", data)]
+            pub struct Point {
+                x: f64,
+                y: f64,
+            }
+        }
+
+        mod grid {
+            #[jni(package = "com.example.grid", data)]
+            pub struct Point {
+                row: i32,
+                column: i32,
+            }
+        }
+        "#,
+    );
+
+    let err = test_crate.generate().unwrap_err().to_string();
+    assert!(
+        err.contains("Another type named 'Point' generates different classes"),
+        "{err}"
+    );
+    assert!(!test_crate.exists("com/example/shapes/Point.java"));
+}
+
+#[test]
+fn skips_disabled_items() {
+    let test_crate = TestCrate::new(
+        "cfg",
+        r#"
+        #[cfg(feature = "shapes")]
+        #[jni(package = "com.example", data)]
+        pub struct Point {
+            x: f64,
+            y: f64,
+            #[cfg(not(unix))]
+            z: f64,
+        }
+
+        #[cfg(feature = "missing")]
+        #[jni(package = "com.example", data)]
+        pub struct Vector {
+            x: f64,
+            y: f64,
+        }
+
+        #[cfg(all(feature = "missing", unix))]
+        mod hidden {
+            #[jni(package = "com.example", class = "Hidden")]
+            fn hidden() {}
+        }
+
+        #[jni(package = "com.example", class = "Util")]
+        #[cfg(any(target_os = "linux", feature = "missing"))]
+        fn shown() {}
+        "#,
+    );
+
+    Builder::new()
+        .source(test_crate.dir.join("src").join("lib.rs"))
+        .out_dir(test_crate.out_dir())
+        .cfg("target_os", Some("linux"))
+        .cfg("unix", None)
+        .feature("shapes")
+        .generate()
+        .unwrap();
+
+    assert!(test_crate.exists("com/example/Point.java"));
+    assert!(!test_crate.read("com/example/Point.java").contains(" z"));
+    assert!(!test_crate.exists("com/example/Vector.java"));
+    assert!(!test_crate.exists("com/example/Hidden.java"));
+    assert!(test_crate
+        .read("com/example/Util.java")
+        .contains("public static void shown()"));
+}
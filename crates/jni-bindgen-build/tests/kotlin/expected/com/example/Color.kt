@@ -0,0 +1,13 @@
+package com.example
+
+/**
+ * A color
+ */
+enum class Color {
+    RED,
+    /**
+     * The color green
+     */
+    GREEN,
+    LIGHT_BLUE
+}
@@ -0,0 +1,7 @@
+package com.example
+
+import com.github.markusjx.jnibindgen.NativeExecutionException
+
+class InvalidShapeException(
+    message: String,
+) : NativeExecutionException(message)
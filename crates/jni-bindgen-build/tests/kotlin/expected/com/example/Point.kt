@@ -0,0 +1,9 @@
+package com.example
+
+/**
+ * A point
+ */
+data class Point(
+    val x: Double,
+    val y: Double,
+)
@@ -0,0 +1,159 @@
+package com.example
+
+import com.example.shapes.Size
+import com.github.markusjx.jnibindgen.NativeClass
+import com.github.markusjx.jnibindgen.NativeClassImpl
+import com.github.markusjx.jnibindgen.NativeExecutionException
+import com.github.markusjx.jnibindgen.NativePointer
+import java.util.concurrent.CompletableFuture
+import kotlin.coroutines.resume
+import kotlin.coroutines.resumeWithException
+import kotlin.coroutines.suspendCoroutine
+
+class Shape : NativeClassImpl<Shape.ShapeNative>, AutoCloseable {
+    private val inner: ShapeNative
+
+    /**
+     * Create a new shape
+     */
+    constructor(name: String, size: Size) {
+        inner = ShapeNative(name, size, this)
+    }
+
+    /**
+     * Wrap an existing native object.
+     * This is called by native code only.
+     */
+    private constructor(ptr: NativePointer) {
+        inner = ShapeNative(ptr, this)
+    }
+
+    /**
+     * The name of the shape
+     */
+    var name: String
+        get() = inner.getName()
+        set(value) = inner.setName(value)
+
+    val size: Size
+        get() = inner.getSize()
+
+    fun setTags(value: List<String>) = inner.setTags(value)
+
+    fun area(): Double = inner.area()
+
+    fun tag(index: Int): String? = inner.tag(index)
+
+    fun tagsByName(): Map<String, Int> = inner.tagsByName()
+
+    @Throws(com.example.InvalidShapeException::class, java.lang.IllegalStateException::class)
+    fun validate() = inner.validate()
+
+    @Throws(NativeExecutionException::class)
+    fun accept(visitor: ShapeVisitor): Boolean = inner.accept(visitor)
+
+    suspend fun sleep(millis: Long) = suspendCoroutine { continuation ->
+        inner.sleep(millis).whenComplete { result, error ->
+            if (error != null) {
+                continuation.resumeWithException(error)
+            } else {
+                continuation.resume(Unit)
+            }
+        }
+    }
+
+    fun sleepAsync(millis: Long): CompletableFuture<Void?> = inner.sleep(millis)
+
+    fun withName(name: String): Shape = inner.withName(name)
+
+    fun bytes(data: ByteArray): ByteArray = inner.bytes(data)
+
+    override fun getInner(): ShapeNative = inner
+
+    /**
+     * Destroy the native object, if it has not been destroyed yet.
+     */
+    override fun close() {
+        if (isValid()) {
+            destroyNative()
+        }
+    }
+
+    companion object {
+        /**
+         * Get the type of the underlying native class
+         * in a hashed form. This is used to check if
+         * the native class is the same as the one
+         * that is expected.
+         *
+         * @return The type hash of this class
+         */
+        @JvmStatic
+        fun getTypeHash(): Long = ShapeNative.getTypeHash()
+
+        suspend fun load(name: String): Shape = suspendCoroutine { continuation ->
+            ShapeNative.load(name).whenComplete { result, error ->
+                if (error != null) {
+                    continuation.resumeWithException(error)
+                } else {
+                    continuation.resume(result)
+                }
+            }
+        }
+
+        @JvmStatic
+        fun loadAsync(name: String): CompletableFuture<Shape> = ShapeNative.load(name)
+    }
+
+    class ShapeNative : NativeClass {
+        internal constructor(name: String, size: Size, referent: Any) : super(new(name, size), referent)
+
+        internal constructor(ptr: NativePointer, referent: Any) : super(ptr.ptr, referent)
+
+        external fun area(): Double
+
+        external fun tag(index: Int): String?
+
+        external fun tagsByName(): Map<String, Int>
+
+        external fun validate()
+
+        external fun accept(visitor: ShapeVisitor): Boolean
+
+        external fun sleep(millis: Long): CompletableFuture<Void?>
+
+        external fun withName(name: String): Shape
+
+        external fun bytes(data: ByteArray): ByteArray
+
+        external fun getName(): String
+
+        external fun setName(value: String)
+
+        external fun getSize(): Size
+
+        external fun setTags(value: List<String>)
+
+        override fun destruct() {
+            drop(getPtr())
+        }
+
+        companion object {
+            init {
+                System.loadLibrary("shapes")
+            }
+
+            @JvmStatic
+            external fun load(name: String): CompletableFuture<Shape>
+
+            @JvmStatic
+            external fun drop(self: Long)
+
+            @JvmStatic
+            external fun getTypeHash(): Long
+
+            @JvmStatic
+            external fun new(name: String, size: Size): Long
+        }
+    }
+}
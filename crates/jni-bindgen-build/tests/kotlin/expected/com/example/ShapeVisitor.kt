@@ -0,0 +1,16 @@
+package com.example
+
+import com.example.shapes.Size
+
+/**
+ * Called for every shape
+ */
+fun interface ShapeVisitor {
+    /**
+     * Visit a shape
+     *
+     * @param name The name of the shape
+     * @return Whether to continue
+     */
+    fun visit(name: String, size: Size): Boolean
+}
@@ -0,0 +1,39 @@
+package com.example
+
+import com.example.shapes.Size
+import com.github.markusjx.jnibindgen.NativeClass
+import com.github.markusjx.jnibindgen.NativeClassImpl
+
+/**
+ * A native implementation of [ShapeVisitor].
+ * Instances of this class are created by native code only.
+ */
+class ShapeVisitorImpl private constructor(ptr: Long) : ShapeVisitor, NativeClassImpl<ShapeVisitorImpl.ShapeVisitorImplNative>, AutoCloseable {
+    private val inner = ShapeVisitorImplNative(ptr, this)
+
+    override fun visit(name: String, size: Size): Boolean = inner.visit(name, size)
+
+    override fun getInner(): ShapeVisitorImplNative = inner
+
+    /**
+     * Destroy the native object, if it has not been destroyed yet.
+     */
+    override fun close() {
+        if (isValid()) {
+            destroyNative()
+        }
+    }
+
+    class ShapeVisitorImplNative internal constructor(ptr: Long, referent: Any) : NativeClass(ptr, referent) {
+        external fun visit(name: String, size: Size): Boolean
+
+        override fun destruct() {
+            drop(getPtr())
+        }
+
+        companion object {
+            @JvmStatic
+            external fun drop(ptr: Long)
+        }
+    }
+}
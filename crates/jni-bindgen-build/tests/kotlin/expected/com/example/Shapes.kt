@@ -0,0 +1,46 @@
+package com.example
+
+import com.example.shapes.Size
+import java.util.concurrent.CompletableFuture
+import kotlin.coroutines.resume
+import kotlin.coroutines.resumeWithException
+import kotlin.coroutines.suspendCoroutine
+
+object Shapes {
+    /**
+     * Get the area of a size
+     */
+    @JvmStatic
+    fun sizeArea(size: Size): Double = ShapesNative.sizeArea(size)
+
+    @JvmStatic
+    fun origin(): Point = ShapesNative.origin()
+
+    suspend fun distance(a: Point, b: Point): Double = suspendCoroutine { continuation ->
+        ShapesNative.distance(a, b).whenComplete { result, error ->
+            if (error != null) {
+                continuation.resumeWithException(error)
+            } else {
+                continuation.resume(result)
+            }
+        }
+    }
+
+    @JvmStatic
+    fun distanceAsync(a: Point, b: Point): CompletableFuture<Double> = ShapesNative.distance(a, b)
+
+    object ShapesNative {
+        init {
+            System.loadLibrary("shapes")
+        }
+
+        @JvmStatic
+        external fun sizeArea(size: Size): Double
+
+        @JvmStatic
+        external fun origin(): Point
+
+        @JvmStatic
+        external fun distance(a: Point, b: Point): CompletableFuture<Double>
+    }
+}
@@ -0,0 +1,9 @@
+package com.example.shapes
+
+import com.example.Color
+
+data class Size(
+    val width: Double,
+    val height: Double,
+    val color: Color?,
+)
@@ -0,0 +1,145 @@
+//! The sources of the Kotlin classes in `tests/kotlin/expected`.
+//! The classes are generated by the `generates_kotlin_classes`
+//! test and compiled using kotlinc in CI.
+use jni::JNIEnv;
+use jni_bindgen::{jni, JniError};
+use std::collections::HashMap;
+
+#[jni(package = "com.example")]
+/// A color
+pub enum Color {
+    Red,
+    /// The color green
+    Green,
+    #[jni(rename = "LIGHT_BLUE")]
+    Blue,
+}
+
+#[jni(package = "com.example", data)]
+/// A point
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[jni(package = "com.example.shapes", data)]
+pub struct Size {
+    width: f64,
+    height: f64,
+    color: Option<Color>,
+}
+
+#[derive(Debug, JniError)]
+#[jni(package = "com.example")]
+pub enum ShapeError {
+    #[jni(exception = "InvalidShapeException", generate)]
+    Invalid(String),
+    #[jni(exception = "java.lang.IllegalStateException")]
+    Closed,
+}
+
+#[jni(package = "com.example")]
+/// Called for every shape
+pub trait ShapeVisitor {
+    /// Visit a shape
+    ///
+    /// @param name The name of the shape
+    /// @return Whether to continue
+    fn visit(&self, env: &mut JNIEnv, name: String, size: Size) -> jni_bindgen::Result<bool>;
+}
+
+#[jni(package = "com.example")]
+pub struct Shape {
+    #[jni(getter, setter)]
+    /// The name of the shape
+    name: String,
+    #[jni(getter)]
+    size: Size,
+    #[jni(setter)]
+    tags: Vec<String>,
+}
+
+#[jni(package = "com.example", load_lib = "shapes")]
+impl Shape {
+    #[jni(constructor)]
+    /// Create a new shape
+    fn new(name: String, size: Size) -> Self {
+        Self {
+            name,
+            size,
+            tags: Vec::new(),
+        }
+    }
+
+    #[jni]
+    fn area(&self) -> f64 {
+        self.size.width * self.size.height
+    }
+
+    #[jni]
+    fn tag(&self, index: i32) -> Option<String> {
+        self.tags.get(index as usize).cloned()
+    }
+
+    #[jni]
+    fn tags_by_name(&self) -> HashMap<String, i32> {
+        HashMap::new()
+    }
+
+    #[jni]
+    fn validate(&self) -> Result<(), ShapeError> {
+        Ok(())
+    }
+
+    #[jni]
+    fn accept(
+        &self,
+        env: &mut JNIEnv,
+        visitor: Box<dyn ShapeVisitor + '_>,
+    ) -> jni_bindgen::Result<bool> {
+        visitor.visit(env, self.name.clone(), Size { ..self.size })
+    }
+
+    #[jni]
+    async fn load(name: String) -> jni_bindgen::Result<Self> {
+        Ok(Self::new(
+            name,
+            Size {
+                width: 1.0,
+                height: 1.0,
+                color: None,
+            },
+        ))
+    }
+
+    #[jni]
+    async fn sleep(&self, millis: i64) {
+        let _ = millis;
+    }
+
+    #[jni]
+    fn with_name(&self, name: String) -> Self {
+        Self::new(name, Size { ..self.size })
+    }
+
+    #[jni]
+    fn bytes(&self, data: &[u8]) -> Vec<u8> {
+        data.to_vec()
+    }
+}
+
+#[jni(package = "com.example", class = "Shapes", load_lib = "shapes")]
+/// Get the area of a size
+fn size_area(size: Size) -> f64 {
+    size.width * size.height
+}
+
+#[jni(package = "com.example", class = "Shapes")]
+fn origin() -> Point {
+    Point { x: 0.0, y: 0.0 }
+}
+
+#[jni(package = "com.example", class = "Shapes")]
+async fn distance(a: Point, b: Point) -> f64 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
@@ -0,0 +1,389 @@
+use std::collections::HashSet;
+
+const DESTRUCT: &str = r#"
+    @Override
+    protected void destruct() {
+        drop(this.ptr);
+    }
+"#;
+
+pub fn interface(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    methods: String,
+    additional_imports: HashSet<String>,
+) -> String {
+    format_code(format!(
+        r#"
+    package {namespace};
+    
+    {}
+    
+    {comment}
+    public interface {class_name} {{
+        {methods}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn interface_impl_class(
+    namespace: &str,
+    interface_name: &str,
+    methods: String,
+    native_methods: String,
+    mut additional_imports: HashSet<String>,
+) -> String {
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClass".to_string());
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClassImpl".to_string());
+    let class_name = format!("{interface_name}Impl");
+
+    format_code(format!(
+        r#"
+    package {namespace};
+
+    {}
+
+    /**
+     * A native implementation of {{@link {interface_name}}}.
+     * Instances of this class are created by native code only.
+     */
+    public class {class_name} implements {interface_name}, NativeClassImpl<{class_name}.{class_name}Native> {{
+        private final {class_name}Native inner;
+
+        private {class_name}(long ptr) {{
+            inner = new {class_name}Native(ptr, this);
+        }}
+
+        {methods}
+
+        @Override
+        public {class_name}Native getInner() {{
+            return inner;
+        }}
+
+        public static class {class_name}Native extends NativeClass {{
+            private {class_name}Native(long ptr, Object referent) {{
+                super(ptr, referent);
+            }}
+
+            {native_methods}
+
+            private static native void drop(long ptr);
+            {DESTRUCT}
+        }}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn util_class(
+    namespace: &str,
+    class_name: &str,
+    methods: String,
+    native_methods: String,
+    load_lib: Option<String>,
+    additional_imports: HashSet<String>,
+) -> String {
+    let init_lib = if let Some(init) = load_lib {
+        load_library(&init)
+    } else {
+        String::new()
+    };
+
+    format_code(format!(
+        r#"
+    package {namespace};
+
+    {}
+
+    public final class {class_name} {{
+        /**
+         * Disable instantiation of {class_name}
+         */
+        private {class_name}() {{
+            throw new UnsupportedOperationException("{class_name} cannot be instantiated");
+        }}
+
+        {methods}
+
+        public static class {class_name}Native {{
+            {init_lib}
+            private {class_name}Native() {{
+                throw new UnsupportedOperationException("{class_name}Native cannot be instantiated");
+            }}
+
+            {native_methods}
+        }}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn enum_class(namespace: &str, class_name: &str, comment: String, variants: String) -> String {
+    format_code(format!(
+        r#"
+    package {namespace};
+
+    {comment}
+    public enum {class_name} {{
+        {variants};
+    }}
+    "#
+    ))
+}
+
+pub fn exception_class(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    fields: Vec<(String, String)>,
+    additional_imports: HashSet<String>,
+) -> String {
+    let declarations = fields
+        .iter()
+        .map(|(ty, name)| format!("private final {ty} {name};"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let args = fields
+        .iter()
+        .map(|(ty, name)| format!(", {ty} {name}"))
+        .collect::<String>();
+    let assignments = fields
+        .iter()
+        .map(|(_, name)| format!("this.{name} = {name};"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let getters = fields
+        .iter()
+        .map(|(ty, name)| format!("public {ty} {name}() {{\nreturn {name};\n}}"))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    format_code(format!(
+        r#"
+    package {namespace};
+
+    import com.github.markusjx.jnibindgen.NativeExecutionException;
+    {}
+
+    {comment}
+    public class {class_name} extends NativeExecutionException {{
+        {declarations}
+
+        public {class_name}(String message{args}) {{
+            super(message);
+            {assignments}
+        }}
+
+        {getters}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn record_class(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    fields: String,
+    additional_imports: HashSet<String>,
+) -> String {
+    format_code(format!(
+        r#"
+    package {namespace};
+
+    {}
+
+    {comment}
+    public record {class_name}({fields}) {{
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn outer_class(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    methods: String,
+    constructors: String,
+    inner: String,
+    mut additional_imports: HashSet<String>,
+) -> String {
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClass".to_string());
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClassImpl".to_string());
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativePointer".to_string());
+
+    format_code(format!(
+        r#"package {};
+
+        {}
+        
+        {comment}
+        public class {class_name} implements NativeClassImpl<{class_name}.{class_name}Native> {{
+    private final {class_name}Native inner;
+    
+    {constructors}
+
+    /**
+     * Wrap an existing native object.
+     * This is called by native code only.
+     */
+    private {class_name}(NativePointer ptr) {{
+        inner = new {class_name}Native(ptr, this);
+    }}
+
+    {methods}
+    
+    /**
+     * Get the type of the underlying native class
+     * in a hashed form. This is used to check if
+     * the native class is the same as the one
+     * that is expected.
+     *
+     * @return The type hash of this class
+     */
+    public static long getTypeHash() {{
+        return {class_name}Native.getTypeHash();
+    }}
+
+    @Override
+    public {class_name}Native getInner() {{
+        return inner;
+    }}
+
+    {inner}
+}}"#,
+        namespace,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn inner_class(
+    class_name: &str,
+    methods: String,
+    constructors: String,
+    load_lib: Option<String>,
+) -> String {
+    let init_lib = if let Some(init) = load_lib {
+        load_library(&init)
+    } else {
+        String::new()
+    };
+
+    format!(
+        r#"
+        public static class {class_name}Native extends NativeClass {{
+            {init_lib}
+            {constructors}
+            private {class_name}Native(NativePointer ptr, Object referent) {{
+                super(ptr.getPtr(), referent);
+            }}
+            {methods}
+            {DESTRUCT}
+        }}
+    "#
+    )
+}
+
+fn load_library(lib_name: &str) -> String {
+    format!(
+        r#"        static {{
+            System.loadLibrary("{lib_name}");
+        }}"#
+    )
+}
+
+fn format_code(code: String) -> String {
+    let mut indent = 0;
+    let split = code
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+
+    split
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if line.starts_with('}') {
+                indent -= 1;
+            }
+
+            let next = split.get(i + 1).map(|s| s.to_string()).unwrap_or_default();
+            let last = if i > 0 {
+                split.get(i - 1).map(|s| s.to_string()).unwrap_or_default()
+            } else {
+                "".to_string()
+            };
+
+            let res = format!(
+                "{pre_newline}{indent}{line}{newline}",
+                pre_newline = if ((line.starts_with("public class")
+                    || line.starts_with("public final class")
+                    || line.starts_with("public record")
+                    || line.starts_with("public enum"))
+                    && !last.contains('*'))
+                    || (line.starts_with("/**") && last.starts_with("import"))
+                {
+                    "\n"
+                } else {
+                    ""
+                },
+                indent = if line.starts_with('*') {
+                    " ".repeat(indent * 4 + 1)
+                } else {
+                    " ".repeat(indent * 4)
+                },
+                newline = if !line.ends_with('{')
+                    && !line.ends_with(',')
+                    && !line.contains('*')
+                    && (!line.ends_with(';')
+                        || line.contains("native")
+                        || line.contains("private")
+                        || line.contains("package"))
+                    && !line.contains('@')
+                    && !next.contains('}')
+                {
+                    "\n"
+                } else {
+                    ""
+                }
+            );
+
+            if line.ends_with('{') {
+                indent += 1;
+            }
+
+            res
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Generate the import statements, sorted in order
+/// to keep the generated code stable between builds.
+/// Classes of the package `namespace` are not imported.
+fn imports(namespace: &str, imports: HashSet<String>) -> String {
+    let mut imports = imports
+        .into_iter()
+        .filter(|i| {
+            i.rsplit_once('.')
+                .is_some_and(|(package, _)| package != namespace)
+        })
+        .collect::<Vec<_>>();
+    imports.sort();
+
+    imports
+        .into_iter()
+        .map(|i| format!("import {i};"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
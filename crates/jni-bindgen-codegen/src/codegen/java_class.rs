@@ -0,0 +1,286 @@
+use crate::codegen::code::{inner_class, outer_class};
+use crate::codegen::java_field::JavaFields;
+use crate::codegen::java_method::{check_overloads, JavaMethod};
+use crate::codegen::kotlin;
+use crate::codegen::signature::JniSignature;
+use crate::codegen::traits::FromDeclaration;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::context::context;
+use crate::util::quotes;
+use crate::util::traits::{GetComment, JniMethod};
+use proc_macro2::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{ImplItem, ItemImpl, Type};
+
+pub struct JavaClass {
+    pub name: String,
+    pub methods: Vec<JavaMethod>,
+    pub constructors: Vec<JavaMethod>,
+    pub namespace: String,
+    /// The field accessors of the struct, which are
+    /// generated when expanding the struct itself.
+    /// These are only known to the build script generator.
+    fields: Option<JavaFields>,
+    attrs: BindgenAttrs,
+    decl: ItemImpl,
+}
+
+impl JavaClass {
+    pub fn from_declaration(decl: &ItemImpl, args: &BindgenAttrs) -> syn::Result<Self> {
+        let name = decl.self_ty.clone().into_token_stream().to_string();
+        let Type::Path(self_ty) = decl.self_ty.as_ref() else {
+            return Err(syn::Error::new(
+                decl.self_ty.span(),
+                "Only impl blocks for named structs are supported",
+            ));
+        };
+
+        let methods = decl
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ImplItem::Fn(m) => {
+                    if m.is_constructor() || !m.has_jni() {
+                        None
+                    } else {
+                        Some(JavaMethod::from_declaration(m).map(|mut m| {
+                            m.return_type = m.return_type.map(|r| r.replace_self(self_ty, &name));
+                            m
+                        }))
+                    }
+                }
+                _ => None,
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let constructors = decl
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                ImplItem::Fn(m) => {
+                    if m.has_jni() && m.is_constructor() {
+                        Some(JavaMethod::from_declaration(m))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let namespace = args.get_namespace()?;
+        let fields = match context() {
+            Some(context) => context.fields(&namespace, &name)?,
+            None => None,
+        };
+
+        let accessors = fields.iter().flat_map(|f| f.methods()).collect::<Vec<_>>();
+        check_overloads(
+            &["drop", "getTypeHash"],
+            methods.iter().chain(&constructors).chain(&accessors),
+        )?;
+
+        Ok(Self {
+            name,
+            methods,
+            constructors,
+            fields,
+            attrs: args.clone(),
+            namespace,
+            decl: decl.clone(),
+        })
+    }
+
+    pub fn as_jni_methods(&self, args: &BindgenAttrs) -> syn::Result<TokenStream> {
+        let namespace = args.get_namespace()?.replace('.', "_");
+        let base_name = quotes::base_name(&namespace, &self.name);
+        let methods: TokenStream = self
+            .methods
+            .iter()
+            .map(|m| {
+                m.as_jni_method(base_name.clone(), &self.name)
+                    .map(|m| m.to_string())
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .join("\n")
+            .parse()?;
+
+        let constructors: TokenStream = self
+            .constructors
+            .iter()
+            .map(|m| {
+                m.as_jni_method(base_name.clone(), &self.name)
+                    .map(|m| m.to_string())
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .join("\n")
+            .parse()?;
+
+        let drop = quotes::drop_struct(base_name.parse()?, self.name.clone());
+        let get_type_hash = quotes::get_type_hash(base_name.parse()?, self.name.clone());
+        let from_jni = quotes::from_jni(self.name.clone(), self.attrs.is_unchecked());
+        let into_jni = quotes::into_jni(
+            self.name.clone(),
+            format!("{}/{}", self.namespace.replace('.', "/"), self.name),
+        );
+
+        Ok(quote!(
+            #methods
+            #constructors
+            #drop
+            #get_type_hash
+            #from_jni
+            #into_jni
+        ))
+    }
+
+    /// Get the signatures of all native methods of the inner class.
+    pub fn signatures(&self) -> Vec<JniSignature> {
+        let base_name = quotes::base_name(&self.namespace.replace('.', "_"), &self.name);
+        let class_name = format!(
+            "{}/{}${}Native",
+            self.namespace.replace('.', "/"),
+            self.name,
+            self.name
+        );
+
+        let mut methods = self.methods.clone();
+        methods.push(JavaMethod::drop_method());
+        methods.push(JavaMethod::get_type_hash());
+        methods.append(&mut self.constructors.clone());
+
+        methods
+            .iter()
+            .map(|m| m.as_native_signature(&class_name, &base_name, &self.namespace))
+            .collect()
+    }
+
+    /// Get the methods accessing the fields of the struct.
+    fn accessors(&self) -> Vec<JavaMethod> {
+        self.fields
+            .as_ref()
+            .map(|fields| fields.methods())
+            .unwrap_or_default()
+    }
+
+    fn get_imports(&self) -> HashSet<String> {
+        let mut imports = self
+            .methods
+            .iter()
+            .chain(self.accessors().iter())
+            .flat_map(|m| m.get_imports())
+            .collect::<HashSet<String>>();
+
+        imports.extend(self.constructors.iter().flat_map(|m| m.get_imports()));
+
+        imports
+    }
+
+    pub fn as_declaration(&self) -> String {
+        let accessors = self.accessors();
+        let mut methods_copy = self.methods.clone();
+        methods_copy.extend(accessors.iter().cloned());
+        methods_copy.push(JavaMethod::drop_method());
+        methods_copy.push(JavaMethod::get_type_hash());
+        methods_copy.append(&mut self.constructors.clone());
+
+        let inner = inner_class(
+            &self.name,
+            methods_copy
+                .iter()
+                .map(|m| m.as_declaration(&self.name, false))
+                .collect::<Vec<_>>()
+                .join("\n\t"),
+            self.constructors
+                .iter()
+                .map(|m| m.as_constructor(&format!("{}Native", self.name), true))
+                .collect::<Vec<_>>()
+                .join("\n\t"),
+            self.attrs.load_lib(),
+        );
+
+        outer_class(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            self.methods
+                .iter()
+                .chain(accessors.iter())
+                .map(|m| m.as_declaration(&self.name, true))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            self.constructors
+                .iter()
+                .map(|m| m.as_constructor(&self.name, false))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            inner,
+            self.get_imports(),
+        )
+    }
+
+    pub fn as_kotlin_declaration(&self) -> String {
+        let accessors = self.accessors();
+        let mut natives = self.methods.clone();
+        natives.extend(accessors.iter().cloned());
+        natives.push(JavaMethod::drop_method());
+        natives.push(JavaMethod::get_type_hash());
+        natives.append(&mut self.constructors.clone());
+
+        let (static_natives, natives): (Vec<_>, Vec<_>) =
+            natives.iter().partition(|m| m.static_method);
+        let (static_methods, methods): (Vec<_>, Vec<_>) =
+            self.methods.iter().partition(|m| m.static_method);
+        let as_kotlin_declarations = |methods: Vec<&JavaMethod>, definition: bool| {
+            methods
+                .iter()
+                .map(|m| m.as_kotlin_declaration(&self.name, definition))
+                .collect::<Vec<_>>()
+                .join("\n\n")
+        };
+
+        let inner = kotlin::inner_class(
+            &self.name,
+            as_kotlin_declarations(natives, false),
+            as_kotlin_declarations(static_natives, false),
+            self.constructors
+                .iter()
+                .map(|m| m.as_kotlin_constructor(&self.name, true))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            self.attrs.load_lib(),
+        );
+
+        let imports = self
+            .methods
+            .iter()
+            .chain(self.constructors.iter())
+            .chain(accessors.iter())
+            .flat_map(|m| m.get_kotlin_imports())
+            .collect::<HashSet<_>>();
+
+        let properties = self
+            .fields
+            .as_ref()
+            .map(|fields| fields.as_kotlin_declaration())
+            .unwrap_or_default();
+
+        kotlin::class(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            [properties, as_kotlin_declarations(methods, true)].join("\n\n"),
+            as_kotlin_declarations(static_methods, true),
+            self.constructors
+                .iter()
+                .map(|m| m.as_kotlin_constructor(&self.name, false))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            inner,
+            imports,
+        )
+    }
+}
@@ -0,0 +1,445 @@
+use crate::codegen::code::{exception_class, record_class};
+use crate::codegen::java_type::{DescriptorPart, JavaType};
+use crate::codegen::kotlin;
+use crate::codegen::traits::FromDeclaration;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::quotes;
+use crate::util::traits::GetComment;
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{Fields, Ident, ItemStruct, Type};
+
+pub struct JavaDataField {
+    pub name: String,
+    pub ident: Ident,
+    pub java_type: JavaType,
+    ty: Type,
+}
+
+impl JavaDataField {
+    fn primitive_getter(java_type: &JavaType) -> Option<TokenStream> {
+        Some(match java_type {
+            JavaType::Integer => quote!(i),
+            JavaType::Long => quote!(j),
+            JavaType::Boolean => quote!(z),
+            JavaType::Float => quote!(f),
+            JavaType::Double => quote!(d),
+            JavaType::Short => quote!(s),
+            JavaType::Char => quote!(c),
+            JavaType::Byte => quote!(b),
+            _ => return None,
+        })
+    }
+
+    fn as_from_jni(&self, namespace: &str) -> TokenStream {
+        let ident = &self.ident;
+        let name = &self.name;
+        let sig = self.java_type.as_jni_declaration(namespace);
+
+        if let Some(getter) = Self::primitive_getter(&self.java_type) {
+            return quote!(#ident: env.get_field(&obj, #name, #sig)?.#getter()?,);
+        }
+
+        if let JavaType::CheckedInt { ty, java_type } = &self.java_type {
+            let getter = Self::primitive_getter(java_type);
+            return quote! {
+                #ident: <#ty as jni_bindgen::conversion::int_convert::CheckedInt>::from_java(
+                    env.get_field(&obj, #name, #sig)?.#getter()?
+                )?,
+            };
+        }
+
+        let convert = match &self.java_type {
+            JavaType::Option { java_type, .. } if matches!(**java_type, JavaType::Value { .. }) => {
+                let JavaType::Value { inner, .. } = java_type.as_ref() else {
+                    unreachable!()
+                };
+
+                quote! {
+                    if value.is_null() {
+                        None
+                    } else {
+                        Some(<#inner>::from_jni(env, value)?)
+                    }
+                }
+            }
+            JavaType::Array { ty, .. } => {
+                quote!(jni_bindgen::conversion::array_convert::into_vec::<#ty>(env, value)?)
+            }
+            JavaType::Option { java_type, .. } if matches!(**java_type, JavaType::Array { .. }) => {
+                let JavaType::Array { ty, .. } = java_type.as_ref() else {
+                    unreachable!()
+                };
+
+                quote! {
+                    if value.is_null() {
+                        None
+                    } else {
+                        Some(jni_bindgen::conversion::array_convert::into_vec::<#ty>(env, value)?)
+                    }
+                }
+            }
+            _ => {
+                let ty = &self.ty;
+                quote!(<#ty>::from_jni(env, value)?)
+            }
+        };
+
+        let sig = quotes::descriptor(self.java_type.as_jni_descriptor_parts(namespace));
+        quote! {
+            #ident: {
+                let value = env
+                    .get_field(&obj, #name, jni_bindgen::natives::descriptor(#sig).as_ref())?
+                    .l()?;
+                #convert
+            },
+        }
+    }
+
+    /// Convert the field into a `JValue` named `arg_name`. Fields of error
+    /// structs are cloned, as the error is converted by reference.
+    fn as_into_jni(&self, arg_name: &Ident, clone: bool) -> TokenStream {
+        let ident = &self.ident;
+        let value = if clone {
+            quote!(self.#ident.clone())
+        } else {
+            quote!(self.#ident)
+        };
+
+        if Self::primitive_getter(&self.java_type).is_some() {
+            return quote!(let #arg_name = jni::objects::JValue::from(#value););
+        }
+
+        if let JavaType::CheckedInt { ty, .. } = &self.java_type {
+            return quote! {
+                let #arg_name = jni::objects::JValue::from(
+                    <#ty as jni_bindgen::conversion::int_convert::CheckedInt>::into_java(#value)?
+                );
+            };
+        }
+
+        let inner_arg_name = Ident::new(&format!("{arg_name}_inner"), arg_name.span());
+        let convert = match &self.java_type {
+            JavaType::Option { java_type, .. } if matches!(**java_type, JavaType::Value { .. }) => {
+                quote! {
+                    match #value {
+                        Some(value) => jni_bindgen::objects::traits::IntoJNI::into_jni(value, env)?,
+                        None => jni::objects::JObject::null(),
+                    }
+                }
+            }
+            JavaType::Array { ty, .. } => {
+                quote! {
+                    unsafe {
+                        jni::objects::JObject::from_raw(
+                            jni_bindgen::conversion::array_convert::from_slice::<#ty>(env, &self.#ident)?
+                        )
+                    }
+                }
+            }
+            JavaType::Option { java_type, .. } if matches!(**java_type, JavaType::Array { .. }) => {
+                let JavaType::Array { ty, .. } = java_type.as_ref() else {
+                    unreachable!()
+                };
+
+                quote! {
+                    match #value {
+                        Some(value) => unsafe {
+                            jni::objects::JObject::from_raw(
+                                jni_bindgen::conversion::array_convert::from_slice::<#ty>(env, &value)?
+                            )
+                        },
+                        None => jni::objects::JObject::null(),
+                    }
+                }
+            }
+            _ => quote!(jni_bindgen::objects::traits::IntoJNI::into_jni(#value, env)?),
+        };
+
+        quote! {
+            let #inner_arg_name = #convert;
+            let #arg_name = jni::objects::JValue::from(&#inner_arg_name);
+        }
+    }
+}
+
+/// A data struct mapped to a java record or, if annotated
+/// with `#[jni(error)]`, an error struct mapped to a java
+/// exception class with a field for every struct field.
+pub struct JavaDataClass {
+    pub name: String,
+    pub fields: Vec<JavaDataField>,
+    pub namespace: String,
+    pub error: bool,
+    decl: ItemStruct,
+}
+
+impl JavaDataClass {
+    pub fn from_declaration(decl: &ItemStruct, args: &BindgenAttrs) -> syn::Result<Self> {
+        let Fields::Named(named) = &decl.fields else {
+            return Err(syn::Error::new(
+                decl.fields.span(),
+                "Data classes must have named fields",
+            ));
+        };
+
+        let fields = named
+            .named
+            .iter()
+            .map(|field| {
+                let java_type = JavaType::from_declaration(&Box::new(field.ty.clone()))?;
+                if matches!(
+                    java_type,
+                    JavaType::This
+                        | JavaType::Void
+                        | JavaType::Env { .. }
+                        | JavaType::Result { .. }
+                        | JavaType::Reference { .. }
+                        | JavaType::Interface { .. }
+                        | JavaType::DirectBuffer { .. }
+                        | JavaType::Array { slice: true, .. }
+                ) {
+                    return Err(syn::Error::new(
+                        field.ty.span(),
+                        "Unsupported data class field type",
+                    ));
+                }
+
+                let ident = field.ident.clone().unwrap();
+                Ok(JavaDataField {
+                    name: ident.to_string().to_case(Case::Camel),
+                    ident,
+                    java_type,
+                    ty: field.ty.clone(),
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name: decl.ident.clone().into_token_stream().to_string(),
+            fields,
+            namespace: args.get_namespace()?,
+            error: args.is_error(),
+            decl: decl.clone(),
+        })
+    }
+
+    fn class_name(&self) -> String {
+        format!("{}/{}", self.namespace.replace('.', "/"), self.name)
+    }
+
+    fn arg_names(&self) -> Vec<Ident> {
+        (0..self.fields.len())
+            .map(|i| Ident::new(&format!("arg_{i}"), proc_macro2::Span::call_site()))
+            .collect()
+    }
+
+    /// Get the descriptor of the constructor, taking
+    /// the given arguments followed by all fields.
+    fn ctor_sig(&self, args: &str) -> TokenStream {
+        let fields = self
+            .fields
+            .iter()
+            .flat_map(|f| f.java_type.as_jni_descriptor_parts(&self.namespace));
+
+        quotes::descriptor(
+            std::iter::once(DescriptorPart::Literal(format!("({args}")))
+                .chain(fields)
+                .chain(std::iter::once(DescriptorPart::Literal(")V".to_string()))),
+        )
+    }
+
+    pub fn as_jni_methods(&self) -> syn::Result<TokenStream> {
+        if self.error {
+            return self.as_error_methods();
+        }
+
+        let struct_name: TokenStream = self.name.parse()?;
+        let class_name = self.class_name();
+        let ctor_sig = self.ctor_sig("");
+
+        let from_fields = self.fields.iter().map(|f| f.as_from_jni(&self.namespace));
+
+        let arg_names = self.arg_names();
+        let into_args = self
+            .fields
+            .iter()
+            .zip(arg_names.iter())
+            .map(|(f, arg_name)| f.as_into_jni(arg_name, false));
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl jni_bindgen::objects::traits::ClassName for #struct_name {
+                const CLASS_NAME: &'static str = #class_name;
+            }
+
+            #[automatically_derived]
+            impl<'local> FromJNI<'local> for #struct_name {
+                fn from_jni(
+                    env: &mut jni::JNIEnv<'local>,
+                    obj: jni::objects::JObject<'local>,
+                ) -> jni_bindgen::Result<Self> {
+                    if obj.is_null() {
+                        return Err(jni_bindgen::error_class!(
+                            jni_bindgen::errors::jni_error::ErrorClass::NullPointer,
+                            "The value is null"
+                        ));
+                    }
+
+                    Ok(Self {
+                        #(#from_fields)*
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl jni_bindgen::objects::traits::IntoJNI for #struct_name {
+                fn into_jni<'a>(
+                    self,
+                    env: &mut jni::JNIEnv<'a>,
+                ) -> jni_bindgen::Result<jni::objects::JObject<'a>> {
+                    static CLASS: jni_bindgen::cache::CachedClass =
+                        jni_bindgen::cache::CachedClass::new(#class_name);
+
+                    #(#into_args)*
+
+                    let class = CLASS.get(env)?;
+                    env.new_object(
+                        class,
+                        jni_bindgen::natives::descriptor(#ctor_sig).as_ref(),
+                        &[#(#arg_names),*],
+                    )
+                        .map_err(Into::into)
+                }
+            }
+        })
+    }
+
+    /// Convert the error into a `JNIError` creating an instance
+    /// of the generated exception class once thrown.
+    fn as_error_methods(&self) -> syn::Result<TokenStream> {
+        let struct_name: TokenStream = self.name.parse()?;
+        let class_name = self.class_name();
+        let ctor_sig = self.ctor_sig("Ljava/lang/String;");
+
+        let arg_names = self.arg_names();
+        let into_args = self
+            .fields
+            .iter()
+            .zip(arg_names.iter())
+            .map(|(f, arg_name)| f.as_into_jni(arg_name, true));
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl jni_bindgen::objects::traits::ClassName for #struct_name {
+                const CLASS_NAME: &'static str = #class_name;
+            }
+
+            #[automatically_derived]
+            impl jni_bindgen::errors::jni_error::ThrowableError for #struct_name {
+                fn class_name(&self) -> &'static str {
+                    #class_name
+                }
+
+                fn to_throwable<'a>(
+                    &self,
+                    env: &mut jni::JNIEnv<'a>,
+                    message: &str,
+                ) -> jni_bindgen::Result<jni::objects::JThrowable<'a>> {
+                    static CLASS: jni_bindgen::cache::CachedClass =
+                        jni_bindgen::cache::CachedClass::new(#class_name);
+
+                    let message = env.new_string(message)?;
+                    let message = jni::objects::JValue::from(&message);
+                    #(#into_args)*
+
+                    let class = CLASS.get(env)?;
+                    env.new_object(
+                        class,
+                        jni_bindgen::natives::descriptor(#ctor_sig).as_ref(),
+                        &[message, #(#arg_names),*],
+                    )
+                        .map(jni::objects::JThrowable::from)
+                        .map_err(Into::into)
+                }
+            }
+
+            #[automatically_derived]
+            impl From<#struct_name> for jni_bindgen::errors::jni_error::JNIError {
+                fn from(value: #struct_name) -> Self {
+                    jni_bindgen::errors::jni_error::JNIError::new(
+                        value.to_string(),
+                        Some(jni_bindgen::errors::jni_error::ErrorClass::Throwable(
+                            std::sync::Arc::new(value),
+                        )),
+                    )
+                }
+            }
+        })
+    }
+
+    fn get_imports(&self) -> HashSet<String> {
+        self.fields
+            .iter()
+            .flat_map(|f| f.java_type.get_imports())
+            .collect()
+    }
+
+    pub fn as_java_declaration(&self) -> String {
+        if self.error {
+            return exception_class(
+                &self.namespace,
+                &self.name,
+                self.decl.attrs.get_comment().unwrap_or_default(),
+                self.fields
+                    .iter()
+                    .map(|f| (f.java_type.as_declaration().unwrap(), f.name.clone()))
+                    .collect(),
+                self.get_imports(),
+            );
+        }
+
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| format!("{} {}", f.java_type.as_declaration().unwrap(), f.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        record_class(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            fields,
+            self.get_imports(),
+        )
+    }
+
+    pub fn as_kotlin_declaration(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.java_type.as_kotlin_declaration().unwrap(),
+                    kotlin::escape(f.name.clone()),
+                )
+            })
+            .collect();
+        let imports = self
+            .fields
+            .iter()
+            .flat_map(|f| f.java_type.get_kotlin_imports())
+            .collect();
+        let comment = self.decl.attrs.get_comment().unwrap_or_default();
+
+        if self.error {
+            kotlin::exception_class(&self.namespace, &self.name, comment, fields, imports)
+        } else {
+            kotlin::data_class(&self.namespace, &self.name, comment, fields, imports)
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::codegen::code::enum_class;
+use crate::codegen::kotlin;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::traits::{GetComment, JniMethod};
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{Fields, Ident, ItemEnum};
+
+pub struct JavaEnumVariant {
+    pub name: String,
+    pub ident: Ident,
+    comment: Option<String>,
+}
+
+pub struct JavaEnum {
+    pub name: String,
+    pub variants: Vec<JavaEnumVariant>,
+    pub namespace: String,
+    decl: ItemEnum,
+}
+
+impl JavaEnum {
+    pub fn from_declaration(decl: &ItemEnum, args: &BindgenAttrs) -> syn::Result<Self> {
+        let name = decl.ident.clone().into_token_stream().to_string();
+        let variants = decl
+            .variants
+            .iter()
+            .map(|variant| {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(syn::Error::new(
+                        variant.fields.span(),
+                        "Only enums without fields are supported",
+                    ));
+                }
+
+                Ok(JavaEnumVariant {
+                    name: (&variant.attrs)
+                        .get_rename()
+                        .unwrap_or_else(|| variant.ident.to_string().to_case(Case::UpperSnake)),
+                    ident: variant.ident.clone(),
+                    comment: variant.attrs.get_comment(),
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name,
+            variants,
+            namespace: args.get_namespace()?,
+            decl: decl.clone(),
+        })
+    }
+
+    pub fn as_jni_methods(&self) -> syn::Result<TokenStream> {
+        let enum_name: TokenStream = self.name.parse()?;
+        let class_name = format!("{}/{}", self.namespace.replace('.', "/"), self.name);
+        let class_sig = format!("L{class_name};");
+
+        let from_ordinal = self.variants.iter().enumerate().map(|(i, v)| {
+            let ident = &v.ident;
+            let i = i as i32;
+            quote!(#i => Ok(#enum_name::#ident),)
+        });
+
+        let into_name = self.variants.iter().map(|v| {
+            let ident = &v.ident;
+            let name = &v.name;
+            quote!(#enum_name::#ident => #name,)
+        });
+
+        let invalid_ordinal = format!("Invalid ordinal {{}} for enum {}", self.name);
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl jni_bindgen::objects::traits::ClassName for #enum_name {
+                const CLASS_NAME: &'static str = #class_name;
+            }
+
+            #[automatically_derived]
+            impl<'local> FromJNI<'local> for #enum_name {
+                fn from_jni(
+                    env: &mut jni::JNIEnv<'local>,
+                    obj: jni::objects::JObject<'local>,
+                ) -> jni_bindgen::Result<Self> {
+                    if obj.is_null() {
+                        return Err(jni_bindgen::error_class!(
+                            jni_bindgen::errors::jni_error::ErrorClass::NullPointer,
+                            "The value is null"
+                        ));
+                    }
+
+                    let ordinal = env.call_method(&obj, "ordinal", "()I", &[])?.i()?;
+                    match ordinal {
+                        #(#from_ordinal)*
+                        _ => Err(jni_bindgen::error_class!(
+                            jni_bindgen::errors::jni_error::ErrorClass::IllegalArgument,
+                            #invalid_ordinal,
+                            ordinal
+                        )),
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl jni_bindgen::objects::traits::IntoJNI for #enum_name {
+                fn into_jni<'a>(
+                    self,
+                    env: &mut jni::JNIEnv<'a>,
+                ) -> jni_bindgen::Result<jni::objects::JObject<'a>> {
+                    static CLASS: jni_bindgen::cache::CachedClass =
+                        jni_bindgen::cache::CachedClass::new(#class_name);
+
+                    let name = match self {
+                        #(#into_name)*
+                    };
+
+                    let class = CLASS.get(env)?;
+                    env.get_static_field(
+                        class,
+                        name,
+                        jni_bindgen::natives::descriptor(#class_sig).as_ref(),
+                    )?
+                        .l()
+                        .map_err(Into::into)
+                }
+            }
+        })
+    }
+
+    pub fn as_java_declaration(&self) -> String {
+        let variants = self
+            .variants
+            .iter()
+            .map(|v| format!("{}{}", v.comment.clone().unwrap_or_default(), v.name))
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        enum_class(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            variants,
+        )
+    }
+
+    pub fn as_kotlin_declaration(&self) -> String {
+        let variants = self
+            .variants
+            .iter()
+            .map(|v| format!("{}{}", v.comment.clone().unwrap_or_default(), v.name))
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        kotlin::enum_class(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            variants,
+        )
+    }
+}
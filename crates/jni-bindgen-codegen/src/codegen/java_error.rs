@@ -0,0 +1,177 @@
+use crate::codegen::code::exception_class;
+use crate::codegen::kotlin;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::language::{default_language, Language};
+use crate::util::traits::{AnyAttribute, GetComment};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, Ident};
+
+const NATIVE_EXECUTION_EXCEPTION: &str = "com.github.markusjx.jnibindgen.NativeExecutionException";
+
+pub struct JavaErrorVariant {
+    pub ident: Ident,
+    /// The fully qualified name of the exception class.
+    pub exception: String,
+    pub generate: bool,
+    fields: Fields,
+    comment: Option<String>,
+}
+
+/// An error enum deriving `JniError`. Every variant is mapped to
+/// a java exception class. As the error type is expanded separately
+/// from the methods returning it, the exception classes are collected
+/// in the context of the build script generator, so the generated
+/// methods can declare them.
+pub struct JavaError {
+    pub name: String,
+    pub variants: Vec<JavaErrorVariant>,
+    /// The language the exception classes are generated in.
+    pub language: Language,
+    ident: Ident,
+}
+
+impl JavaError {
+    pub fn from_declaration(decl: &DeriveInput) -> syn::Result<Self> {
+        let Data::Enum(data) = &decl.data else {
+            return Err(syn::Error::new(
+                decl.span(),
+                "JniError can only be derived for enums",
+            ));
+        };
+
+        let args = Self::parse_attrs(&decl.attrs)?;
+        let namespace = args.as_ref().map(|args| args.get_namespace()).transpose()?;
+        let language = match &args {
+            Some(args) => args.language()?,
+            None => default_language(),
+        };
+
+        let variants = data
+            .variants
+            .iter()
+            .map(|variant| {
+                let args = Self::parse_attrs(&variant.attrs)?.unwrap_or_default();
+                let generate = args.is_generate();
+                let exception = match args.get_exception() {
+                    Some(exception) if exception.contains('.') => exception,
+                    Some(exception) => match &namespace {
+                        Some(namespace) => format!("{namespace}.{exception}"),
+                        None => {
+                            return Err(syn::Error::new(
+                                variant.span(),
+                                "Exception classes without a package require a package = \"...\" attribute on the enum",
+                            ))
+                        }
+                    },
+                    None if generate => {
+                        return Err(syn::Error::new(
+                            variant.span(),
+                            "Missing exception = \"...\" attribute",
+                        ))
+                    }
+                    None => NATIVE_EXECUTION_EXCEPTION.to_string(),
+                };
+
+                Ok(JavaErrorVariant {
+                    ident: variant.ident.clone(),
+                    exception,
+                    generate,
+                    fields: variant.fields.clone(),
+                    comment: variant.attrs.get_comment(),
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name: decl.ident.to_string(),
+            variants,
+            language,
+            ident: decl.ident.clone(),
+        })
+    }
+
+    fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Option<BindgenAttrs>> {
+        attrs
+            .iter()
+            .find(|attr| attr.is_jni())
+            .map(|attr| attr.parse_args::<BindgenAttrs>())
+            .transpose()
+    }
+
+    pub fn as_jni_methods(&self) -> TokenStream {
+        let ident = &self.ident;
+        let classes = self.variants.iter().map(|v| {
+            let variant = &v.ident;
+            let class_name = v.exception.replace('.', "/");
+            let pattern = match &v.fields {
+                Fields::Named(_) => quote!(#ident::#variant { .. }),
+                Fields::Unnamed(_) => quote!(#ident::#variant(..)),
+                Fields::Unit => quote!(#ident::#variant),
+            };
+
+            quote!(#pattern => #class_name,)
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl From<#ident> for jni_bindgen::errors::jni_error::JNIError {
+                fn from(value: #ident) -> Self {
+                    let class = match &value {
+                        #(#classes)*
+                    };
+
+                    // Keep the sources of the error as causes
+                    let mut error = jni_bindgen::errors::jni_error::JNIError::from_error(&value);
+                    error.class = Some(jni_bindgen::errors::jni_error::ErrorClass::Any(
+                        class.to_string(),
+                    ));
+
+                    error
+                }
+            }
+        }
+    }
+
+    /// The fully qualified names of all exception classes this error may be
+    /// converted into, in the order of the variants.
+    pub fn exceptions(&self) -> Vec<String> {
+        let mut exceptions = Vec::<String>::new();
+        for variant in &self.variants {
+            if !exceptions.contains(&variant.exception) {
+                exceptions.push(variant.exception.clone());
+            }
+        }
+
+        exceptions
+    }
+
+    /// The declarations of all exception classes to generate in the
+    /// language of this error as tuples of namespace, class name and declaration.
+    pub fn as_declarations(&self) -> Vec<(String, String, String)> {
+        self.variants
+            .iter()
+            .filter(|v| v.generate)
+            .map(|v| {
+                let (namespace, class_name) = v.exception.rsplit_once('.').unwrap();
+                let comment = v.comment.clone().unwrap_or_default();
+                let code = match self.language {
+                    Language::Java => {
+                        exception_class(namespace, class_name, comment, vec![], HashSet::new())
+                    }
+                    Language::Kotlin => kotlin::exception_class(
+                        namespace,
+                        class_name,
+                        comment,
+                        vec![],
+                        HashSet::new(),
+                    ),
+                };
+
+                (namespace.to_string(), class_name.to_string(), code)
+            })
+            .collect()
+    }
+}
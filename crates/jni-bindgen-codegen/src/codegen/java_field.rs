@@ -0,0 +1,245 @@
+use crate::codegen::java_method::JavaMethod;
+use crate::codegen::kotlin;
+use crate::codegen::signature::JniSignature;
+use crate::codegen::traits::FromDeclaration;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::quotes;
+use crate::util::traits::{AnyAttribute, GetComment, JniMethod};
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Attribute, Field, ImplItemFn, ItemStruct};
+
+/// A getter or setter of a field. The accessor is a method
+/// of the struct, which is exported like any other method.
+struct Accessor {
+    decl: ImplItemFn,
+    method: JavaMethod,
+}
+
+impl Accessor {
+    fn new(decl: ImplItemFn) -> syn::Result<Self> {
+        Ok(Self {
+            method: JavaMethod::from_declaration(&decl)?,
+            decl,
+        })
+    }
+}
+
+/// A field of a struct exported through a `JavaClass`
+/// with a getter and/or setter generated for it.
+struct JavaField {
+    name: String,
+    docs: Vec<Attribute>,
+    getter: Option<Accessor>,
+    setter: Option<Accessor>,
+}
+
+impl JavaField {
+    fn from_declaration(field: &Field, args: &BindgenAttrs) -> syn::Result<Self> {
+        if !args.is_getter() && !args.is_setter() {
+            return Err(syn::Error::new(
+                field.span(),
+                "Expected a getter or setter attribute",
+            ));
+        }
+
+        let Some(ident) = &field.ident else {
+            return Err(syn::Error::new(
+                field.span(),
+                "Accessors can only be generated for named fields",
+            ));
+        };
+
+        let ty = &field.ty;
+        let name = args.get_rename().unwrap_or_else(|| ident.to_string());
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("doc"))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        // The getter returns a clone of the value, as
+        // the value is converted into a java object
+        let getter = if args.is_getter() {
+            let fn_name = format_ident!("jni_bindgen_get_{ident}");
+            let rename = format!("get_{name}");
+            Some(Accessor::new(syn::parse2(quote! {
+                #(#docs)*
+                #[jni(rename = #rename)]
+                #[doc(hidden)]
+                pub fn #fn_name(&self) -> #ty {
+                    std::clone::Clone::clone(&self.#ident)
+                }
+            })?)?)
+        } else {
+            None
+        };
+
+        let setter = if args.is_setter() {
+            let fn_name = format_ident!("jni_bindgen_set_{ident}");
+            let rename = format!("set_{name}");
+            Some(Accessor::new(syn::parse2(quote! {
+                #(#docs)*
+                #[jni(rename = #rename)]
+                #[doc(hidden)]
+                pub fn #fn_name(&mut self, value: #ty) {
+                    self.#ident = value;
+                }
+            })?)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name,
+            docs,
+            getter,
+            setter,
+        })
+    }
+
+    fn accessors(&self) -> impl Iterator<Item = &Accessor> {
+        self.getter.iter().chain(self.setter.iter())
+    }
+
+    /// Get the Kotlin property of this field. A field with only a
+    /// setter is declared as a function, as Kotlin does not support
+    /// write-only properties.
+    fn as_kotlin_declaration(&self, struct_name: &str) -> String {
+        let Some(getter) = &self.getter else {
+            return self
+                .setter
+                .as_ref()
+                .map(|s| s.method.as_kotlin_declaration(struct_name, true))
+                .unwrap_or_default();
+        };
+
+        let name = kotlin::escape(self.name.to_case(Case::Camel));
+        let comment = self.docs.get_comment().unwrap_or_default();
+        let ty = getter
+            .method
+            .return_type
+            .as_ref()
+            .and_then(|t| t.as_kotlin_declaration())
+            .unwrap_or_default();
+        let get = format!(
+            "get() = inner.{}()",
+            getter.method.name.to_case(Case::Camel)
+        );
+
+        match &self.setter {
+            Some(setter) => format!(
+                "{comment}var {name}: {ty}\n{get}\nset(value) = inner.{}(value)",
+                setter.method.name.to_case(Case::Camel)
+            ),
+            None => format!("{comment}val {name}: {ty}\n{get}"),
+        }
+    }
+}
+
+/// The fields of a struct annotated with `#[jni(getter)]` or `#[jni(setter)]`.
+/// The accessors are added to the java class generated for the `impl` block
+/// of the struct. As the struct and the `impl` block are expanded separately,
+/// the `impl` block reads the struct from the context of the build script
+/// generator.
+pub struct JavaFields {
+    pub name: String,
+    pub namespace: String,
+    fields: Vec<JavaField>,
+    decl: ItemStruct,
+}
+
+impl JavaFields {
+    pub fn from_declaration(decl: &ItemStruct, args: &BindgenAttrs) -> syn::Result<Self> {
+        let namespace = args.get_namespace()?;
+        let fields = decl
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let args = (&field.attrs).get_jni_attr()?;
+                Some(JavaField::from_declaration(field, &args))
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name: decl.ident.to_string(),
+            namespace,
+            fields,
+            decl: decl.clone(),
+        })
+    }
+
+    /// Whether no field has a getter or setter.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get the methods of the java class accessing the fields.
+    pub fn methods(&self) -> Vec<JavaMethod> {
+        self.fields
+            .iter()
+            .flat_map(|f| f.accessors())
+            .map(|a| a.method.clone())
+            .collect()
+    }
+
+    /// Generate the accessors of the struct and their JNI methods.
+    pub fn as_jni_methods(&self) -> syn::Result<TokenStream> {
+        if self.fields.is_empty() {
+            return Ok(quote!());
+        }
+
+        let base_name = quotes::base_name(&self.namespace.replace('.', "_"), &self.name);
+        let accessors = self
+            .fields
+            .iter()
+            .flat_map(|f| f.accessors())
+            .map(|a| {
+                let mut decl = a.decl.clone();
+                decl.attrs.retain(|a| !a.is_jni());
+
+                let jni_method = a.method.as_jni_method(base_name.clone(), &self.name)?;
+                Ok((decl, jni_method))
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let (decls, jni_methods): (Vec<_>, Vec<_>) = accessors.into_iter().unzip();
+
+        let struct_name = &self.decl.ident;
+        Ok(quote! {
+            #[automatically_derived]
+            impl #struct_name {
+                #(#decls)*
+            }
+
+            #(#jni_methods)*
+        })
+    }
+
+    /// Get the signatures of the native methods of the accessors.
+    pub fn signatures(&self) -> Vec<JniSignature> {
+        let base_name = quotes::base_name(&self.namespace.replace('.', "_"), &self.name);
+        let class_name = format!(
+            "{}/{}${}Native",
+            self.namespace.replace('.', "/"),
+            self.name,
+            self.name
+        );
+
+        self.methods()
+            .iter()
+            .map(|m| m.as_native_signature(&class_name, &base_name, &self.namespace))
+            .collect()
+    }
+
+    /// Get the Kotlin properties of the fields.
+    pub fn as_kotlin_declaration(&self) -> String {
+        self.fields
+            .iter()
+            .map(|f| f.as_kotlin_declaration(&self.name))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
@@ -0,0 +1,314 @@
+use crate::codegen::code::{interface, interface_impl_class};
+use crate::codegen::java_method::{check_overloads, JavaMethod};
+use crate::codegen::kotlin;
+use crate::codegen::signature::{JniSignature, SignatureKind};
+use crate::codegen::traits::FromDeclaration;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::quotes;
+use crate::util::traits::GetComment;
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{ItemTrait, TraitItem};
+
+pub struct JavaInterface {
+    pub name: String,
+    pub methods: Vec<JavaMethod>,
+    pub namespace: String,
+    //_attrs: BindgenAttrs,
+    decl: ItemTrait,
+}
+
+impl JavaInterface {
+    pub fn from_declaration(decl: &ItemTrait, args: &BindgenAttrs) -> syn::Result<Self> {
+        let name = decl.ident.clone().into_token_stream().to_string();
+        let methods = decl
+            .items
+            .iter()
+            .map(|item| match item {
+                TraitItem::Fn(func) => JavaMethod::from_declaration(func),
+                _ => Err(syn::Error::new(item.span(), "Only functions are supported")),
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        check_overloads(&["drop"], &methods)?;
+
+        Ok(Self {
+            name,
+            methods,
+            //_attrs: args.clone(),
+            namespace: args.get_namespace()?,
+            decl: decl.clone(),
+        })
+    }
+
+    pub fn as_jni_methods(&self) -> syn::Result<TokenStream> {
+        let struct_name: TokenStream = format!("{}Impl", self.name).parse()?;
+        let global_name: TokenStream = format!("{}GlobalImpl", self.name).parse()?;
+        let trait_name: TokenStream = self.name.parse()?;
+
+        let class_name = format!("{}/{}", self.namespace.replace('.', "/"), self.name);
+        let methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_trait_method(&self.namespace, &class_name))
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        // Native methods of the java class implementing the
+        // interface by calling into a boxed Rust implementation
+        let boxed_name = format!("Box<dyn {}>", self.name);
+        let base_name = quotes::base_name(
+            &self.namespace.replace('.', "_"),
+            &format!("{}Impl", self.name),
+        );
+        let native_methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_jni_method(base_name.clone(), &boxed_name))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let drop = quotes::drop_struct(base_name.parse()?, boxed_name);
+        let impl_class = format!("{}/{}Impl", self.namespace.replace('.', "/"), self.name);
+
+        // The implementation using a local reference can only be
+        // generated if every method is passed the JNIEnv of its caller
+        let local_impl = if self.methods.iter().all(|m| m.takes_env()) {
+            quote! {
+                pub struct #struct_name<'local> {
+                    pub obj: jni::objects::JObject<'local>,
+                }
+
+                #[automatically_derived]
+                impl #trait_name for #struct_name<'_> {
+                    #(#methods)*
+                }
+
+                #[automatically_derived]
+                impl<'local> FromJNI<'local> for Box<dyn #trait_name + 'local> {
+                    fn from_jni(
+                        env: &mut jni::JNIEnv<'local>,
+                        obj: jni::objects::JObject<'local>,
+                    ) -> jni_bindgen::Result<Self> {
+                        Ok(Box::new(#struct_name { obj }))
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #[automatically_derived]
+                impl<'local> FromJNI<'local> for Box<dyn #trait_name + 'local> {
+                    fn from_jni(
+                        env: &mut jni::JNIEnv<'local>,
+                        obj: jni::objects::JObject<'local>,
+                    ) -> jni_bindgen::Result<Self> {
+                        Ok(Box::new(#global_name::new(env, &obj)?))
+                    }
+                }
+            }
+        };
+
+        Ok(quote! {
+            #local_impl
+
+            /// An implementation of the java interface holding a global
+            /// reference to the java object. This may be stored and called
+            /// from any thread, methods without a `JNIEnv` argument attach
+            /// the current thread to the JVM.
+            pub struct #global_name {
+                pub obj: jni::objects::GlobalRef,
+                pub vm: jni::JavaVM,
+            }
+
+            impl #global_name {
+                pub fn new(
+                    env: &mut jni::JNIEnv,
+                    obj: &jni::objects::JObject,
+                ) -> jni_bindgen::Result<Self> {
+                    if obj.is_null() {
+                        jni_bindgen::bail_class!(
+                            jni_bindgen::errors::jni_error::ErrorClass::NullPointer,
+                            "The value is null"
+                        );
+                    }
+
+                    Ok(Self {
+                        obj: env.new_global_ref(obj)?,
+                        vm: env.get_java_vm()?,
+                    })
+                }
+            }
+
+            #[automatically_derived]
+            impl #trait_name for #global_name {
+                #(#methods)*
+            }
+
+            #[automatically_derived]
+            impl<'local, 'a> FromJNI<'local> for Box<dyn #trait_name + Send + Sync + 'a> {
+                fn from_jni(
+                    env: &mut jni::JNIEnv<'local>,
+                    obj: jni::objects::JObject<'local>,
+                ) -> jni_bindgen::Result<Self> {
+                    Ok(Box::new(#global_name::new(env, &obj)?))
+                }
+            }
+
+            #[automatically_derived]
+            impl jni_bindgen::objects::traits::IntoJNI for Box<dyn #trait_name> {
+                fn into_jni<'a>(
+                    self,
+                    env: &mut jni::JNIEnv<'a>,
+                ) -> jni_bindgen::Result<jni::objects::JObject<'a>> {
+                    static CLASS: jni_bindgen::cache::CachedClass =
+                        jni_bindgen::cache::CachedClass::new(#impl_class);
+
+                    let class = CLASS.get(env)?;
+                    let ptr = jni_bindgen::conversion::class_convert::into_ptr(std::sync::Arc::new(self));
+                    env.new_object(class, "(J)V", &[ptr.into()])
+                        .map_err(|e| {
+                            unsafe { jni_bindgen::conversion::class_convert::drop_ptr::<Self>(ptr) };
+                            e.into()
+                        })
+                }
+            }
+
+            #(#native_methods)*
+            #drop
+        })
+    }
+
+    /// Get the signatures of the interface methods called by the
+    /// generated rust code and of the native methods of the class
+    /// implementing this interface by calling into a Rust implementation.
+    pub fn signatures(&self) -> Vec<JniSignature> {
+        let package = self.namespace.replace('.', "/");
+        let impl_name = format!("{}Impl", self.name);
+        let base_name = quotes::base_name(&self.namespace.replace('.', "_"), &impl_name);
+        let impl_class = format!("{package}/{impl_name}${impl_name}Native");
+
+        let interface_methods = self.methods.iter().map(|m| JniSignature {
+            class_name: format!("{package}/{}", self.name),
+            method_name: m.name.to_case(Case::Camel),
+            descriptor: m.as_jni_descriptor(&self.namespace),
+            kind: SignatureKind::Interface,
+        });
+        let native_methods = self
+            .methods
+            .iter()
+            .chain([&JavaMethod::drop_method()])
+            .map(|m| m.as_native_signature(&impl_class, &base_name, &self.namespace))
+            .collect::<Vec<_>>();
+
+        interface_methods.chain(native_methods).collect()
+    }
+
+    /// Get the declaration of the java class implementing
+    /// this interface by calling into a Rust implementation.
+    pub fn as_java_impl_declaration(&self) -> String {
+        let methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_interface_impl_declaration(true))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let native_methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_interface_impl_declaration(false))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let imports = self
+            .methods
+            .iter()
+            .flat_map(|m| m.get_imports())
+            .filter(|i| !i.contains("NativeExecutionException"))
+            .collect::<HashSet<_>>();
+
+        interface_impl_class(
+            &self.namespace,
+            &self.name,
+            methods,
+            native_methods,
+            imports,
+        )
+    }
+
+    pub fn as_java_declaration(&self) -> String {
+        let methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_interface_declaration())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let imports = self
+            .methods
+            .iter()
+            .flat_map(|m| m.get_imports())
+            .filter(|i| !i.contains("NativeExecutionException"))
+            .collect::<HashSet<_>>();
+
+        interface(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            methods,
+            imports,
+        )
+    }
+
+    fn get_kotlin_imports(&self) -> HashSet<String> {
+        self.methods
+            .iter()
+            .flat_map(|m| m.get_kotlin_imports())
+            .filter(|i| !i.contains("NativeExecutionException"))
+            .collect()
+    }
+
+    /// Get the Kotlin declaration of the class implementing
+    /// this interface by calling into a Rust implementation.
+    pub fn as_kotlin_impl_declaration(&self) -> String {
+        let methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_kotlin_interface_impl_declaration(true))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let native_methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_kotlin_interface_impl_declaration(false))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        kotlin::interface_impl_class(
+            &self.namespace,
+            &self.name,
+            methods,
+            native_methods,
+            self.get_kotlin_imports(),
+        )
+    }
+
+    /// Get the Kotlin declaration of this interface. Interfaces
+    /// with a single method are declared as functional interfaces,
+    /// so they can be implemented using a lambda.
+    pub fn as_kotlin_declaration(&self) -> String {
+        let methods = self
+            .methods
+            .iter()
+            .map(|m| m.as_kotlin_interface_declaration())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        kotlin::interface(
+            &self.namespace,
+            &self.name,
+            self.decl.attrs.get_comment().unwrap_or_default(),
+            methods,
+            self.methods.len() == 1,
+            self.get_kotlin_imports(),
+        )
+    }
+}
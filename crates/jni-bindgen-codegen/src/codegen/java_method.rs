@@ -0,0 +1,945 @@
+use crate::codegen::java_type::{DescriptorPart, JNIArgGetter, JavaArg, JavaType};
+use crate::codegen::kotlin;
+use crate::codegen::signature::{JniSignature, SignatureKind};
+use crate::codegen::traits::FromDeclaration;
+use crate::util::quotes;
+use crate::util::traits::{GetComment, JniMethod};
+use convert_case::{Case, Casing};
+use indexmap::IndexMap;
+use proc_macro2::Ident;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{Attribute, FnArg, PatType, Signature, TraitItemFn};
+use syn::{ImplItemFn, ItemFn};
+
+#[derive(Clone)]
+pub struct JavaMethod {
+    pub name: String,
+    pub original_name: String,
+    pub args: IndexMap<String, JavaArg>,
+    pub return_type: Option<JavaType>,
+    pub static_method: bool,
+    pub mut_self: bool,
+    _decl: Option<FnDecl>,
+}
+
+#[derive(Clone)]
+enum FnDecl {
+    Impl(ImplItemFn),
+    Trait(TraitItemFn),
+    Free(ItemFn),
+}
+
+impl FnDecl {
+    fn attrs(&self) -> &Vec<Attribute> {
+        match self {
+            Self::Impl(i) => &i.attrs,
+            Self::Trait(t) => &t.attrs,
+            Self::Free(f) => &f.attrs,
+        }
+    }
+
+    fn sig(&self) -> &Signature {
+        match self {
+            Self::Impl(i) => &i.sig,
+            Self::Trait(t) => &t.sig,
+            Self::Free(f) => &f.sig,
+        }
+    }
+}
+
+/// Fail if two of the given methods, or a method and one of the `reserved`
+/// methods of the generated class, have the same java name. Overloaded
+/// native methods are not supported, as the exported functions are named
+/// after the short name the JVM looks up, which omits the arguments.
+pub fn check_overloads<'a>(
+    reserved: &[&str],
+    methods: impl IntoIterator<Item = &'a JavaMethod>,
+) -> syn::Result<()> {
+    let mut names = reserved
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<HashSet<_>>();
+    for method in methods {
+        let name = method.name.to_case(Case::Camel);
+        if !names.insert(name.clone()) {
+            return Err(syn::Error::new(
+                method.span(),
+                format!(
+                    "Overloaded native methods are not supported, '{name}' is already declared"
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+impl JavaMethod {
+    /// The span of the name of the declaration of this method.
+    pub fn span(&self) -> Span {
+        self._decl
+            .as_ref()
+            .map_or_else(Span::call_site, |decl| decl.sig().ident.span())
+    }
+
+    pub fn drop_method() -> Self {
+        Self {
+            name: "drop".to_string(),
+            original_name: "drop".to_string(),
+            args: vec![("self".to_string(), JavaArg::this())]
+                .into_iter()
+                .collect(),
+            return_type: None,
+            static_method: true,
+            mut_self: false,
+            _decl: None,
+        }
+    }
+
+    pub fn get_type_hash() -> Self {
+        Self {
+            name: "getTypeHash".to_string(),
+            original_name: "getTypeHash".to_string(),
+            args: Default::default(),
+            return_type: Some(JavaType::Long),
+            static_method: true,
+            mut_self: false,
+            _decl: None,
+        }
+    }
+
+    pub fn get_imports(&self) -> HashSet<String> {
+        let mut res = self
+            .args
+            .values()
+            .flat_map(|a| a.java_type.get_imports())
+            .collect::<HashSet<String>>();
+
+        if let Some(ret) = self.return_type.as_ref() {
+            res.extend(ret.get_imports());
+        }
+
+        res
+    }
+
+    fn get_name(arg: &FnArg) -> String {
+        match arg {
+            FnArg::Receiver(_) => "self".to_string(),
+            FnArg::Typed(PatType { pat, .. }) => pat.into_token_stream().to_string(),
+        }
+    }
+
+    fn get_args(&self) -> String {
+        self.args
+            .iter()
+            .filter_map(|(name, arg)| {
+                Some(format!(
+                    "{} {}",
+                    arg.as_declaration()?,
+                    name.to_case(Case::Camel)
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn get_arg_names(&self) -> String {
+        self.args
+            .iter()
+            .filter_map(|(n, a)| {
+                if a.as_declaration().is_some() {
+                    Some(n.to_case(Case::Camel))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    pub fn as_constructor(&self, class_name: &str, inner: bool) -> String {
+        let code = if inner {
+            format!(
+                "super({}({}), referent);",
+                self.name.to_case(Case::Camel),
+                self.get_arg_names()
+            )
+        } else {
+            let mut args = self.get_arg_names();
+            if !args.is_empty() {
+                args += ", ";
+            }
+
+            format!("inner = new {class_name}Native({args}this);")
+        };
+
+        let mut args = self.get_args();
+        if inner {
+            if !args.is_empty() {
+                args += ", ";
+            }
+
+            args += "Object referent";
+        }
+
+        let public = if inner { "private" } else { "public" };
+        let throws = self
+            .return_type
+            .as_ref()
+            .and_then(|r| r.throws())
+            .unwrap_or_default();
+        let comment = self.get_comment().unwrap_or_default();
+
+        format!(
+            r#"{comment}{public} {class_name}({args}){throws} {{
+        {code}
+        }}
+        "#
+        )
+    }
+
+    pub fn as_declaration(&self, struct_name: &str, definition: bool) -> String {
+        let static_method = if self.static_method { "static " } else { "" };
+        let return_type = match &self.return_type {
+            Some(t) => t.as_declaration().unwrap(),
+            None => "void".to_string(),
+        };
+
+        let native = if definition { "" } else { "native " };
+        let def = if definition {
+            let ret = if self
+                .return_type
+                .as_ref()
+                .map(|r| !r.is_void(true))
+                .unwrap_or_default()
+            {
+                "return "
+            } else {
+                ""
+            };
+
+            let inner = if self.static_method {
+                format!("{struct_name}Native")
+            } else {
+                "inner".to_string()
+            };
+
+            format!(
+                " {{\n\t\t{ret}{inner}.{}({});\n\t}}",
+                self.name.to_case(Case::Camel),
+                self.get_arg_names()
+            )
+        } else {
+            ";".to_string()
+        };
+
+        let public = if definition { "public" } else { "private" };
+        let throws = self
+            .return_type
+            .as_ref()
+            .and_then(|r| r.throws())
+            .unwrap_or_default();
+        let comment = self.get_comment().unwrap_or_default();
+
+        format!(
+            "\t{comment}{public} {static_method}{native}{return_type} {}({}){throws}{def}",
+            self.name.to_case(Case::Camel),
+            self.get_args()
+        )
+    }
+
+    pub fn as_interface_declaration(&self) -> String {
+        let return_type = match &self.return_type {
+            Some(t) => t.as_declaration().unwrap(),
+            None => "void".to_string(),
+        };
+
+        let comment = self.get_comment().unwrap_or_default();
+        format!(
+            "\t{comment}{return_type} {}({});",
+            self.name.to_case(Case::Camel),
+            self.get_args()
+        )
+    }
+
+    /// Get the declaration of this method in a native implementation
+    /// of an interface. If `definition` is true, this will be the public
+    /// method forwarding to the inner class, otherwise the native method
+    /// of the inner class.
+    pub fn as_interface_impl_declaration(&self, definition: bool) -> String {
+        let return_type = match &self.return_type {
+            Some(t) => t.as_declaration().unwrap(),
+            None => "void".to_string(),
+        };
+
+        let name = self.name.to_case(Case::Camel);
+        if definition {
+            let ret = if self
+                .return_type
+                .as_ref()
+                .map(|r| !r.is_void(true))
+                .unwrap_or_default()
+            {
+                "return "
+            } else {
+                ""
+            };
+
+            format!(
+                "\t@Override\n\tpublic {return_type} {name}({}) {{\n\t\t{ret}inner.{name}({});\n\t}}",
+                self.get_args(),
+                self.get_arg_names()
+            )
+        } else {
+            format!(
+                "\tprivate native {return_type} {name}({});",
+                self.get_args()
+            )
+        }
+    }
+
+    /// Get the imports required by the Kotlin declaration of this method.
+    pub fn get_kotlin_imports(&self) -> HashSet<String> {
+        let mut res = self
+            .args
+            .values()
+            .flat_map(|a| a.java_type.get_kotlin_imports())
+            .collect::<HashSet<String>>();
+
+        if let Some(ret) = self.return_type.as_ref() {
+            res.extend(ret.get_kotlin_imports());
+        }
+
+        if self.is_async() {
+            res.extend(kotlin::suspend_imports());
+        }
+
+        res
+    }
+
+    fn is_async(&self) -> bool {
+        matches!(self.return_type, Some(JavaType::Future { .. }))
+    }
+
+    fn get_kotlin_args(&self) -> String {
+        self.args
+            .iter()
+            .filter_map(|(name, arg)| {
+                Some(format!(
+                    "{}: {}",
+                    kotlin::escape(name.to_case(Case::Camel)),
+                    arg.java_type.as_kotlin_declaration()?
+                ))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn get_kotlin_arg_names(&self) -> String {
+        self.args
+            .iter()
+            .filter(|(_, a)| a.as_declaration().is_some())
+            .map(|(n, _)| kotlin::escape(n.to_case(Case::Camel)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Get the return type of the Kotlin declaration
+    /// of this method, e.g. `: Int`, or nothing if the
+    /// method does not return a value.
+    fn get_kotlin_return_type(&self) -> String {
+        match &self.return_type {
+            Some(t) if !t.is_void(true) => format!(": {}", t.as_kotlin_declaration().unwrap()),
+            _ => String::new(),
+        }
+    }
+
+    /// Get the `@Throws` annotation of this method, so java
+    /// callers are able to catch the declared exceptions.
+    fn get_kotlin_throws(&self) -> String {
+        self.return_type
+            .as_ref()
+            .and_then(|r| r.exceptions())
+            .map(|exceptions| {
+                let classes = exceptions
+                    .iter()
+                    .map(|e| format!("{e}::class"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("@Throws({classes})\n")
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn as_kotlin_constructor(&self, class_name: &str, inner: bool) -> String {
+        let args = self.get_kotlin_args();
+        let arg_names = self.get_kotlin_arg_names();
+
+        if inner {
+            let args = if args.is_empty() {
+                "referent: Any".to_string()
+            } else {
+                format!("{args}, referent: Any")
+            };
+
+            format!(
+                "internal constructor({args}) : super({}({arg_names}), referent)",
+                self.name.to_case(Case::Camel)
+            )
+        } else {
+            let arg_names = if arg_names.is_empty() {
+                "this".to_string()
+            } else {
+                format!("{arg_names}, this")
+            };
+
+            format!(
+                "{}{}constructor({args}) {{\ninner = {class_name}Native({arg_names})\n}}",
+                self.get_comment().unwrap_or_default(),
+                self.get_kotlin_throws()
+            )
+        }
+    }
+
+    /// Get the Kotlin declaration of this method. If `definition` is true,
+    /// this will be the public method forwarding to the inner class, otherwise
+    /// the native method of the inner class. Static methods must be declared
+    /// in a companion object or an object declaration.
+    ///
+    /// Async methods are declared as a `suspend` function awaiting the
+    /// result and a function returning the future with an `Async` suffix.
+    pub fn as_kotlin_declaration(&self, struct_name: &str, definition: bool) -> String {
+        let name = kotlin::escape(self.name.to_case(Case::Camel));
+        let args = self.get_kotlin_args();
+        let ret = self.get_kotlin_return_type();
+        let jvm_static = if self.static_method {
+            "@JvmStatic\n"
+        } else {
+            ""
+        };
+
+        if !definition {
+            return format!("{jvm_static}external fun {name}({args}){ret}");
+        }
+
+        let inner = if self.static_method {
+            format!("{struct_name}Native")
+        } else {
+            "inner".to_string()
+        };
+
+        let comment = self.get_comment().unwrap_or_default();
+        let throws = self.get_kotlin_throws();
+        let call = format!("{inner}.{name}({})", self.get_kotlin_arg_names());
+        let Some(JavaType::Future { java_type }) = &self.return_type else {
+            return format!("{comment}{throws}{jvm_static}fun {name}({args}){ret} = {call}");
+        };
+
+        let value = java_type.unwrap_result();
+        let suspend_ret = if value.is_void(false) {
+            String::new()
+        } else {
+            format!(": {}", value.as_kotlin_declaration().unwrap())
+        };
+
+        let suspend = kotlin::suspend_fun(
+            &format!("{comment}suspend fun {name}({args}){suspend_ret}"),
+            &call,
+            value.is_void(false),
+        );
+
+        format!(
+            "{suspend}\n\n{jvm_static}fun {}Async({args}){ret} = {call}",
+            self.name.to_case(Case::Camel)
+        )
+    }
+
+    pub fn as_kotlin_interface_declaration(&self) -> String {
+        format!(
+            "{}fun {}({}){}",
+            self.get_comment().unwrap_or_default(),
+            kotlin::escape(self.name.to_case(Case::Camel)),
+            self.get_kotlin_args(),
+            self.get_kotlin_return_type()
+        )
+    }
+
+    /// Get the Kotlin declaration of this method in a native implementation
+    /// of an interface. If `definition` is true, this will be the public
+    /// method forwarding to the inner class, otherwise the native method
+    /// of the inner class.
+    pub fn as_kotlin_interface_impl_declaration(&self, definition: bool) -> String {
+        let name = kotlin::escape(self.name.to_case(Case::Camel));
+        let args = self.get_kotlin_args();
+        let ret = self.get_kotlin_return_type();
+
+        if definition {
+            format!(
+                "override fun {name}({args}){ret} = inner.{name}({})",
+                self.get_kotlin_arg_names()
+            )
+        } else {
+            format!("external fun {name}({args}){ret}")
+        }
+    }
+
+    /// Get the name of the function exported for the native
+    /// method of this method in the class with the given base name.
+    pub fn as_jni_symbol(&self, base_name: &str) -> String {
+        format!("{base_name}_{}", self.name.to_case(Case::Camel))
+    }
+
+    /// Get the JNI descriptor of the java declaration
+    /// of this method, e.g. `(ILjava/lang/String;)V`.
+    pub fn as_jni_descriptor(&self, namespace: &str) -> String {
+        let descriptor = |ty: &JavaType| match ty {
+            // Native pointers are passed as longs
+            JavaType::This => "J".to_string(),
+            ty => ty.as_jni_declaration(namespace),
+        };
+
+        let args = self
+            .args
+            .values()
+            .filter(|a| a.as_declaration().is_some())
+            .map(|a| descriptor(&a.java_type))
+            .collect::<String>();
+        let ret = self
+            .return_type
+            .as_ref()
+            .map_or("V".to_string(), descriptor);
+
+        format!("({args}){ret}")
+    }
+
+    /// Get the JNI descriptor of this method as an expression generated
+    /// into the rust code. See [`JavaType::as_jni_descriptor_parts`].
+    pub fn as_jni_descriptor_tokens(&self, namespace: &str) -> TokenStream {
+        let parts = |ty: &JavaType| match ty {
+            JavaType::This => vec![DescriptorPart::Literal("J".to_string())],
+            ty => ty.as_jni_descriptor_parts(namespace),
+        };
+
+        let mut descriptor = vec![DescriptorPart::Literal("(".to_string())];
+        for arg in self.args.values().filter(|a| a.as_declaration().is_some()) {
+            descriptor.extend(parts(&arg.java_type));
+        }
+        descriptor.push(DescriptorPart::Literal(")".to_string()));
+        match &self.return_type {
+            Some(ret) => descriptor.extend(parts(ret)),
+            None => descriptor.push(DescriptorPart::Literal("V".to_string())),
+        }
+
+        quotes::descriptor(descriptor)
+    }
+
+    /// Get the signature of the native method
+    /// in the class with the given binary name.
+    pub fn as_native_signature(
+        &self,
+        class_name: &str,
+        base_name: &str,
+        namespace: &str,
+    ) -> JniSignature {
+        JniSignature {
+            class_name: class_name.to_string(),
+            method_name: self.name.to_case(Case::Camel),
+            descriptor: self.as_jni_descriptor(namespace),
+            kind: SignatureKind::Native {
+                symbol: self.as_jni_symbol(base_name),
+                static_method: self.static_method,
+            },
+        }
+    }
+
+    pub fn as_jni_method(&self, base_name: String, struct_name: &str) -> syn::Result<TokenStream> {
+        let name: TokenStream = self.as_jni_symbol(&base_name).parse()?;
+
+        let ret = self
+            .return_type
+            .as_ref()
+            .map(|r| -> syn::Result<TokenStream> {
+                let ret = r.as_jni_return_type()?;
+                Ok(quote!(-> #ret))
+            })
+            .map_or(Ok(None), |v| v.map(Some))?;
+
+        let parsed_struct_name = struct_name.parse()?;
+        let ret_val = self
+            .return_type
+            .as_ref()
+            .map_or(Ok(quote!(())), |r| r.error_return_val())?;
+        let is_async = matches!(self.return_type, Some(JavaType::Future { .. }));
+        if is_async {
+            self.check_async()?;
+        }
+
+        let this = if self.static_method {
+            None
+        } else if is_async {
+            Some(quotes::this_arc(&parsed_struct_name, &ret_val))
+        } else {
+            Some(quotes::this(&parsed_struct_name, &ret_val, self.mut_self))
+        };
+
+        let j_args: TokenStream = self
+            .args
+            .values()
+            .filter(|a| !a.is_self())
+            .enumerate()
+            .filter_map(|(i, a)| {
+                a.as_jni_fn_arg()
+                    .map_or_else(|e| Some(Err(e)), |a| a.map(|o| Ok((i, o))))
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .into_iter()
+            .map(|(i, a)| {
+                let name: TokenStream = format!("j_arg_{i}").parse()?;
+                Ok(quote!(#name: #a).to_string())
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .join(", ")
+            .parse()?;
+
+        let arg_converters: TokenStream = self
+            .args
+            .values()
+            .filter(|a| !a.is_self())
+            .enumerate()
+            .map(|(i, a)| {
+                let name = format!("j_arg_{i}");
+                let arg_name: TokenStream = format!("arg_{i}").parse()?;
+                let getter = a.as_jni_arg_getter(&name, self.return_type.as_ref())?;
+
+                if let JNIArgGetter::Getter(getter) = getter {
+                    Ok(Some(
+                        quote!(
+                            let #arg_name = #getter;
+                        )
+                        .to_string(),
+                    ))
+                } else {
+                    Ok(None)
+                }
+            })
+            .filter_map(|a| a.map_or_else(|e| Some(Err(e)), |a| a.map(Ok)))
+            .collect::<syn::Result<Vec<_>>>()?
+            .join("\n")
+            .parse()?;
+
+        let args: TokenStream = self
+            .args
+            .values()
+            .filter(|a| !a.is_self())
+            .enumerate()
+            .map(|(i, a)| {
+                Ok(
+                    match a.as_jni_arg_getter("arg", self.return_type.as_ref())? {
+                        JNIArgGetter::Getter(_) if a.is_slice() => format!("&arg_{i}"),
+                        JNIArgGetter::Getter(_) => format!("arg_{i}"),
+                        JNIArgGetter::ArgName(name) => name,
+                    },
+                )
+            })
+            .collect::<syn::Result<Vec<_>>>()?
+            .join(", ")
+            .parse()?;
+
+        let class_or_this = if this.is_some() {
+            quote!(object: jni::objects::JObject<'local>)
+        } else {
+            quote!(class: jni::objects::JClass<'local>)
+        };
+
+        let method_name = Ident::new(&self.original_name, Span::call_site());
+        let call = if is_async {
+            quotes::call_async(this.is_some(), &method_name, &parsed_struct_name, args)
+        } else {
+            quotes::call(
+                this.is_some(),
+                ret.is_some() && !self.return_type.as_ref().unwrap().is_void(false),
+                &method_name,
+                &parsed_struct_name,
+                args,
+            )
+        };
+
+        let return_res = self
+            .return_type
+            .as_ref()
+            .map_or(Ok(quote!()), |r| r.as_jni_return_val())?;
+
+        let closure_ret = if self.return_type.as_ref().is_none_or(|r| r.is_void(true)) {
+            quote!()
+        } else {
+            ret.clone().unwrap_or_default()
+        };
+        let receiver = if this.is_some() {
+            quote!(object)
+        } else {
+            quote!(class)
+        };
+        let body = quotes::catch_panic(
+            receiver,
+            &closure_ret,
+            &ret_val,
+            quote! {
+                use jni_bindgen::objects::traits::IntoJNIResult;
+                #this
+                #arg_converters
+
+                #call;
+                #return_res
+            },
+        );
+
+        let export = quotes::export();
+
+        Ok(quote!(
+            #export
+            pub extern "system" fn #name<'local>(
+                mut env: jni::JNIEnv<'local>,
+                #class_or_this,
+                #j_args
+            ) #ret {
+                #body
+            }
+        ))
+    }
+
+    /// Check that the future returned by an `async` method may be
+    /// moved to another thread, i.e. that it does not borrow anything
+    /// from the JNI call.
+    fn check_async(&self) -> syn::Result<()> {
+        let span = self
+            ._decl
+            .as_ref()
+            .map_or(Span::call_site(), |decl| decl.sig().span());
+
+        if self.mut_self {
+            return Err(syn::Error::new(span, "Async methods cannot take &mut self"));
+        }
+
+        for arg in self.args.values() {
+            match &arg.java_type {
+                JavaType::Env { .. }
+                | JavaType::Reference { .. }
+                | JavaType::Object
+                | JavaType::Interface { .. }
+                | JavaType::DirectBuffer { .. }
+                | JavaType::Array { slice: true, .. } => {
+                    return Err(syn::Error::new(
+                        span,
+                        "Async methods can only take owned arguments which do not borrow from the JNIEnv",
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn as_trait_method(&self, namespace: &str, class_name: &str) -> syn::Result<TokenStream> {
+        let name: TokenStream = self.name.parse()?;
+        let FnDecl::Trait(decl) = self._decl.as_ref().unwrap() else {
+            panic!("Expected trait method")
+        };
+
+        if self.static_method {
+            return Err(syn::Error::new(
+                decl.span(),
+                "Trait methods cannot be static",
+            ));
+        }
+
+        if self.return_type.is_none()
+            || !matches!(self.return_type.as_ref().unwrap(), JavaType::Result { .. })
+        {
+            return Err(syn::Error::new(
+                decl.span(),
+                "Trait methods must return a Result",
+            ));
+        }
+
+        // Methods without a JNIEnv attach the current thread
+        // to the JavaVM stored in the implementation
+        let attach = match self.args.values().find_map(|a| match &a.java_type {
+            JavaType::Env { mutable, inner } => Some((mutable, inner)),
+            _ => None,
+        }) {
+            Some((true, _)) => quote!(),
+            Some((false, env)) => {
+                return Err(syn::Error::new(
+                    env.span(),
+                    "JNIEnv must be mutable in trait methods",
+                ))
+            }
+            None => quote! {
+                let mut env = self.vm.attach_current_thread()?;
+                let env = &mut *env;
+            },
+        };
+
+        let args = self
+            .args
+            .values()
+            .filter(|arg| !arg.is_self())
+            .enumerate()
+            .map(|(i, arg)| {
+                let arg_name: TokenStream = if arg.is_env() {
+                    "env".to_string().parse()?
+                } else {
+                    format!("arg_{i}").parse()?
+                };
+
+                let arg = arg.java_type.as_interface_arg()?;
+                Ok(quote!(#arg_name: #arg, ))
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let vals = self
+            .args
+            .values()
+            .filter(|arg| !arg.is_self())
+            .enumerate()
+            .filter(|(_, arg)| !arg.is_env())
+            .map(|(i, arg)| {
+                let in_arg: TokenStream = format!("arg_{i}").parse()?;
+                let out_arg: TokenStream = format!("j_arg_{i}").parse()?;
+                arg.as_interface_val(in_arg, out_arg)
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let j_args = self
+            .args
+            .values()
+            .filter(|arg| !arg.is_self())
+            .enumerate()
+            .filter(|(_, arg)| !arg.is_env())
+            .map(|(i, _)| -> syn::Result<TokenStream> {
+                format!("j_arg_{i}.as_jni()")
+                    .parse::<TokenStream>()
+                    .map_err(Into::into)
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let decl_str = self.as_jni_descriptor_tokens(namespace);
+        let java_name = self.name.to_case(Case::Camel);
+
+        let ret = decl.sig.output.clone();
+        let ret_val = self
+            .return_type
+            .as_ref()
+            .map(|r| r.as_rust_return_val())
+            .unwrap_or(quote!(Ok(())));
+        Ok(quote! {
+            fn #name(&self, #(#args)*) #ret {
+                static CLASS: jni_bindgen::cache::CachedClass =
+                    jni_bindgen::cache::CachedClass::new(#class_name);
+                static METHOD: jni_bindgen::cache::CachedMethod =
+                    jni_bindgen::cache::CachedMethod::new(&CLASS, #java_name, #decl_str);
+
+                #attach
+                #(#vals)*
+
+                let res = unsafe { METHOD.call(env, &self.obj, &[#(#j_args),*]) }
+                    .map_err(|e| jni_bindgen::errors::jni_error::JNIError::from_jni_error(env, e))?;
+                #ret_val
+            }
+        })
+    }
+
+    /// Whether this method takes a `JNIEnv` argument.
+    pub fn takes_env(&self) -> bool {
+        self.args.values().any(|a| a.is_env())
+    }
+
+    fn get_comment(&self) -> Option<String> {
+        self._decl
+            .as_ref()
+            .and_then(|decl| decl.attrs().get_comment())
+    }
+
+    fn from_sig(sig: &Signature, name: String, decl: FnDecl) -> syn::Result<Self> {
+        let args = sig
+            .inputs
+            .iter()
+            .filter(|arg| !matches!(arg, syn::FnArg::Receiver(_)))
+            .map(|arg| Ok((Self::get_name(arg), JavaArg::from_declaration(arg)?)))
+            .collect::<syn::Result<IndexMap<_, _>>>()?;
+        let mut return_type = match &sig.output {
+            syn::ReturnType::Default => None,
+            syn::ReturnType::Type(_, ty) => Some(JavaType::from_declaration(ty)?),
+        };
+
+        if sig.asyncness.is_some() {
+            return_type = Some(JavaType::Future {
+                java_type: Box::new(return_type.unwrap_or(JavaType::Void)),
+            });
+        }
+        let self_arg = sig
+            .inputs
+            .iter()
+            .filter_map(|arg| match arg {
+                FnArg::Receiver(r) => {
+                    if r.reference.is_some() {
+                        Some(Ok(r.mutability.is_some()))
+                    } else {
+                        Some(Err(syn::Error::new(
+                            r.span(),
+                            "self argument must be a reference",
+                        )))
+                    }
+                }
+                _ => None,
+            })
+            .next()
+            .map_or(Ok(None), |r| r.map(Some))?;
+
+        Ok(Self {
+            name,
+            original_name: sig.ident.to_string(),
+            args,
+            return_type,
+            static_method: self_arg.is_none(),
+            mut_self: self_arg.unwrap_or_default(),
+            _decl: Some(decl),
+        })
+    }
+}
+
+impl FromDeclaration<&ImplItemFn, JavaMethod> for JavaMethod {
+    fn from_declaration(decl: &ImplItemFn) -> syn::Result<Self> {
+        let name = decl
+            .get_rename()
+            .unwrap_or_else(|| decl.sig.ident.to_string());
+
+        Self::from_sig(&decl.sig, name, FnDecl::Impl(decl.clone()))
+    }
+}
+
+impl FromDeclaration<&TraitItemFn, JavaMethod> for JavaMethod {
+    fn from_declaration(decl: &TraitItemFn) -> syn::Result<Self> {
+        let name = decl
+            .get_rename()
+            .unwrap_or_else(|| decl.sig.ident.to_string());
+
+        Self::from_sig(&decl.sig, name, FnDecl::Trait(decl.clone()))
+    }
+}
+
+impl FromDeclaration<&ItemFn, JavaMethod> for JavaMethod {
+    fn from_declaration(decl: &ItemFn) -> syn::Result<Self> {
+        let name = decl
+            .get_rename()
+            .unwrap_or_else(|| decl.sig.ident.to_string());
+
+        Self::from_sig(&decl.sig, name, FnDecl::Free(decl.clone()))
+    }
+}
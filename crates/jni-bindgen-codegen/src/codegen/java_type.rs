@@ -1,5 +1,6 @@
 use crate::codegen::traits::FromDeclaration;
 use crate::util::attrs::BindgenAttrs;
+use crate::util::context::context;
 use crate::util::traits::JniMethod;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
@@ -19,6 +20,13 @@ pub enum JNIArgGetter {
     ArgName(String),
 }
 
+/// A part of a JNI descriptor generated into the rust code.
+pub enum DescriptorPart {
+    Literal(String),
+    /// The class name of a type, taken from its `ClassName` implementation.
+    Class(TokenStream),
+}
+
 impl JavaArg {
     pub fn this() -> Self {
         Self {
@@ -43,6 +51,10 @@ impl JavaArg {
         matches!(self.java_type, JavaType::Env { .. })
     }
 
+    pub fn is_slice(&self) -> bool {
+        matches!(self.java_type, JavaType::Array { slice: true, .. })
+    }
+
     pub fn as_jni_fn_arg(&self) -> syn::Result<Option<TokenStream>> {
         if self.is_self() {
             return Err(syn::Error::new(
@@ -77,7 +89,11 @@ impl JavaArg {
             | JavaType::Object
             | JavaType::Vec { .. }
             | JavaType::HashMap { .. }
-            | JavaType::Interface { .. } => {
+            | JavaType::Interface { .. }
+            | JavaType::Value { .. }
+            | JavaType::DirectBuffer { .. }
+            | JavaType::Array { .. }
+            | JavaType::BigInteger { .. } => {
                 quote!(jni::objects::JObject<'local>)
             }
             rest => rest.as_jni_return_type()?,
@@ -133,6 +149,12 @@ impl JavaArg {
                     "Result is not a valid argument for a JNI method",
                 ))
             }
+            JavaType::Future { .. } => {
+                return Err(syn::Error::new(
+                    self.get_span(),
+                    "A future is not a valid argument for a JNI method",
+                ))
+            }
             JavaType::Option { java_type, inner } => {
                 ret_ty.unwrap_or(&JavaType::Void).match_error(
                 match java_type.as_ref() {
@@ -163,6 +185,10 @@ impl JavaArg {
                     JavaType::String => {
                         quote!(jni_bindgen::conversion::option_convert::string_from_jni(&mut env, #arg_name))
                     },
+                    JavaType::CheckedInt { ty, .. } | JavaType::BigInteger { ty } => {
+                        let from_jni = JavaType::option_convert_fn(ty, "from_jni");
+                        quote!(#from_jni(&mut env, #arg_name))
+                    }
                     JavaType::Reference {inner} => {
                         let inner = inner.into_token_stream();
 
@@ -192,6 +218,24 @@ impl JavaArg {
                             }
                         }
                     }
+                    JavaType::Value { inner, .. } | JavaType::DirectBuffer { inner } => {
+                        quote! {
+                            if #arg_name.is_null() {
+                                Ok(None)
+                            } else {
+                                <#inner>::from_jni(&mut env, #arg_name).map(Some)
+                            }
+                        }
+                    }
+                    JavaType::Array { ty, slice: false, .. } => {
+                        quote! {
+                            if #arg_name.is_null() {
+                                Ok(None)
+                            } else {
+                                jni_bindgen::conversion::array_convert::into_vec::<#ty>(&mut env, #arg_name).map(Some)
+                            }
+                        }
+                    }
                     _ => return Err(syn::Error::new(inner.span(), "Unsupported option type")),
                 })?
             },
@@ -232,6 +276,26 @@ impl JavaArg {
                     <Box<#inner>>::from_jni(&mut env, #arg_name)
                 })?
             }
+            JavaType::Value { inner, .. } | JavaType::DirectBuffer { inner } => {
+                ret_ty.unwrap_or(&JavaType::Void).match_error(quote!{
+                    <#inner>::from_jni(&mut env, #arg_name)
+                })?
+            }
+            JavaType::Array { ty, .. } => {
+                ret_ty.unwrap_or(&JavaType::Void).match_error(quote!(
+                    jni_bindgen::conversion::array_convert::into_vec::<#ty>(&mut env, #arg_name)
+                ))?
+            }
+            JavaType::CheckedInt { ty, .. } => {
+                ret_ty.unwrap_or(&JavaType::Void).match_error(quote!(
+                    <#ty as jni_bindgen::conversion::int_convert::CheckedInt>::from_java(#arg_name)
+                ))?
+            }
+            JavaType::BigInteger { ty } => {
+                ret_ty.unwrap_or(&JavaType::Void).match_error(quote!(
+                    <#ty>::from_jni(&mut env, #arg_name)
+                ))?
+            }
         }))
     }
 
@@ -269,7 +333,7 @@ impl JavaArg {
             | JavaType::Short
             | JavaType::Char
             | JavaType::Byte => {
-                quote!(let #out_arg = #arg_name.into();)
+                quote!(let #out_arg = jni::objects::JValue::from(#arg_name);)
             }
             JavaType::Env { .. } => {
                 return Err(syn::Error::new(
@@ -283,6 +347,12 @@ impl JavaArg {
                     "Result is not a valid argument for a JNI method",
                 ))
             }
+            JavaType::Future { .. } => {
+                return Err(syn::Error::new(
+                    self.get_span(),
+                    "A future is not a valid argument for a JNI method",
+                ))
+            }
             JavaType::Option { java_type, inner } => match java_type.as_ref() {
                 JavaType::Integer => {
                     quote! {
@@ -401,6 +471,15 @@ impl JavaArg {
                         let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
                     }
                 }
+                JavaType::CheckedInt { ty, .. } | JavaType::BigInteger { ty } => {
+                    let into_jni = JavaType::option_convert_fn(ty, "into_jni");
+                    quote! {
+                        let #inner_arg_name = unsafe {
+                            jni::objects::JObject::from_raw(#into_jni(env, #arg_name)?)
+                        };
+                        let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
+                    }
+                }
                 JavaType::Vec { ty, .. } => {
                     quote! {
                         let #inner_arg_name = if let Some(s) = #arg_name {
@@ -429,6 +508,32 @@ impl JavaArg {
                         let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
                     }
                 }
+                JavaType::Value { .. } | JavaType::DirectBuffer { .. } => {
+                    quote! {
+                        let #inner_arg_name = if let Some(s) = #arg_name {
+                            jni_bindgen::objects::traits::IntoJNI::into_jni(s, env)?
+                        } else {
+                            jni::objects::JObject::null()
+                        };
+                        let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
+                    }
+                }
+                JavaType::Array {
+                    ty, slice: false, ..
+                } => {
+                    quote! {
+                        let #inner_arg_name = if let Some(s) = #arg_name {
+                            unsafe {
+                                jni::objects::JObject::from_raw(
+                                    jni_bindgen::conversion::array_convert::from_slice::<#ty>(env, &s)?
+                                )
+                            }
+                        } else {
+                            jni::objects::JObject::null()
+                        };
+                        let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
+                    }
+                }
                 _ => return Err(syn::Error::new(inner.span(), "Unsupported option type")),
             },
             JavaType::Reference { .. } => {
@@ -438,7 +543,7 @@ impl JavaArg {
                 ))
             }
             JavaType::Object => {
-                quote!(let #out_arg = (&#arg_name).into();)
+                quote!(let #out_arg: jni::objects::JValue = (&#arg_name).into();)
             }
             JavaType::Vec { ty, .. } => {
                 quote! {
@@ -457,7 +562,34 @@ impl JavaArg {
                 }
             }
             JavaType::Interface { .. } => {
-                quote!(let #out_arg = #arg_name.obj.into();)
+                quote!(let #out_arg: jni::objects::JValue = #arg_name.obj.into();)
+            }
+            JavaType::Value { .. } | JavaType::DirectBuffer { .. } => {
+                quote! {
+                    let #inner_arg_name = jni_bindgen::objects::traits::IntoJNI::into_jni(#arg_name, env)?;
+                    let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
+                }
+            }
+            JavaType::Array { ty, .. } => {
+                quote! {
+                    let #inner_arg_name = unsafe {
+                        jni::objects::JObject::from_raw(jni_bindgen::conversion::array_convert::from_slice::<#ty>(env, &#arg_name)?)
+                    };
+                    let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
+                }
+            }
+            JavaType::CheckedInt { ty, .. } => {
+                quote! {
+                    let #out_arg = jni::objects::JValue::from(
+                        <#ty as jni_bindgen::conversion::int_convert::CheckedInt>::into_java(#arg_name)?
+                    );
+                }
+            }
+            JavaType::BigInteger { .. } => {
+                quote! {
+                    let #inner_arg_name = jni_bindgen::objects::traits::IntoJNI::into_jni(#arg_name, env)?;
+                    let #out_arg = jni::objects::JValue::from(&#inner_arg_name);
+                }
             }
         })
     }
@@ -515,7 +647,7 @@ pub enum JavaType {
     },
     HashMap {
         key: Type,
-        value: Type,
+        value: Box<Type>,
         java_key: Box<JavaType>,
         java_value: Box<JavaType>,
     },
@@ -523,14 +655,77 @@ pub enum JavaType {
         inner: Type,
         class_name: String,
     },
+    /// A type which is converted by value using its `FromJNI`
+    /// and `IntoJNI` implementations, e.g. an enum or a
+    /// data class annotated with `#[jni]`.
+    Value {
+        inner: TypePath,
+        class_name: String,
+    },
+    /// A `jni_bindgen::objects::direct_buffer::DirectBuffer`
+    /// which is mapped to a direct `java.nio.ByteBuffer`.
+    DirectBuffer {
+        inner: TypePath,
+    },
+    /// A `Vec` or slice of a primitive type which is
+    /// converted into a Java primitive array, e.g.
+    /// `Vec<u8>` or `&[u8]` into `byte[]`.
+    Array {
+        ty: Type,
+        java_type: Box<JavaType>,
+        slice: bool,
+    },
+    /// The return type of an `async` method, which is
+    /// mapped to a `java.util.concurrent.CompletableFuture`.
+    Future {
+        java_type: Box<JavaType>,
+    },
+    /// An integer type without a matching java primitive type,
+    /// e.g. `u32`, which is converted into a wider or signed
+    /// java type, checking for overflows.
+    CheckedInt {
+        ty: Type,
+        java_type: Box<JavaType>,
+    },
+    /// A 128 bit integer which is mapped to a `java.math.BigInteger`.
+    BigInteger {
+        ty: Type,
+    },
 }
 
 impl JavaType {
     pub fn throws(&self) -> Option<String> {
-        if let JavaType::Result { .. } = self {
-            Some(" throws NativeExecutionException".to_string())
-        } else {
-            None
+        self.exceptions()
+            .map(|exceptions| format!(" throws {}", exceptions.join(", ")))
+    }
+
+    /// Get the exception classes a method returning this type may throw.
+    /// Returns `None` if this is not a [`JavaType::Result`].
+    pub fn exceptions(&self) -> Option<Vec<String>> {
+        let JavaType::Result { result_type, .. } = self else {
+            return None;
+        };
+
+        Some(
+            Self::result_error_name(result_type)
+                .zip(context())
+                .and_then(|(name, context)| context.exceptions(&name))
+                .unwrap_or_else(|| vec!["NativeExecutionException".to_string()]),
+        )
+    }
+
+    /// Get the name of the error type of a `Result<T, E>`.
+    fn result_error_name(result_type: &TypePath) -> Option<String> {
+        let last = result_type.path.segments.last()?;
+        let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+            return None;
+        };
+
+        match args.args.iter().nth(1)? {
+            GenericArgument::Type(Type::Path(path)) => {
+                Some(path.path.segments.last()?.ident.to_string())
+            }
+            _ => None,
         }
     }
 
@@ -558,6 +753,21 @@ impl JavaType {
                     .insert("com.github.markusjx.jnibindgen.NativeExecutionException".to_string());
                 imports.extend(java_type.get_imports());
             }
+            JavaType::DirectBuffer { .. } => {
+                imports.insert("java.nio.ByteBuffer".to_string());
+            }
+            JavaType::BigInteger { .. } => {
+                imports.insert("java.math.BigInteger".to_string());
+            }
+            JavaType::Future { java_type } => {
+                imports.insert("java.util.concurrent.CompletableFuture".to_string());
+                imports.extend(java_type.unwrap_result().get_imports());
+            }
+            JavaType::Interface { class_name, .. } | JavaType::Value { class_name, .. }
+                if class_name.contains('.') =>
+            {
+                imports.insert(class_name.clone());
+            }
             _ => {}
         }
 
@@ -578,26 +788,24 @@ impl JavaType {
             JavaType::Char => "char".to_string(),
             JavaType::Byte => "byte".to_string(),
             JavaType::Option { java_type, .. } => match java_type.as_ref() {
-                JavaType::String => "String".to_string(),
-                JavaType::Integer => "Integer".to_string(),
-                JavaType::Long => "Long".to_string(),
-                JavaType::Boolean => "Boolean".to_string(),
-                JavaType::Float => "Float".to_string(),
-                JavaType::Double => "Double".to_string(),
-                JavaType::Short => "Short".to_string(),
-                JavaType::Char => "Character".to_string(),
-                JavaType::Byte => "Byte".to_string(),
-                JavaType::Reference { inner, .. } => inner.into_token_stream().to_string(),
-                JavaType::Vec { java_type, .. } => format!("List<{}>", java_type.as_declaration()?),
-                JavaType::HashMap {
-                    java_key,
-                    java_value,
-                    ..
-                } => format!(
-                    "Map<{}, {}>",
-                    java_key.as_declaration()?,
-                    java_value.as_declaration()?
-                ),
+                JavaType::String
+                | JavaType::Integer
+                | JavaType::Long
+                | JavaType::Boolean
+                | JavaType::Float
+                | JavaType::Double
+                | JavaType::Short
+                | JavaType::Char
+                | JavaType::Byte
+                | JavaType::Reference { .. }
+                | JavaType::Value { .. }
+                | JavaType::DirectBuffer { .. }
+                | JavaType::Interface { .. }
+                | JavaType::Array { .. }
+                | JavaType::Vec { .. }
+                | JavaType::HashMap { .. }
+                | JavaType::CheckedInt { .. }
+                | JavaType::BigInteger { .. } => java_type.as_boxed_declaration()?,
                 _ => panic!(
                     "Unsupported option type: {}",
                     java_type.as_declaration().unwrap_or("Env".into())
@@ -607,20 +815,119 @@ impl JavaType {
             JavaType::Env { .. } => return None,
             JavaType::Reference { inner, .. } => inner.into_token_stream().to_string(),
             JavaType::Object => "Object".to_string(),
-            JavaType::Vec { java_type, .. } => format!("List<{}>", java_type.as_declaration()?),
+            JavaType::Vec { java_type, .. } => {
+                format!("List<{}>", java_type.as_boxed_declaration()?)
+            }
             JavaType::HashMap {
                 java_key,
                 java_value,
                 ..
             } => format!(
                 "Map<{}, {}>",
-                java_key.as_declaration()?,
-                java_value.as_declaration()?
+                java_key.as_boxed_declaration()?,
+                java_value.as_boxed_declaration()?
             ),
-            JavaType::Interface { class_name, .. } => class_name.clone(),
+            JavaType::Interface { class_name, .. } | JavaType::Value { class_name, .. } => {
+                class_name
+                    .rsplit('.')
+                    .next()
+                    .unwrap_or(class_name)
+                    .to_string()
+            }
+            JavaType::DirectBuffer { .. } => "ByteBuffer".to_string(),
+            JavaType::Array { java_type, .. } => format!("{}[]", java_type.as_declaration()?),
+            JavaType::Future { java_type } => format!(
+                "CompletableFuture<{}>",
+                java_type.unwrap_result().as_boxed_declaration()?
+            ),
+            JavaType::CheckedInt { java_type, .. } => java_type.as_declaration()?,
+            JavaType::BigInteger { .. } => "BigInteger".to_string(),
+        })
+    }
+
+    /// Get the Java declaration of this type, using the
+    /// boxed class for primitive types, e.g. `Integer` for `int`.
+    pub fn as_boxed_declaration(&self) -> Option<String> {
+        Some(match self {
+            JavaType::Integer => "Integer".to_string(),
+            JavaType::Long => "Long".to_string(),
+            JavaType::Boolean => "Boolean".to_string(),
+            JavaType::Float => "Float".to_string(),
+            JavaType::Double => "Double".to_string(),
+            JavaType::Short => "Short".to_string(),
+            JavaType::Char => "Character".to_string(),
+            JavaType::Byte => "Byte".to_string(),
+            JavaType::Void => "Void".to_string(),
+            JavaType::CheckedInt { java_type, .. } => java_type.as_boxed_declaration()?,
+            rest => rest.as_declaration()?,
         })
     }
 
+    /// Get the Kotlin declaration of this type. Optional values
+    /// are mapped to nullable types, primitive arrays to the
+    /// Kotlin array types, e.g. `IntArray` for `int[]`.
+    pub fn as_kotlin_declaration(&self) -> Option<String> {
+        Some(match self {
+            JavaType::String => "String".to_string(),
+            JavaType::This => "Long".to_string(),
+            JavaType::Void => "Unit".to_string(),
+            JavaType::Integer => "Int".to_string(),
+            JavaType::Long => "Long".to_string(),
+            JavaType::Boolean => "Boolean".to_string(),
+            JavaType::Float => "Float".to_string(),
+            JavaType::Double => "Double".to_string(),
+            JavaType::Short => "Short".to_string(),
+            JavaType::Char => "Char".to_string(),
+            JavaType::Byte => "Byte".to_string(),
+            JavaType::Option { java_type, .. } => {
+                let inner = java_type.as_kotlin_declaration()?;
+                if inner.ends_with('?') {
+                    inner
+                } else {
+                    format!("{inner}?")
+                }
+            }
+            JavaType::Result { java_type, .. } => java_type.as_kotlin_declaration()?,
+            JavaType::Env { .. } => return None,
+            JavaType::Object => "Any?".to_string(),
+            JavaType::Vec { java_type, .. } => {
+                format!("List<{}>", java_type.as_kotlin_declaration()?)
+            }
+            JavaType::HashMap {
+                java_key,
+                java_value,
+                ..
+            } => format!(
+                "Map<{}, {}>",
+                java_key.as_kotlin_declaration()?,
+                java_value.as_kotlin_declaration()?
+            ),
+            JavaType::Array { java_type, .. } => {
+                format!("{}Array", java_type.as_kotlin_declaration()?)
+            }
+            JavaType::Future { java_type } => match java_type.unwrap_result() {
+                JavaType::Void => "CompletableFuture<Void?>".to_string(),
+                inner => format!("CompletableFuture<{}>", inner.as_kotlin_declaration()?),
+            },
+            JavaType::CheckedInt { java_type, .. } => java_type.as_kotlin_declaration()?,
+            JavaType::Reference { .. }
+            | JavaType::Interface { .. }
+            | JavaType::Value { .. }
+            | JavaType::DirectBuffer { .. }
+            | JavaType::BigInteger { .. } => self.as_declaration()?,
+        })
+    }
+
+    /// Get the imports required by the Kotlin declaration of this type.
+    /// Lists and maps are mapped to the Kotlin collection types,
+    /// which do not need to be imported.
+    pub fn get_kotlin_imports(&self) -> HashSet<String> {
+        self.get_imports()
+            .into_iter()
+            .filter(|i| i != "java.util.List" && i != "java.util.Map")
+            .collect()
+    }
+
     pub fn as_jni_return_type(&self) -> syn::Result<TokenStream> {
         Ok(match self {
             JavaType::String => quote!(jni::sys::jstring),
@@ -637,8 +944,15 @@ impl JavaType {
             JavaType::Option { .. }
             | JavaType::Object
             | JavaType::Vec { .. }
-            | JavaType::HashMap { .. } => quote!(jni::sys::jobject),
+            | JavaType::HashMap { .. }
+            | JavaType::Value { .. }
+            | JavaType::DirectBuffer { .. }
+            | JavaType::Interface { .. }
+            | JavaType::Array { slice: false, .. }
+            | JavaType::Future { .. }
+            | JavaType::BigInteger { .. } => quote!(jni::sys::jobject),
             JavaType::Result { java_type, .. } => java_type.as_jni_return_type()?,
+            JavaType::CheckedInt { java_type, .. } => java_type.as_jni_return_type()?,
             JavaType::Env { inner, .. } => {
                 return Err(syn::Error::new(
                     inner.span(),
@@ -651,10 +965,12 @@ impl JavaType {
                     "A reference to a type cannot be returned",
                 ))
             }
-            JavaType::Interface { inner, .. } => {
+            JavaType::Array {
+                ty, slice: true, ..
+            } => {
                 return Err(syn::Error::new(
-                    inner.span(),
-                    "Interfaces cannot be returned",
+                    ty.span(),
+                    "A slice cannot be returned, use a Vec instead",
                 ))
             }
         })
@@ -666,9 +982,16 @@ impl JavaType {
             | JavaType::Option { .. }
             | JavaType::Object
             | JavaType::Vec { .. }
-            | JavaType::HashMap { .. } => {
+            | JavaType::HashMap { .. }
+            | JavaType::Value { .. }
+            | JavaType::DirectBuffer { .. }
+            | JavaType::Interface { .. }
+            | JavaType::Array { .. }
+            | JavaType::Future { .. }
+            | JavaType::BigInteger { .. } => {
                 quote!(std::ptr::null_mut())
             }
+            JavaType::CheckedInt { java_type, .. } => java_type.error_return_val()?,
             JavaType::This
             | JavaType::Integer
             | JavaType::Long
@@ -691,12 +1014,6 @@ impl JavaType {
                     "A reference to a type cannot be returned",
                 ))
             }
-            JavaType::Interface { inner, .. } => {
-                return Err(syn::Error::new(
-                    inner.span(),
-                    "Interfaces cannot be returned",
-                ))
-            }
         })
     }
 
@@ -735,10 +1052,36 @@ impl JavaType {
                 quote!(HashMap<#key_ty, #value_ty>)
             }
             JavaType::Interface { inner, .. } => quote!(Box<#inner>),
+            JavaType::Value { inner, .. } | JavaType::DirectBuffer { inner } => quote!(#inner),
+            JavaType::Array { ty, slice, .. } => {
+                if *slice {
+                    quote!(&[#ty])
+                } else {
+                    quote!(Vec<#ty>)
+                }
+            }
+            JavaType::Future { .. } => {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "Interface methods cannot be async",
+                ))
+            }
+            JavaType::CheckedInt { ty, .. } | JavaType::BigInteger { ty } => quote!(#ty),
         })
     }
 
-    pub fn as_jni_declaration(&self) -> String {
+    fn as_jni_class_name(class_name: &str, namespace: &str) -> String {
+        if class_name.contains('.') {
+            format!("L{};", class_name.replace('.', "/"))
+        } else {
+            format!("L{}/{};", namespace.replace('.', "/"), class_name)
+        }
+    }
+
+    /// Get the JNI type signature of this type.
+    /// Class names which are not fully qualified are
+    /// assumed to be in the given `namespace`.
+    pub fn as_jni_declaration(&self, namespace: &str) -> String {
         match self {
             JavaType::String => "Ljava/lang/String;".to_string(),
             JavaType::This => panic!("Self is not a valid argument for a JNI method"),
@@ -752,7 +1095,7 @@ impl JavaType {
             JavaType::Char => "C".to_string(),
             JavaType::Byte => "B".to_string(),
             JavaType::Env { .. } => panic!("Env is not a valid argument for a JNI method"),
-            JavaType::Result { java_type, .. } => java_type.as_jni_declaration(),
+            JavaType::Result { java_type, .. } => java_type.as_jni_declaration(namespace),
             JavaType::Option { java_type, .. } => match java_type.as_ref() {
                 JavaType::String => "Ljava/lang/String;".to_string(),
                 JavaType::This => panic!("Self is not a valid argument for a JNI method"),
@@ -766,25 +1109,77 @@ impl JavaType {
                 JavaType::Char => "Ljava/lang/Character;".to_string(),
                 JavaType::Byte => "Ljava/lang/Byte;".to_string(),
                 JavaType::Env { .. } => panic!("Env is not a valid argument for a JNI method"),
-                JavaType::Result { java_type, .. } => java_type.as_jni_declaration(),
+                JavaType::Result { java_type, .. } => java_type.as_jni_declaration(namespace),
                 JavaType::Option { .. } => {
                     panic!("Option is not a valid argument for a JNI method")
                 }
-                JavaType::Reference { .. } => panic!("A reference to a type cannot be passed"),
+                JavaType::Reference { .. } => java_type.as_jni_declaration(namespace),
                 JavaType::Object => "Ljava/lang/Object;".to_string(),
                 JavaType::Vec { .. } => "Ljava/util/List;".to_string(),
                 JavaType::HashMap { .. } => "Ljava/util/Map;".to_string(),
-                JavaType::Interface { class_name, .. } => {
-                    format!("L{};", class_name.replace('.', "/"))
+                JavaType::Interface { class_name, .. } | JavaType::Value { class_name, .. } => {
+                    Self::as_jni_class_name(class_name, namespace)
+                }
+                JavaType::DirectBuffer { .. } => "Ljava/nio/ByteBuffer;".to_string(),
+                JavaType::Array { .. } => java_type.as_jni_declaration(namespace),
+                JavaType::Future { .. } => "Ljava/util/concurrent/CompletableFuture;".to_string(),
+                JavaType::CheckedInt { java_type, .. } => {
+                    format!("Ljava/lang/{};", java_type.as_boxed_declaration().unwrap())
                 }
+                JavaType::BigInteger { .. } => "Ljava/math/BigInteger;".to_string(),
             },
-            JavaType::Reference { .. } => panic!("A reference to a type cannot be passed"),
+            JavaType::Reference { inner } => {
+                Self::as_jni_class_name(&inner.to_token_stream().to_string(), namespace)
+            }
             JavaType::Object => "Ljava/lang/Object;".to_string(),
             JavaType::Vec { .. } => "Ljava/util/List;".to_string(),
             JavaType::HashMap { .. } => "Ljava/util/Map;".to_string(),
-            JavaType::Interface { class_name, .. } => {
-                format!("L{};", class_name.replace('.', "/"))
+            JavaType::Interface { class_name, .. } | JavaType::Value { class_name, .. } => {
+                Self::as_jni_class_name(class_name, namespace)
             }
+            JavaType::DirectBuffer { .. } => "Ljava/nio/ByteBuffer;".to_string(),
+            JavaType::Array { java_type, .. } => {
+                format!("[{}", java_type.as_jni_declaration(namespace))
+            }
+            JavaType::Future { .. } => "Ljava/util/concurrent/CompletableFuture;".to_string(),
+            JavaType::CheckedInt { java_type, .. } => java_type.as_jni_declaration(namespace),
+            JavaType::BigInteger { .. } => "Ljava/math/BigInteger;".to_string(),
+        }
+    }
+
+    /// Get the JNI type signature of this type as parts of a descriptor generated
+    /// into the rust code. Unlike [`as_jni_declaration`](Self::as_jni_declaration),
+    /// the class names of types which are not known while expanding the macros are
+    /// taken from their `ClassName` implementation, as they may be declared in
+    /// another package than `namespace`.
+    pub fn as_jni_descriptor_parts(&self, namespace: &str) -> Vec<DescriptorPart> {
+        let class = |inner: &TypePath| {
+            vec![
+                DescriptorPart::Literal("L".to_string()),
+                DescriptorPart::Class(
+                    quote!(<#inner as jni_bindgen::objects::traits::ClassName>::CLASS_NAME),
+                ),
+                DescriptorPart::Literal(";".to_string()),
+            ]
+        };
+
+        match self {
+            JavaType::Value { inner, class_name }
+                if !class_name.contains('.') && class_name != "Self" =>
+            {
+                class(inner)
+            }
+            JavaType::Reference { inner } if !inner.path.is_ident("Self") => class(inner),
+            JavaType::Result { java_type, .. } => java_type.as_jni_descriptor_parts(namespace),
+            JavaType::Option { java_type, .. }
+                if matches!(
+                    java_type.as_ref(),
+                    JavaType::Result { .. } | JavaType::Reference { .. } | JavaType::Value { .. }
+                ) =>
+            {
+                java_type.as_jni_descriptor_parts(namespace)
+            }
+            _ => vec![DescriptorPart::Literal(self.as_jni_declaration(namespace))],
         }
     }
 
@@ -856,6 +1251,11 @@ impl JavaType {
                         panic!("Result is not a valid argument for a JNI method")
                     }
                     JavaType::Option { .. } => panic!("Option must not be nested"),
+                    JavaType::Future { .. } => panic!("Interface methods cannot be async"),
+                    JavaType::CheckedInt { ty, .. } | JavaType::BigInteger { ty } => {
+                        let from_jni = Self::option_convert_fn(ty, "from_jni");
+                        quote!(#from_jni(env, res.l()?).map_err(Into::into))
+                    }
                     JavaType::Reference { inner } => {
                         let inner = inner.into_token_stream();
 
@@ -912,6 +1312,28 @@ impl JavaType {
                             }
                         }
                     }
+                    JavaType::Value { inner, .. } | JavaType::DirectBuffer { inner } => {
+                        quote! {
+                            let res_unpacked = res.l()?;
+                            if res_unpacked.is_null() {
+                                Ok(None)
+                            } else {
+                                <#inner>::from_jni(env, res_unpacked).map(Some).map_err(Into::into)
+                            }
+                        }
+                    }
+                    JavaType::Array { ty, .. } => {
+                        quote! {
+                            let res_unpacked = res.l()?;
+                            if res_unpacked.is_null() {
+                                Ok(None)
+                            } else {
+                                jni_bindgen::conversion::array_convert::into_vec::<#ty>(env, res_unpacked)
+                                    .map(Some)
+                                    .map_err(Into::into)
+                            }
+                        }
+                    }
                 }
             }
             JavaType::Reference { .. } => panic!("A reference to a type cannot be passed"),
@@ -925,6 +1347,27 @@ impl JavaType {
             JavaType::Interface { inner, .. } => {
                 quote!(Box<#inner>::from_jni(env, res.l()?).map_err(Into::into))
             }
+            JavaType::Value { inner, .. } | JavaType::DirectBuffer { inner } => {
+                quote!(<#inner>::from_jni(env, res.l()?).map_err(Into::into))
+            }
+            JavaType::Array { ty, .. } => {
+                quote!(jni_bindgen::conversion::array_convert::into_vec::<#ty>(env, res.l()?).map_err(Into::into))
+            }
+            JavaType::Future { .. } => panic!("Interface methods cannot be async"),
+            JavaType::CheckedInt { ty, java_type } => {
+                let getter = match java_type.as_ref() {
+                    JavaType::Short => quote!(s),
+                    _ => quote!(j),
+                };
+
+                quote! {
+                    <#ty as jni_bindgen::conversion::int_convert::CheckedInt>::from_java(res.#getter()?)
+                        .map_err(Into::into)
+                }
+            }
+            JavaType::BigInteger { ty } => {
+                quote!(<#ty>::from_jni(env, res.l()?).map_err(Into::into))
+            }
         }
     }
 
@@ -944,7 +1387,7 @@ impl JavaType {
                 }
             },
             JavaType::This => quote!(
-                Box::into_raw(Box::new(res)) as jni::sys::jlong
+                jni_bindgen::conversion::class_convert::into_ptr(std::sync::Arc::new(res))
             ),
             JavaType::Void => quote!(()),
             JavaType::Integer => quote!(res as jni::sys::jint),
@@ -1049,6 +1492,28 @@ impl JavaType {
                         }
                     }
                 }
+                JavaType::Value { .. } | JavaType::DirectBuffer { .. } | JavaType::Interface { .. } => {
+                    quote! {
+                        if let Some(s) = res {
+                            jni_bindgen::objects::traits::IntoJNI::into_jni(s, &mut env).map(|o| o.into_raw())
+                        } else {
+                            Ok(std::ptr::null_mut())
+                        }
+                    }
+                }
+                JavaType::Array { ty, slice: false, .. } => {
+                    quote! {
+                        if let Some(s) = res {
+                            jni_bindgen::conversion::array_convert::from_slice::<#ty>(&mut env, &s)
+                        } else {
+                            Ok(std::ptr::null_mut())
+                        }
+                    }
+                }
+                JavaType::CheckedInt { ty, .. } | JavaType::BigInteger { ty } => {
+                    let into_jni = Self::option_convert_fn(ty, "into_jni");
+                    quote!(#into_jni(&mut env, res))
+                }
                 _ => return Err(syn::Error::new(inner.span(), "Unsupported option type")),
             })?,
             JavaType::Reference { inner, .. } => return Err(syn::Error::new(
@@ -1061,13 +1526,134 @@ impl JavaType {
             JavaType::HashMap { key, value, .. } => {
                 self.match_error(quote!(jni_bindgen::conversion::object_convert::from_hashmap::<#key, #value>(&mut env, res)))?
             }
-            JavaType::Interface { inner, .. } => return Err(syn::Error::new(
-                inner.span(),
-                "Interfaces cannot be returned"
-            ))
+            JavaType::Value { .. } | JavaType::DirectBuffer { .. } | JavaType::Interface { .. } | JavaType::BigInteger { .. } => {
+                self.match_error(quote!(jni_bindgen::objects::traits::IntoJNI::into_jni(res, &mut env).map(|o| o.into_raw())))?
+            }
+            JavaType::CheckedInt { ty, .. } => {
+                self.match_error(quote!(<#ty as jni_bindgen::conversion::int_convert::CheckedInt>::into_java(res)))?
+            }
+            JavaType::Array { ty, slice: false, .. } => {
+                self.match_error(quote!(jni_bindgen::conversion::array_convert::from_slice::<#ty>(&mut env, &res)))?
+            }
+            JavaType::Array { ty, slice: true, .. } => return Err(syn::Error::new(
+                ty.span(),
+                "A slice cannot be returned, use a Vec instead"
+            )),
+            JavaType::Future { java_type } => {
+                let (inner, future) = match java_type.as_ref() {
+                    JavaType::Result { java_type, .. } => (java_type.as_ref(), quote! {
+                        async move {
+                            res.await
+                                .into_jni_result()
+                                .map_err(|e| e.or_class(jni_bindgen::errors::jni_error::ErrorClass::NativeExecutionException))
+                        }
+                    }),
+                    rest => (rest, quote!(async move { Ok(res.await) })),
+                };
+
+                let convert = if inner.is_void(false) {
+                    quote!(|_, _| Ok(jni::objects::JObject::null()))
+                } else {
+                    let val = inner.as_boxed_jni_val()?;
+                    quote!(|env, res| #val)
+                };
+
+                self.match_error(quote! {
+                    jni_bindgen::future::spawn(&mut env, #future, #convert).map(|o| o.into_raw())
+                })?
+            }
+        })
+    }
+
+    /// Convert `res` into a java object, boxing primitive types.
+    /// This is used to complete the future returned by `async` methods,
+    /// where `env` is a `&mut JNIEnv`.
+    fn as_boxed_jni_val(&self) -> syn::Result<TokenStream> {
+        Ok(match self {
+            JavaType::Void => quote!(Ok(jni::objects::JObject::null())),
+            JavaType::Option { java_type, .. } => {
+                let inner = java_type.as_boxed_jni_val()?;
+                quote! {
+                    match res {
+                        Some(res) => #inner,
+                        None => Ok(jni::objects::JObject::null()),
+                    }
+                }
+            }
+            JavaType::Array {
+                ty, slice: false, ..
+            } => quote! {
+                jni_bindgen::conversion::array_convert::from_slice::<#ty>(env, &res)
+                    .map(|o| unsafe { jni::objects::JObject::from_raw(o) })
+            },
+            JavaType::String
+            | JavaType::Integer
+            | JavaType::Long
+            | JavaType::Boolean
+            | JavaType::Float
+            | JavaType::Double
+            | JavaType::Short
+            | JavaType::Char
+            | JavaType::Byte
+            | JavaType::Vec { .. }
+            | JavaType::HashMap { .. }
+            | JavaType::Value { .. }
+            | JavaType::CheckedInt { .. }
+            | JavaType::BigInteger { .. } => {
+                quote!(jni_bindgen::objects::traits::IntoJNI::into_jni(res, env))
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "Unsupported return type for an async method",
+                ))
+            }
         })
     }
 
+    /// Replace `Self` in this type with the struct of the impl block
+    /// it is used in. This is required for methods returning `Self`
+    /// or `Arc<Self>` which are not constructors, as the generated
+    /// JNI functions are not part of the impl block.
+    pub fn replace_self(self, self_ty: &TypePath, class_name: &str) -> Self {
+        match self {
+            JavaType::This => JavaType::Value {
+                inner: self_ty.clone(),
+                class_name: class_name.to_string(),
+            },
+            JavaType::Value {
+                inner,
+                class_name: value_class_name,
+            } => JavaType::Value {
+                inner: replace_self_ty(inner, self_ty),
+                class_name: if value_class_name == "Self" {
+                    class_name.to_string()
+                } else {
+                    value_class_name
+                },
+            },
+            JavaType::Result {
+                java_type,
+                result_type,
+            } => JavaType::Result {
+                java_type: Box::new(java_type.replace_self(self_ty, class_name)),
+                result_type: replace_self_ty(result_type, self_ty),
+            },
+            JavaType::Option { java_type, inner } => JavaType::Option {
+                java_type: Box::new(java_type.replace_self(self_ty, class_name)),
+                inner: replace_self_ty(inner, self_ty),
+            },
+            JavaType::Vec { ty, java_type } => JavaType::Vec {
+                ty: replace_self_ty(ty, self_ty),
+                java_type: Box::new(java_type.replace_self(self_ty, class_name)),
+            },
+            JavaType::Future { java_type } => JavaType::Future {
+                java_type: Box::new(java_type.replace_self(self_ty, class_name)),
+            },
+            rest => rest,
+        }
+    }
+
     pub fn is_void(&self, check_result: bool) -> bool {
         match self {
             JavaType::Void => true,
@@ -1076,6 +1662,23 @@ impl JavaType {
         }
     }
 
+    /// Get the function of `jni_bindgen::conversion::option_convert`
+    /// converting an optional value of the given integer type,
+    /// e.g. `u32_into_jni` for `u32` and `into_jni`.
+    fn option_convert_fn(ty: &Type, suffix: &str) -> TokenStream {
+        let name = Ident::new(&format!("{}_{suffix}", ty.into_token_stream()), ty.span());
+        quote!(jni_bindgen::conversion::option_convert::#name)
+    }
+
+    /// Get the type of the value of a [`JavaType::Result`],
+    /// or this type if it is not a result.
+    pub fn unwrap_result(&self) -> &JavaType {
+        match self {
+            JavaType::Result { java_type, .. } => java_type,
+            rest => rest,
+        }
+    }
+
     fn match_error(&self, inner: TokenStream) -> syn::Result<TokenStream> {
         let error_return_val = self.error_return_val()?;
 
@@ -1094,6 +1697,77 @@ impl JavaType {
         })
     }
 
+    /// Get a plain path type without any generic arguments
+    /// starting with an uppercase letter and its last segment.
+    fn plain_path(ty: &Type) -> Option<(&TypePath, &Ident)> {
+        let Type::Path(path) = ty else {
+            return None;
+        };
+
+        let last = path.path.segments.last()?;
+        if !matches!(last.arguments, syn::PathArguments::None)
+            || !last.ident.to_string().starts_with(char::is_uppercase)
+        {
+            return None;
+        }
+
+        Some((path, &last.ident))
+    }
+
+    /// Match a plain path type without any generic arguments.
+    /// Such a type is expected to be an enum, a data class or a
+    /// struct annotated with `#[jni]`. The build script generator
+    /// knows all of these types and only accepts declared types.
+    /// When expanding the macros, the type is expected to be
+    /// declared in the package of the class using it.
+    fn match_value(ty: &Type) -> Option<Self> {
+        let (path, ident) = Self::plain_path(ty)?;
+        let class_name = match context() {
+            Some(context) if ident != "Self" => context.class_name(&ident.to_string())?.to_string(),
+            _ => ident.to_string(),
+        };
+
+        Some(JavaType::Value {
+            inner: path.clone(),
+            class_name,
+        })
+    }
+
+    /// Match the element type of a `Vec` or slice which
+    /// is converted into a Java primitive array.
+    fn match_array_element(ty: &Type) -> Option<Self> {
+        Some(match ty.into_token_stream().to_string().as_str() {
+            "i8" | "u8" => JavaType::Byte,
+            "i16" => JavaType::Short,
+            "u16" => JavaType::Char,
+            "i32" => JavaType::Integer,
+            "i64" => JavaType::Long,
+            "f32" => JavaType::Float,
+            "f64" => JavaType::Double,
+            "bool" => JavaType::Boolean,
+            _ => return None,
+        })
+    }
+
+    /// Convert this type into the element type of a `Vec` or `HashMap`.
+    /// The elements of a collection are converted using `FromJNI`
+    /// and `IntoJNI`, so nested primitive vectors are kept as lists.
+    fn into_element(self) -> syn::Result<Self> {
+        match self {
+            JavaType::Array {
+                ty, slice: false, ..
+            } if Self::from_declaration(&Box::new(ty.clone())).is_ok() => Ok(JavaType::Vec {
+                java_type: Box::new(Self::from_declaration(&Box::new(ty.clone()))?),
+                ty,
+            }),
+            JavaType::Array { ty, .. } => Err(syn::Error::new(
+                ty.span(),
+                "Unsupported collection element type",
+            )),
+            rest => Ok(rest),
+        }
+    }
+
     fn match_typed<T: Spanned>(
         ty: &Type,
         decl: &T,
@@ -1103,7 +1777,7 @@ impl JavaType {
             Type::Path(path) => {
                 if let Some(last) = path.path.segments.last() {
                     match last.ident.to_string().as_str() {
-                        "Result" | "Option" | "Vec" | "Box" => {
+                        "Result" | "Option" | "Vec" | "Box" | "Arc" => {
                             if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
                                 if let Some(GenericArgument::Type(ty)) = args.args.first() {
                                     match last.ident.to_string().as_str() {
@@ -1124,12 +1798,38 @@ impl JavaType {
                                             }))
                                         }
                                         "Vec" => {
+                                            if let Some(java_type) = Self::match_array_element(ty) {
+                                                return Ok(Some(JavaType::Array {
+                                                    ty: ty.clone(),
+                                                    java_type: Box::new(java_type),
+                                                    slice: false,
+                                                }));
+                                            }
+
                                             return Ok(Some(JavaType::Vec {
                                                 ty: ty.clone(),
-                                                java_type: Box::new(JavaType::from_declaration(
-                                                    &Box::new(ty.clone()),
-                                                )?),
-                                            }))
+                                                java_type: Box::new(
+                                                    JavaType::from_declaration(&Box::new(
+                                                        ty.clone(),
+                                                    ))?
+                                                    .into_element()?,
+                                                ),
+                                            }));
+                                        }
+                                        "Arc" => {
+                                            if let Some(JavaType::Value { class_name, .. }) =
+                                                Self::match_value(ty)
+                                            {
+                                                return Ok(Some(JavaType::Value {
+                                                    inner: path.clone(),
+                                                    class_name,
+                                                }));
+                                            }
+
+                                            return Err(syn::Error::new(
+                                                decl.span(),
+                                                "Arc must contain a struct exported using #[jni]",
+                                            ));
                                         }
                                         "Box" => {
                                             let mut ty = ty.clone();
@@ -1159,7 +1859,12 @@ impl JavaType {
                                                         _ => None,
                                                     })
                                                 {
-                                                    l.ident = Ident::new("local", l.ident.span());
+                                                    // Only GlobalRef backed implementations
+                                                    // of an interface may outlive the call
+                                                    if l.ident != "static" {
+                                                        l.ident =
+                                                            Ident::new("local", l.ident.span());
+                                                    }
                                                 } else {
                                                     return Err(syn::Error::new(
                                                         decl.span(),
@@ -1207,9 +1912,17 @@ impl JavaType {
                                                 }
                                             }
 
+                                            // Use the package of the trait if it is known
+                                            let class_name = class_name.unwrap();
+                                            let class_name = context()
+                                                .and_then(|c| {
+                                                    c.class_name(&class_name).map(str::to_string)
+                                                })
+                                                .unwrap_or(class_name);
+
                                             return Ok(Some(JavaType::Interface {
                                                 inner: ty.clone(),
-                                                class_name: class_name.unwrap(),
+                                                class_name,
                                             }));
                                         }
                                         _ => unreachable!(),
@@ -1218,19 +1931,26 @@ impl JavaType {
                             }
                         }
                         "JObject" => return Ok(Some(JavaType::Object)),
+                        "DirectBuffer" => {
+                            return Ok(Some(JavaType::DirectBuffer {
+                                inner: path.clone(),
+                            }))
+                        }
                         "HashMap" => {
                             if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
                                 if let Some(GenericArgument::Type(ty)) = args.args.first() {
                                     if let Some(GenericArgument::Type(ty2)) = args.args.last() {
                                         return Ok(Some(JavaType::HashMap {
                                             key: ty.clone(),
-                                            value: ty2.clone(),
-                                            java_key: Box::new(JavaType::from_declaration(
-                                                &Box::new(ty.clone()),
-                                            )?),
-                                            java_value: Box::new(JavaType::from_declaration(
-                                                &Box::new(ty2.clone()),
-                                            )?),
+                                            value: Box::new(ty2.clone()),
+                                            java_key: Box::new(
+                                                JavaType::from_declaration(&Box::new(ty.clone()))?
+                                                    .into_element()?,
+                                            ),
+                                            java_value: Box::new(
+                                                JavaType::from_declaration(&Box::new(ty2.clone()))?
+                                                    .into_element()?,
+                                            ),
                                         }));
                                     }
                                 }
@@ -1241,6 +1961,23 @@ impl JavaType {
                 }
             }
             Type::Reference(reference) => {
+                if let Type::Slice(slice) = reference.elem.as_ref() {
+                    if let Some(java_type) = Self::match_array_element(&slice.elem) {
+                        return if reference.mutability.is_some() {
+                            Err(syn::Error::new(
+                                decl.span(),
+                                "Mutable references are not supported",
+                            ))
+                        } else {
+                            Ok(Some(JavaType::Array {
+                                ty: slice.elem.as_ref().clone(),
+                                java_type: Box::new(java_type),
+                                slice: true,
+                            }))
+                        };
+                    }
+                }
+
                 if let Type::Path(path) = reference.elem.as_ref() {
                     if let Some(last) = path.path.segments.last() {
                         if last.ident == "JNIEnv" {
@@ -1270,6 +2007,28 @@ impl JavaType {
     }
 }
 
+fn replace_self_ty<T: syn::parse::Parse + ToTokens>(ty: T, self_ty: &TypePath) -> T {
+    syn::parse2(replace_self_tokens(ty.into_token_stream(), self_ty)).unwrap()
+}
+
+fn replace_self_tokens(tokens: TokenStream, self_ty: &TypePath) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) if ident == "Self" => self_ty.to_token_stream(),
+            proc_macro2::TokenTree::Group(group) => {
+                let mut res = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_self_tokens(group.stream(), self_ty),
+                );
+                res.set_span(group.span());
+                proc_macro2::TokenTree::Group(res).into_token_stream()
+            }
+            rest => rest.into_token_stream(),
+        })
+        .collect()
+}
+
 impl FromDeclaration<&FnArg, JavaType> for JavaType {
     fn from_declaration(decl: &FnArg) -> syn::Result<Self> {
         match decl {
@@ -1277,8 +2036,17 @@ impl FromDeclaration<&FnArg, JavaType> for JavaType {
             FnArg::Typed(PatType { ty, attrs, .. }) => {
                 let attr = attrs.get_jni_attr();
 
+                let class_name = attr.as_ref().and_then(|a| a.get_class_name());
+
                 if let Some(res) = Self::match_typed(ty, decl, attr)? {
                     Ok(res)
+                } else if let Some(((path, _), class_name)) = Self::plain_path(ty).zip(class_name) {
+                    // The class is set explicitly, thus the
+                    // type does not need to be known
+                    Ok(JavaType::Value {
+                        inner: path.clone(),
+                        class_name,
+                    })
                 } else {
                     Self::from_declaration(ty)
                 }
@@ -1302,6 +2070,17 @@ impl FromDeclaration<&Box<Type>, JavaType> for JavaType {
             "i16" => JavaType::Short,
             "u16" => JavaType::Char,
             "i8" => JavaType::Byte,
+            "u8" => JavaType::CheckedInt {
+                ty: decl.as_ref().clone(),
+                java_type: Box::new(JavaType::Short),
+            },
+            "u32" | "u64" | "usize" | "isize" => JavaType::CheckedInt {
+                ty: decl.as_ref().clone(),
+                java_type: Box::new(JavaType::Long),
+            },
+            "i128" | "u128" => JavaType::BigInteger {
+                ty: decl.as_ref().clone(),
+            },
             "& jni :: JNIEnv" | "& JNIEnv" | "& mut jni :: JNIEnv" | "& mut JNIEnv" => {
                 JavaType::Env {
                     mutable: as_str.starts_with("& mut"),
@@ -1311,6 +2090,16 @@ impl FromDeclaration<&Box<Type>, JavaType> for JavaType {
             _ => {
                 if let Some(res) = Self::match_typed(decl, decl, None)? {
                     res
+                } else if let Some(res) = Self::match_value(decl) {
+                    res
+                } else if Self::plain_path(decl).is_some() {
+                    Err(syn::Error::new(
+                        decl.span(),
+                        format!(
+                            "Unsupported type: '{}'. Only enums, data classes and structs annotated with #[jni] are supported",
+                            decl.into_token_stream()
+                        ),
+                    ))?
                 } else {
                     Err(syn::Error::new(
                         decl.span(),
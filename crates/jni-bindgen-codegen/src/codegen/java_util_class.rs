@@ -0,0 +1,125 @@
+use crate::codegen::code::util_class;
+use crate::codegen::java_method::JavaMethod;
+use crate::codegen::kotlin;
+use crate::codegen::signature::JniSignature;
+use crate::codegen::traits::FromDeclaration;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::context::Context;
+use crate::util::quotes;
+use proc_macro2::TokenStream;
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::ItemFn;
+
+/// A free function exported as a static method of a java utility class.
+/// All functions with the same package and class name are gathered into
+/// a single java class. As every function is expanded on its own, the
+/// java class is generated from the functions of the class collected
+/// in the [`Context`] of the build script generator.
+pub struct JavaUtilClass {
+    pub name: String,
+    pub namespace: String,
+    pub method: JavaMethod,
+    load_lib: Option<String>,
+}
+
+impl JavaUtilClass {
+    pub fn from_declaration(decl: &ItemFn, args: &BindgenAttrs) -> syn::Result<Self> {
+        let name = args.get_class().ok_or(syn::Error::new(
+            args.span,
+            "Missing class = \"...\" attribute",
+        ))?;
+
+        let mut method = JavaMethod::from_declaration(decl)?;
+        if !method.static_method {
+            return Err(syn::Error::new(
+                decl.sig.span(),
+                "Free functions cannot take self",
+            ));
+        }
+        if let Some(rename) = args.get_rename() {
+            method.name = rename;
+        }
+
+        Ok(Self {
+            name,
+            namespace: args.get_namespace()?,
+            method,
+            load_lib: args.load_lib(),
+        })
+    }
+
+    pub fn as_jni_methods(&self) -> syn::Result<TokenStream> {
+        let base_name = quotes::base_name(&self.namespace.replace('.', "_"), &self.name);
+
+        // The function is called relative to the module
+        // the JNI function is generated in
+        self.method.as_jni_method(base_name, "self")
+    }
+
+    /// Get the signature of the native method of this function.
+    pub fn signature(&self) -> JniSignature {
+        let base_name = quotes::base_name(&self.namespace.replace('.', "_"), &self.name);
+        let class_name = format!(
+            "{}/{}${}Native",
+            self.namespace.replace('.', "/"),
+            self.name,
+            self.name
+        );
+
+        self.method
+            .as_native_signature(&class_name, &base_name, &self.namespace)
+    }
+
+    /// Generate the java class from all functions of this class.
+    pub fn as_java_declaration(&self, context: &Context) -> syn::Result<String> {
+        let functions = context.functions(&self.namespace, &self.name)?;
+        let imports = functions
+            .iter()
+            .flat_map(|f| f.method.get_imports())
+            .collect::<HashSet<_>>();
+
+        Ok(util_class(
+            &self.namespace,
+            &self.name,
+            functions
+                .iter()
+                .map(|f| f.method.as_declaration(&self.name, true))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            functions
+                .iter()
+                .map(|f| f.method.as_declaration(&self.name, false))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            functions.iter().find_map(|f| f.load_lib.clone()),
+            imports,
+        ))
+    }
+
+    /// Generate the Kotlin object from all functions of this class.
+    pub fn as_kotlin_declaration(&self, context: &Context) -> syn::Result<String> {
+        let functions = context.functions(&self.namespace, &self.name)?;
+        let imports = functions
+            .iter()
+            .flat_map(|f| f.method.get_kotlin_imports())
+            .collect::<HashSet<_>>();
+
+        Ok(kotlin::util_class(
+            &self.namespace,
+            &self.name,
+            functions
+                .iter()
+                .map(|f| f.method.as_kotlin_declaration(&self.name, true))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            functions
+                .iter()
+                .map(|f| f.method.as_kotlin_declaration(&self.name, false))
+                .collect::<Vec<_>>()
+                .join("\n\n"),
+            functions.iter().find_map(|f| f.load_lib.clone()),
+            imports,
+        ))
+    }
+}
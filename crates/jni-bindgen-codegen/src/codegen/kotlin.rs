@@ -0,0 +1,422 @@
+use std::collections::HashSet;
+
+/// Kotlin keywords which may be used as identifiers
+/// in rust, but must be escaped in Kotlin.
+const KEYWORDS: &[&str] = &[
+    "class",
+    "fun",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "throw",
+    "typealias",
+    "val",
+    "var",
+    "when",
+];
+
+const CLOSE: &str = r#"
+    /**
+     * Destroy the native object, if it has not been destroyed yet.
+     */
+    override fun close() {
+        if (isValid()) {
+            destroyNative()
+        }
+    }
+"#;
+
+const DESTRUCT: &str = r#"
+    override fun destruct() {
+        drop(getPtr())
+    }
+"#;
+
+/// Escape an identifier which is a Kotlin keyword.
+pub fn escape(name: String) -> String {
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("`{name}`")
+    } else {
+        name
+    }
+}
+
+pub fn interface(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    methods: String,
+    functional: bool,
+    additional_imports: HashSet<String>,
+) -> String {
+    let fun = if functional { "fun " } else { "" };
+
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    {}
+
+    {comment}
+    {fun}interface {class_name} {{
+        {methods}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn interface_impl_class(
+    namespace: &str,
+    interface_name: &str,
+    methods: String,
+    native_methods: String,
+    mut additional_imports: HashSet<String>,
+) -> String {
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClass".to_string());
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClassImpl".to_string());
+    let class_name = format!("{interface_name}Impl");
+
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    {}
+
+    /**
+     * A native implementation of [{interface_name}].
+     * Instances of this class are created by native code only.
+     */
+    class {class_name} private constructor(ptr: Long) : {interface_name}, NativeClassImpl<{class_name}.{class_name}Native>, AutoCloseable {{
+        private val inner = {class_name}Native(ptr, this)
+
+        {methods}
+
+        override fun getInner(): {class_name}Native = inner
+
+        {CLOSE}
+
+        class {class_name}Native internal constructor(ptr: Long, referent: Any) : NativeClass(ptr, referent) {{
+            {native_methods}
+
+            {DESTRUCT}
+
+            companion object {{
+                @JvmStatic
+                external fun drop(ptr: Long)
+            }}
+        }}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn util_class(
+    namespace: &str,
+    class_name: &str,
+    methods: String,
+    native_methods: String,
+    load_lib: Option<String>,
+    additional_imports: HashSet<String>,
+) -> String {
+    let init_lib = load_lib.map(|lib| load_library(&lib)).unwrap_or_default();
+
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    {}
+
+    object {class_name} {{
+        {methods}
+
+        object {class_name}Native {{
+            {init_lib}
+
+            {native_methods}
+        }}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn enum_class(namespace: &str, class_name: &str, comment: String, variants: String) -> String {
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    {comment}
+    enum class {class_name} {{
+        {variants}
+    }}
+    "#
+    ))
+}
+
+pub fn exception_class(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    fields: Vec<(String, String)>,
+    additional_imports: HashSet<String>,
+) -> String {
+    let fields = fields
+        .iter()
+        .map(|(ty, name)| format!("val {name}: {ty},"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    import com.github.markusjx.jnibindgen.NativeExecutionException
+    {}
+
+    {comment}
+    class {class_name}(
+        message: String,
+        {fields}
+    ) : NativeExecutionException(message)
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn data_class(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    fields: Vec<(String, String)>,
+    additional_imports: HashSet<String>,
+) -> String {
+    // Data classes require at least one property
+    let class = if fields.is_empty() {
+        format!("class {class_name}")
+    } else {
+        let fields = fields
+            .iter()
+            .map(|(ty, name)| format!("val {name}: {ty},"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!("data class {class_name}(\n{fields}\n)")
+    };
+
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    {}
+
+    {comment}
+    {class}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn class(
+    namespace: &str,
+    class_name: &str,
+    comment: String,
+    methods: String,
+    static_methods: String,
+    constructors: String,
+    inner: String,
+    mut additional_imports: HashSet<String>,
+) -> String {
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClass".to_string());
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativeClassImpl".to_string());
+    additional_imports.insert("com.github.markusjx.jnibindgen.NativePointer".to_string());
+
+    format_code(format!(
+        r#"
+    package {namespace}
+
+    {}
+
+    {comment}
+    class {class_name} : NativeClassImpl<{class_name}.{class_name}Native>, AutoCloseable {{
+        private val inner: {class_name}Native
+
+        {constructors}
+
+        /**
+         * Wrap an existing native object.
+         * This is called by native code only.
+         */
+        private constructor(ptr: NativePointer) {{
+            inner = {class_name}Native(ptr, this)
+        }}
+
+        {methods}
+
+        override fun getInner(): {class_name}Native = inner
+
+        {CLOSE}
+
+        companion object {{
+            /**
+             * Get the type of the underlying native class
+             * in a hashed form. This is used to check if
+             * the native class is the same as the one
+             * that is expected.
+             *
+             * @return The type hash of this class
+             */
+            @JvmStatic
+            fun getTypeHash(): Long = {class_name}Native.getTypeHash()
+
+            {static_methods}
+        }}
+
+        {inner}
+    }}
+    "#,
+        imports(namespace, additional_imports),
+    ))
+}
+
+pub fn inner_class(
+    class_name: &str,
+    methods: String,
+    static_methods: String,
+    constructors: String,
+    load_lib: Option<String>,
+) -> String {
+    let init_lib = load_lib.map(|lib| load_library(&lib)).unwrap_or_default();
+
+    format!(
+        r#"
+        class {class_name}Native : NativeClass {{
+            {constructors}
+
+            internal constructor(ptr: NativePointer, referent: Any) : super(ptr.ptr, referent)
+
+            {methods}
+
+            {DESTRUCT}
+
+            companion object {{
+                {init_lib}
+
+                {static_methods}
+            }}
+        }}
+    "#
+    )
+}
+
+/// Generate a `suspend` function awaiting the
+/// future returned by the given function call.
+pub fn suspend_fun(signature: &str, call: &str, void: bool) -> String {
+    let value = if void { "Unit" } else { "result" };
+
+    format!(
+        r#"{signature} = suspendCoroutine {{ continuation ->
+            {call}.whenComplete {{ result, error ->
+                if (error != null) {{
+                    continuation.resumeWithException(error)
+                }} else {{
+                    continuation.resume({value})
+                }}
+            }}
+        }}"#
+    )
+}
+
+/// The imports required by [`suspend_fun`].
+pub fn suspend_imports() -> HashSet<String> {
+    [
+        "kotlin.coroutines.resume",
+        "kotlin.coroutines.resumeWithException",
+        "kotlin.coroutines.suspendCoroutine",
+    ]
+    .iter()
+    .map(|i| i.to_string())
+    .collect()
+}
+
+fn load_library(lib_name: &str) -> String {
+    format!(
+        r#"init {{
+            System.loadLibrary("{lib_name}")
+        }}"#
+    )
+}
+
+/// Indent the code by the depth of its braces and parentheses,
+/// removing empty lines at the start and end of every block
+/// and after doc comments.
+fn format_code(code: String) -> String {
+    let lines = code.lines().map(str::trim).collect::<Vec<_>>();
+    let mut res = Vec::<String>::new();
+    let mut indent = 0usize;
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.is_empty() {
+            let next = lines[i + 1..].iter().find(|l| !l.is_empty());
+            let last = res.last().map(|l| l.trim_end());
+            if last.is_none_or(|l| {
+                l.is_empty() || l.ends_with(['{', '(']) || l.ends_with("->") || l.ends_with("*/")
+            }) || next.is_none_or(|l| l.starts_with(['}', ')']))
+            {
+                continue;
+            }
+
+            res.push(String::new());
+            continue;
+        }
+
+        let is_comment = line.starts_with('*') || line.starts_with("/*") || line.starts_with("//");
+        let (opened, closed) = if is_comment {
+            (0, 0)
+        } else {
+            (
+                line.matches(['{', '(']).count(),
+                line.matches(['}', ')']).count(),
+            )
+        };
+
+        let leading = if is_comment {
+            0
+        } else {
+            line.chars().take_while(|c| matches!(c, '}' | ')')).count()
+        };
+
+        // Property accessors are indented below their property
+        let accessor = usize::from(line.starts_with("get()") || line.starts_with("set("));
+        let line_indent = indent.saturating_sub(leading) + accessor;
+        let extra = if line.starts_with('*') { " " } else { "" };
+        res.push(format!("{}{extra}{line}", " ".repeat(line_indent * 4)));
+        indent = (indent + opened).saturating_sub(closed);
+    }
+
+    res.join("\n") + "\n"
+}
+
+/// Generate the import statements, sorted in order
+/// to keep the generated code stable between builds.
+/// Classes of the package `namespace` are not imported.
+fn imports(namespace: &str, imports: HashSet<String>) -> String {
+    let mut imports = imports
+        .into_iter()
+        .filter(|i| {
+            i.rsplit_once('.')
+                .is_some_and(|(package, _)| package != namespace)
+        })
+        .collect::<Vec<_>>();
+    imports.sort();
+
+    imports
+        .into_iter()
+        .map(|i| format!("import {i}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,13 @@
+pub mod code;
+pub mod java_class;
+pub mod java_data_class;
+pub mod java_enum;
+pub mod java_error;
+pub mod java_field;
+pub mod java_interface;
+pub mod java_method;
+pub mod java_type;
+pub mod java_util_class;
+pub mod kotlin;
+pub mod signature;
+pub mod traits;
@@ -0,0 +1,41 @@
+/// A method of a java class the generated rust code depends on.
+/// These are used to check that the compiled java classes
+/// match the code generated by the macros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JniSignature {
+    /// The binary name of the class declaring the method,
+    /// e.g. `com/example/Foo$FooNative`.
+    pub class_name: String,
+    pub method_name: String,
+    /// The JNI descriptor of the method, e.g. `(I)Ljava/lang/String;`.
+    pub descriptor: String,
+    pub kind: SignatureKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureKind {
+    /// A native method implemented by the exported function `symbol`.
+    Native { symbol: String, static_method: bool },
+    /// A method of a java interface called by the generated rust code.
+    Interface,
+}
+
+/// Get the name of the function the JVM looks up for the
+/// native method `method_name` of the class `class_name`,
+/// as described in the JNI specification.
+pub fn jni_symbol(class_name: &str, method_name: &str) -> String {
+    format!("Java_{}_{}", mangle(class_name), mangle(method_name))
+}
+
+fn mangle(name: &str) -> String {
+    name.encode_utf16()
+        .map(|c| match char::from_u32(c as u32) {
+            Some('/') => "_".to_string(),
+            Some('_') => "_1".to_string(),
+            Some(';') => "_2".to_string(),
+            Some('[') => "_3".to_string(),
+            Some(c) if c.is_ascii_alphanumeric() => c.to_string(),
+            _ => format!("_0{c:04x}"),
+        })
+        .collect()
+}
@@ -0,0 +1,6 @@
+//! The code generation used by `jni-bindgen-macros` in order to generate
+//! the JNI methods and by `jni-bindgen-build` in order to generate the
+//! java classes from a build script.
+
+pub mod codegen;
+pub mod util;
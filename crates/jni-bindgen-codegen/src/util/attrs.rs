@@ -1,3 +1,4 @@
+use crate::util::language::{default_language, Language};
 use proc_macro2::{Ident, Span};
 use std::cell::Cell;
 use syn::parse::{Parse, ParseStream, Result as SynResult};
@@ -43,12 +44,102 @@ impl BindgenAttrs {
         })
     }
 
+    pub fn get_class(&self) -> Option<String> {
+        self.get_attr(|arg| match &arg.1 {
+            BindgenAttr::Class(_, name, _) => Some(name.clone()),
+            _ => None,
+        })
+    }
+
     pub fn is_constructor(&self) -> bool {
         self.attrs
             .iter()
             .any(|arg| matches!(&arg.1, BindgenAttr::Constructor(_)))
     }
 
+    pub fn is_data(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::Data(_)))
+    }
+
+    pub fn get_exception(&self) -> Option<String> {
+        self.get_attr(|arg| match &arg.1 {
+            BindgenAttr::Exception(_, name, _) => Some(name.clone()),
+            _ => None,
+        })
+    }
+
+    pub fn is_generate(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::Generate(_)))
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::Error(_)))
+    }
+
+    /// Whether struct arguments of this class are converted
+    /// without checking the class of the java object.
+    pub fn is_unchecked(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::Unchecked(_)))
+    }
+
+    /// Whether the function is called once the library is loaded.
+    pub fn is_on_load(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::OnLoad(_)))
+    }
+
+    /// Whether the function is called once the library is unloaded.
+    pub fn is_on_unload(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::OnUnload(_)))
+    }
+
+    /// Whether a getter is generated for the struct field.
+    pub fn is_getter(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::Getter(_)))
+    }
+
+    /// Whether a setter is generated for the struct field.
+    pub fn is_setter(&self) -> bool {
+        self.attrs
+            .iter()
+            .any(|arg| matches!(&arg.1, BindgenAttr::Setter(_)))
+    }
+
+    /// Get the language of the generated class, if set explicitly.
+    pub fn get_language(&self) -> Option<String> {
+        self.get_attr(|arg| match &arg.1 {
+            BindgenAttr::Language(_, name, _) => Some(name.clone()),
+            _ => None,
+        })
+    }
+
+    /// Get the language of the generated class.
+    pub fn language(&self) -> syn::Result<Language> {
+        match self.attrs.iter().find_map(|arg| match &arg.1 {
+            BindgenAttr::Language(_, name, span) => Some((name, *span)),
+            _ => None,
+        }) {
+            Some((name, span)) => Language::from_name(name).ok_or(syn::Error::new(
+                span,
+                "Unsupported language, expected \"java\" or \"kotlin\"",
+            )),
+            None => Ok(default_language()),
+        }
+    }
+
     pub fn load_lib(&self) -> Option<String> {
         self.get_attr(|arg| match &arg.1 {
             BindgenAttr::LoadLib(_, name, _) => Some(name.clone()),
@@ -103,6 +194,17 @@ macro_rules! attrgen {
             (constructor, Constructor(Span)),
             (load_lib, LoadLib(Span, String, Span)),
             (class_name, ClassName(Span, String, Span)),
+            (data, Data(Span)),
+            (class, Class(Span, String, Span)),
+            (exception, Exception(Span, String, Span)),
+            (generate, Generate(Span)),
+            (error, Error(Span)),
+            (language, Language(Span, String, Span)),
+            (unchecked, Unchecked(Span)),
+            (on_load, OnLoad(Span)),
+            (on_unload, OnUnload(Span)),
+            (getter, Getter(Span)),
+            (setter, Setter(Span)),
         }
     };
 }
@@ -0,0 +1,172 @@
+use crate::codegen::java_error::JavaError;
+use crate::codegen::java_field::JavaFields;
+use crate::codegen::java_method::check_overloads;
+use crate::codegen::java_util_class::JavaUtilClass;
+use crate::util::attrs::BindgenAttrs;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use syn::{DeriveInput, Ident, Item, ItemFn, ItemStruct, Type};
+
+thread_local! {
+    /// The context set by the build script generator.
+    static CONTEXT: RefCell<Option<Rc<Context>>> = const { RefCell::new(None) };
+}
+
+/// The items of a crate the generated classes of other items depend on.
+/// The context is created by the build script generator from all items
+/// of the crate before any class is generated, so the generated classes
+/// do not depend on the order in which the items are expanded.
+#[derive(Default)]
+pub struct Context {
+    /// The exception classes of every error type by the name of the type.
+    exceptions: HashMap<String, Vec<String>>,
+    /// The free functions of every utility class by the
+    /// fully qualified name of the class, in declaration order.
+    functions: HashMap<String, Vec<(ItemFn, BindgenAttrs)>>,
+    /// The structs with field accessors by the fully qualified
+    /// name of the class generated for their `impl` block.
+    fields: HashMap<String, (ItemStruct, BindgenAttrs)>,
+    /// The fully qualified names of the classes generated for
+    /// enums, data classes, structs and traits by their name.
+    class_names: HashMap<String, String>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item annotated with `#[jni]`.
+    pub fn add_item(&mut self, item: &Item, args: &BindgenAttrs) -> syn::Result<()> {
+        let name = match item {
+            Item::Enum(enum_) => Some(&enum_.ident),
+            Item::Struct(struct_) => Some(&struct_.ident),
+            Item::Trait(trait_) => Some(&trait_.ident),
+            Item::Impl(impl_) => match impl_.self_ty.as_ref() {
+                Type::Path(path) => path.path.segments.last().map(|s| &s.ident),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(name) = name {
+            let namespace = args.get_namespace()?;
+            insert_unique(&mut self.class_names, name, format!("{namespace}.{name}"))?;
+        }
+
+        match item {
+            Item::Fn(func) => {
+                if let Some(class) = args.get_class() {
+                    let namespace = args.get_namespace()?;
+                    self.functions
+                        .entry(format!("{namespace}.{class}"))
+                        .or_default()
+                        .push((func.clone(), args.clone()));
+                }
+            }
+            Item::Struct(struct_) if args.is_error() => {
+                let namespace = args.get_namespace()?;
+                insert_unique(
+                    &mut self.exceptions,
+                    &struct_.ident,
+                    vec![format!("{namespace}.{}", struct_.ident)],
+                )?;
+            }
+            Item::Struct(struct_) if !args.is_data() => {
+                let namespace = args.get_namespace()?;
+                self.fields.insert(
+                    format!("{namespace}.{}", struct_.ident),
+                    (struct_.clone(), args.clone()),
+                );
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Add an error enum deriving `JniError`.
+    pub fn add_error(&mut self, input: &DeriveInput) -> syn::Result<()> {
+        let java_error = JavaError::from_declaration(input)?;
+        insert_unique(&mut self.exceptions, &input.ident, java_error.exceptions())
+    }
+
+    /// Get the exception classes the error type with the given name is
+    /// converted into. Returns `None` if no error type with this name
+    /// derives `JniError` or is a struct with an `error` attribute.
+    pub fn exceptions(&self, name: &str) -> Option<Vec<String>> {
+        self.exceptions.get(name).cloned()
+    }
+
+    /// Get the fully qualified name of the class generated for the
+    /// enum, data class, struct or trait with the given name.
+    /// Returns `None` if no such item is annotated with `#[jni]`.
+    pub fn class_name(&self, name: &str) -> Option<&str> {
+        self.class_names.get(name).map(String::as_str)
+    }
+
+    /// Get the field accessors of the struct with the given name.
+    /// Returns `None` if the struct is not annotated with `#[jni]`.
+    pub fn fields(&self, namespace: &str, name: &str) -> syn::Result<Option<JavaFields>> {
+        self.fields
+            .get(&format!("{namespace}.{name}"))
+            .map(|(decl, args)| JavaFields::from_declaration(decl, args))
+            .transpose()
+    }
+
+    /// Get all functions of the utility class with the given name.
+    pub fn functions(&self, namespace: &str, class: &str) -> syn::Result<Vec<JavaUtilClass>> {
+        let functions = self
+            .functions
+            .get(&format!("{namespace}.{class}"))
+            .into_iter()
+            .flatten()
+            .map(|(func, args)| JavaUtilClass::from_declaration(func, args))
+            .collect::<syn::Result<Vec<_>>>()?;
+        check_overloads(&[], functions.iter().map(|f| &f.method))?;
+
+        Ok(functions)
+    }
+}
+
+/// Insert the value for a type, failing if another type with the same name
+/// has been inserted with a different value. Types are looked up by their
+/// name only, as the paths used to reference them are not resolved.
+fn insert_unique<T: PartialEq>(
+    map: &mut HashMap<String, T>,
+    name: &Ident,
+    value: T,
+) -> syn::Result<()> {
+    match map.get(&name.to_string()) {
+        Some(existing) if *existing != value => Err(syn::Error::new(
+            name.span(),
+            format!("Another type named '{name}' generates different classes, the names of types annotated with #[jni] or deriving JniError must be unique within a crate"),
+        )),
+        _ => {
+            map.insert(name.to_string(), value);
+            Ok(())
+        }
+    }
+}
+
+/// Get the context set using [`with_context`].
+/// Returns `None` when running inside the proc macro.
+pub fn context() -> Option<Rc<Context>> {
+    CONTEXT.with(|ctx| ctx.borrow().clone())
+}
+
+/// Whether the classes are generated by the build script generator.
+/// This is the case if a context is set or, when running inside the
+/// proc macro, the build script has set `JNI_BINDGEN_BUILD`.
+pub fn is_build() -> bool {
+    context().is_some() || std::env::var("JNI_BINDGEN_BUILD").is_ok_and(|v| v == "1")
+}
+
+/// Run the given closure with the context set to `context`.
+pub fn with_context<R>(context: Context, f: impl FnOnce() -> R) -> R {
+    let prev = CONTEXT.with(|ctx| ctx.replace(Some(Rc::new(context))));
+    let res = f();
+    CONTEXT.with(|ctx| *ctx.borrow_mut() = prev);
+
+    res
+}
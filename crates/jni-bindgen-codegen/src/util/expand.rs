@@ -0,0 +1,376 @@
+use crate::codegen::java_class::JavaClass;
+use crate::codegen::java_data_class::JavaDataClass;
+use crate::codegen::java_enum::JavaEnum;
+use crate::codegen::java_error::JavaError;
+use crate::codegen::java_field::JavaFields;
+use crate::codegen::java_interface::JavaInterface;
+use crate::codegen::java_util_class::JavaUtilClass;
+use crate::codegen::signature::JniSignature;
+use crate::util::attrs::BindgenAttrs;
+use crate::util::context::{context, is_build};
+use crate::util::language::Language;
+use crate::util::manifest::Manifest;
+use crate::util::out_dir::out_dir;
+use crate::util::quotes;
+use crate::util::traits::AnyAttribute;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use std::path::Path;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, FnArg, Item};
+
+/// A generated java or Kotlin class.
+pub struct JavaFile {
+    pub namespace: String,
+    pub class_name: String,
+    pub language: Language,
+    pub code: String,
+}
+
+impl JavaFile {
+    fn new(namespace: &str, class_name: &str, language: Language, code: String) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            class_name: class_name.to_string(),
+            language,
+            code,
+        }
+    }
+
+    /// The path of the class relative to the output directory.
+    pub fn path(&self) -> std::path::PathBuf {
+        Path::new(&self.namespace.replace('.', "/")).join(format!(
+            "{}.{}",
+            self.class_name,
+            self.language.extension()
+        ))
+    }
+
+    /// Write the class into the given output directory.
+    /// The file is only written if its contents changed.
+    pub fn write(&self, out_dir: &Path) -> std::io::Result<()> {
+        let java_file = out_dir.join(self.path());
+        if std::fs::read_to_string(&java_file).is_ok_and(|code| code == self.code) {
+            return Ok(());
+        }
+
+        std::fs::create_dir_all(java_file.parent().unwrap())?;
+        std::fs::write(java_file, &self.code)
+    }
+}
+
+/// An item annotated with `#[jni]` with the generated
+/// rust code and java classes.
+pub struct Expanded {
+    /// The item, with all `#[jni]` attributes of its fields removed.
+    pub item: Item,
+    /// The generated JNI methods.
+    pub code: Option<TokenStream>,
+    pub java_files: Vec<JavaFile>,
+}
+
+fn is_debug() -> bool {
+    std::env::var("DEBUG_JNI_BINDGEN").is_ok_and(|d| d == "true")
+}
+
+/// Write the classes into the output directory and add them to its
+/// manifest, so `cargo jni-bindgen clean` can delete them. Stale
+/// classes are never deleted here, as each expansion only knows about
+/// the classes it generates and unchanged items are not expanded again.
+/// Only `jni_bindgen_build::Builder`, which generates all classes at
+/// once, deletes the classes of removed or renamed items.
+fn write_classes(java_files: &[JavaFile]) -> syn::Result<()> {
+    let Some(java_dir) = out_dir() else {
+        return Ok(());
+    };
+
+    let java_dir = Path::new(&java_dir);
+    let error = |e: std::io::Error| {
+        syn::Error::new(
+            Span::call_site(),
+            format!(
+                "Failed to write the java classes to '{}': {e}",
+                java_dir.display()
+            ),
+        )
+    };
+
+    let _lock = Manifest::lock(java_dir).map_err(error)?;
+    let mut manifest = Manifest::read(java_dir).map_err(error)?;
+    for java_file in java_files {
+        java_file.write(java_dir).map_err(error)?;
+        manifest.add(java_file.path());
+    }
+
+    manifest.write().map_err(error)
+}
+
+/// Generate the JNI methods and java classes for an item annotated with `#[jni]`.
+/// Functions without a `class` attribute only have their arguments' `#[jni]`
+/// attributes removed. The java class of functions with a `class` attribute
+/// is generated from all functions of the class, which are only known to the
+/// build script generator. Thus, the proc macro only generates their JNI
+/// methods and fails if the classes are not generated by the build script.
+pub fn expand_item(item: Item, args: &BindgenAttrs) -> syn::Result<Expanded> {
+    let language = args.language()?;
+    let kotlin = language == Language::Kotlin;
+    let mut java_files = Vec::new();
+    let (item, code) = match item {
+        Item::Impl(impl_) => {
+            let java_class = JavaClass::from_declaration(&impl_, args)?;
+
+            let res = java_class.as_jni_methods(args)?;
+            java_files.push(JavaFile::new(
+                &java_class.namespace,
+                &java_class.name,
+                language,
+                if kotlin {
+                    java_class.as_kotlin_declaration()
+                } else {
+                    java_class.as_declaration()
+                },
+            ));
+
+            (Item::Impl(impl_), Some(res))
+        }
+        Item::Trait(tr) => {
+            let interface = JavaInterface::from_declaration(&tr, args)?;
+            let res = interface.as_jni_methods()?;
+            java_files.push(JavaFile::new(
+                &interface.namespace,
+                &interface.name,
+                language,
+                if kotlin {
+                    interface.as_kotlin_declaration()
+                } else {
+                    interface.as_java_declaration()
+                },
+            ));
+            java_files.push(JavaFile::new(
+                &interface.namespace,
+                &format!("{}Impl", interface.name),
+                language,
+                if kotlin {
+                    interface.as_kotlin_impl_declaration()
+                } else {
+                    interface.as_java_impl_declaration()
+                },
+            ));
+
+            (Item::Trait(tr), Some(res))
+        }
+        Item::Struct(struct_) if args.is_data() || args.is_error() => {
+            let data_class = JavaDataClass::from_declaration(&struct_, args)?;
+            let res = data_class.as_jni_methods()?;
+
+            java_files.push(JavaFile::new(
+                &data_class.namespace,
+                &data_class.name,
+                language,
+                if kotlin {
+                    data_class.as_kotlin_declaration()
+                } else {
+                    data_class.as_java_declaration()
+                },
+            ));
+
+            (Item::Struct(struct_), Some(res))
+        }
+        Item::Struct(mut struct_) => {
+            let fields = JavaFields::from_declaration(&struct_, args)?;
+            if !fields.is_empty() && !is_build() {
+                return Err(syn::Error::new(
+                    struct_.ident.span(),
+                    "Field accessors require the classes to be generated by jni_bindgen_build::Builder",
+                ));
+            }
+
+            let res = fields.as_jni_methods()?;
+
+            for field in struct_.fields.iter_mut() {
+                field.attrs.retain(|a| !a.is_jni());
+            }
+
+            (Item::Struct(struct_), Some(res))
+        }
+        Item::Enum(mut enum_) => {
+            let java_enum = JavaEnum::from_declaration(&enum_, args)?;
+            let res = java_enum.as_jni_methods()?;
+            java_files.push(JavaFile::new(
+                &java_enum.namespace,
+                &java_enum.name,
+                language,
+                if kotlin {
+                    java_enum.as_kotlin_declaration()
+                } else {
+                    java_enum.as_java_declaration()
+                },
+            ));
+
+            for variant in enum_.variants.iter_mut() {
+                variant.attrs.retain(|a| !a.is_jni());
+            }
+
+            (Item::Enum(enum_), Some(res))
+        }
+        Item::Fn(func) if args.is_on_load() || args.is_on_unload() => {
+            if args.get_class().is_some() || (args.is_on_load() && args.is_on_unload()) {
+                return Err(syn::Error::new(
+                    func.sig.span(),
+                    "on_load and on_unload functions cannot be exported to java or combined",
+                ));
+            } else if func.sig.asyncness.is_some() {
+                return Err(syn::Error::new(
+                    func.sig.asyncness.span(),
+                    "on_load and on_unload functions cannot be async",
+                ));
+            }
+
+            let code = if args.is_on_load() {
+                quotes::on_load(&func.sig.ident)
+            } else {
+                quotes::on_unload(&func.sig.ident)
+            };
+
+            (Item::Fn(func), Some(code))
+        }
+        Item::Fn(mut func) => {
+            let util_class = if args.get_class().is_some() {
+                Some(JavaUtilClass::from_declaration(&func, args)?)
+            } else {
+                None
+            };
+
+            func.sig.inputs = func
+                .sig
+                .inputs
+                .into_iter()
+                .map(|i| match i {
+                    FnArg::Typed(mut typed) => {
+                        typed.attrs.retain(|a| !a.is_jni());
+                        FnArg::Typed(typed)
+                    }
+                    rest => rest,
+                })
+                .collect();
+
+            let code = match util_class {
+                Some(util_class) => {
+                    let res = util_class.as_jni_methods()?;
+                    match context() {
+                        Some(context) => java_files.push(JavaFile::new(
+                            &util_class.namespace,
+                            &util_class.name,
+                            language,
+                            if kotlin {
+                                util_class.as_kotlin_declaration(&context)?
+                            } else {
+                                util_class.as_java_declaration(&context)?
+                            },
+                        )),
+                        // The class is generated by the build script
+                        None if is_build() => {}
+                        None => {
+                            return Err(syn::Error::new(
+                                func.sig.span(),
+                                "Functions exported to a java class require the classes to be generated by jni_bindgen_build::Builder",
+                            ))
+                        }
+                    }
+
+                    Some(res)
+                }
+                None => None,
+            };
+
+            (Item::Fn(func), code)
+        }
+        Item::Verbatim(v) => (Item::Verbatim(v), None),
+        item => {
+            return Err(syn::Error::new(
+                item.span(),
+                "Only impl blocks, traits, enums, structs and functions are supported",
+            ))
+        }
+    };
+
+    Ok(Expanded {
+        item,
+        code,
+        java_files,
+    })
+}
+
+/// Get the signatures of all java methods the code generated
+/// for an item annotated with `#[jni]` depends on.
+pub fn signatures(item: &Item, args: &BindgenAttrs) -> syn::Result<Vec<JniSignature>> {
+    Ok(match item {
+        Item::Impl(impl_) => JavaClass::from_declaration(impl_, args)?.signatures(),
+        Item::Trait(tr) => JavaInterface::from_declaration(tr, args)?.signatures(),
+        Item::Fn(func) if args.get_class().is_some() => {
+            vec![JavaUtilClass::from_declaration(func, args)?.signature()]
+        }
+        Item::Struct(struct_) if !args.is_data() && !args.is_error() => {
+            JavaFields::from_declaration(struct_, args)?.signatures()
+        }
+        _ => vec![],
+    })
+}
+
+/// Expand the `#[jni]` attribute macro.
+pub fn expand(args: TokenStream, input: TokenStream) -> syn::Result<TokenStream> {
+    let item = syn::parse2::<Item>(input)?;
+    let args: BindgenAttrs = syn::parse2(args)?;
+
+    let Expanded {
+        item,
+        code,
+        java_files,
+    } = expand_item(item, &args)?;
+    if is_debug() {
+        for java_file in &java_files {
+            println!("{}\n", java_file.code);
+        }
+
+        if let Some(code) = &code {
+            println!("{code}");
+        }
+    }
+
+    write_classes(&java_files)?;
+    Ok(quote!(
+        #item
+
+        #code
+    ))
+}
+
+/// Generate the conversion into a `JNIError` and the
+/// exception classes for an enum deriving `JniError`.
+pub fn expand_error_item(input: &DeriveInput) -> syn::Result<(TokenStream, Vec<JavaFile>)> {
+    let java_error = JavaError::from_declaration(input)?;
+
+    let java_files = java_error
+        .as_declarations()
+        .into_iter()
+        .map(|(namespace, class_name, code)| {
+            JavaFile::new(&namespace, &class_name, java_error.language, code)
+        })
+        .collect();
+
+    Ok((java_error.as_jni_methods(), java_files))
+}
+
+/// Expand the `JniError` derive macro.
+pub fn expand_error(input: TokenStream) -> syn::Result<TokenStream> {
+    let input = syn::parse2::<DeriveInput>(input)?;
+    let (res, java_files) = expand_error_item(&input)?;
+    if is_debug() {
+        for java_file in &java_files {
+            println!("{}", java_file.code);
+        }
+    }
+
+    write_classes(&java_files)?;
+    Ok(res)
+}
@@ -0,0 +1,55 @@
+use std::cell::Cell;
+
+/// The language of the generated classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Java,
+    Kotlin,
+}
+
+thread_local! {
+    /// The language set by the build script generator.
+    static LANGUAGE: Cell<Option<Language>> = const { Cell::new(None) };
+}
+
+impl Language {
+    /// Parse the name of a language, ignoring its case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "java" => Some(Language::Java),
+            "kotlin" => Some(Language::Kotlin),
+            _ => None,
+        }
+    }
+
+    /// The file extension of source files of this language.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Language::Java => "java",
+            Language::Kotlin => "kt",
+        }
+    }
+}
+
+/// Get the language of classes without a `language` attribute.
+/// This is the language set using [`with_language`] or the
+/// `JNI_BINDGEN_LANGUAGE` environment variable, defaulting to java.
+pub fn default_language() -> Language {
+    LANGUAGE
+        .with(Cell::get)
+        .or_else(|| {
+            std::env::var("JNI_BINDGEN_LANGUAGE")
+                .ok()
+                .and_then(|name| Language::from_name(&name))
+        })
+        .unwrap_or(Language::Java)
+}
+
+/// Run the given closure with the default language set to `language`.
+pub fn with_language<R>(language: Language, f: impl FnOnce() -> R) -> R {
+    let prev = LANGUAGE.with(|l| l.replace(Some(language)));
+    let res = f();
+    LANGUAGE.with(|l| l.set(prev));
+
+    res
+}
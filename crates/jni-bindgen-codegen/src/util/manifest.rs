@@ -0,0 +1,147 @@
+use indexmap::IndexSet;
+use std::fs::File;
+use std::path::{Component, Path, PathBuf};
+
+/// The java classes generated into an output directory.
+/// The manifest is stored in `.jni-bindgen/manifest` inside of the
+/// output directory, listing the path of every generated class
+/// relative to the output directory, one per line.
+pub struct Manifest {
+    out_dir: PathBuf,
+    files: IndexSet<PathBuf>,
+}
+
+impl Manifest {
+    /// Create an empty manifest for the given output directory.
+    pub fn new(out_dir: &Path) -> Self {
+        Self {
+            out_dir: out_dir.to_path_buf(),
+            files: IndexSet::new(),
+        }
+    }
+
+    /// Read the manifest stored in the given output directory.
+    /// Returns an empty manifest if no manifest has been stored yet.
+    pub fn read(out_dir: &Path) -> std::io::Result<Self> {
+        let mut manifest = Self::new(out_dir);
+        let contents = match std::fs::read_to_string(Self::path(out_dir)) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(manifest),
+            Err(e) => return Err(e),
+        };
+
+        // Never touch files outside of the output directory,
+        // even if the manifest has been modified
+        manifest.files = contents
+            .lines()
+            .map(PathBuf::from)
+            .filter(|file| file.components().all(|c| matches!(c, Component::Normal(_))))
+            .collect();
+
+        Ok(manifest)
+    }
+
+    fn path(out_dir: &Path) -> PathBuf {
+        out_dir.join(".jni-bindgen").join("manifest")
+    }
+
+    /// Lock the manifest stored in the given output directory, waiting
+    /// until no other process, e.g. a macro expanded in parallel, holds
+    /// the lock. The lock is released once the returned file is dropped.
+    /// Hold the lock while reading and writing the manifest, otherwise
+    /// the changes of another process may be lost.
+    pub fn lock(out_dir: &Path) -> std::io::Result<File> {
+        let path = out_dir.join(".jni-bindgen").join("manifest.lock");
+        std::fs::create_dir_all(path.parent().unwrap())?;
+
+        let file = File::options()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)?;
+        file.lock()?;
+        Ok(file)
+    }
+
+    /// Add a generated file, relative to the output directory.
+    pub fn add(&mut self, file: PathBuf) {
+        self.files.insert(file);
+    }
+
+    /// The generated files, relative to the output directory.
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(PathBuf::as_path)
+    }
+
+    /// Store the manifest in the output directory.
+    pub fn write(&self) -> std::io::Result<()> {
+        let path = Self::path(&self.out_dir);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+
+        let files = self
+            .files
+            .iter()
+            .map(|file| file.to_string_lossy().replace('\\', "/"))
+            .collect::<Vec<_>>();
+        std::fs::write(path, files.join("\n"))
+    }
+
+    /// Delete all files listed in the `previous` manifest
+    /// which are not listed in this manifest.
+    ///
+    /// # Returns
+    /// The deleted files, relative to the output directory.
+    pub fn remove_stale(&self, previous: &Manifest) -> std::io::Result<Vec<PathBuf>> {
+        let stale = previous
+            .files
+            .iter()
+            .filter(|file| !self.files.contains(*file))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for file in &stale {
+            self.remove_file(file)?;
+        }
+
+        Ok(stale)
+    }
+
+    /// Delete all files listed in this manifest and
+    /// all data stored in the `.jni-bindgen` directory.
+    ///
+    /// # Returns
+    /// The deleted files, relative to the output directory.
+    pub fn clean(self) -> std::io::Result<Vec<PathBuf>> {
+        for file in &self.files {
+            self.remove_file(file)?;
+        }
+
+        let stored = self.out_dir.join(".jni-bindgen");
+        if stored.exists() {
+            std::fs::remove_dir_all(stored)?;
+        }
+
+        Ok(self.files.into_iter().collect())
+    }
+
+    /// Delete a generated file and all directories
+    /// inside of the output directory it leaves empty.
+    fn remove_file(&self, file: &Path) -> std::io::Result<()> {
+        match std::fs::remove_file(self.out_dir.join(file)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+
+        let mut dir = file.parent();
+        while let Some(parent) = dir.filter(|d| !d.as_os_str().is_empty()) {
+            // Fails if the directory is not empty
+            if std::fs::remove_dir(self.out_dir.join(parent)).is_err() {
+                break;
+            }
+
+            dir = parent.parent();
+        }
+
+        Ok(())
+    }
+}
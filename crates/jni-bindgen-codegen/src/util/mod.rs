@@ -1,5 +1,9 @@
 pub mod attrs;
+pub mod context;
 pub mod expand;
+pub mod language;
+pub mod manifest;
 pub mod method;
+pub mod out_dir;
 pub mod quotes;
 pub mod traits;
@@ -0,0 +1,11 @@
+use crate::util::context::is_build;
+
+/// Get the directory the java classes are written to while
+/// expanding the macros. This is the `JNI_BINDGEN_OUT_DIR`
+/// environment variable. If the classes are generated by the
+/// build script, the macros do not write any classes.
+pub fn out_dir() -> Option<String> {
+    std::env::var("JNI_BINDGEN_OUT_DIR")
+        .ok()
+        .filter(|dir| !dir.is_empty() && !is_build())
+}
@@ -0,0 +1,301 @@
+use crate::codegen::java_type::DescriptorPart;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+
+pub fn this(struct_name: &TokenStream, ret_val: &TokenStream, is_mut: bool) -> TokenStream {
+    let as_ref = if is_mut {
+        quote!(ptr_as_mut)
+    } else {
+        quote!(ptr_as_ref)
+    };
+
+    get_this(struct_name, ret_val, as_ref)
+}
+
+/// Get `this` as an `Arc`, so it can be moved into
+/// the future returned by an `async` method.
+pub fn this_arc(struct_name: &TokenStream, ret_val: &TokenStream) -> TokenStream {
+    get_this(struct_name, ret_val, quote!(ptr_as_arc))
+}
+
+fn get_this(struct_name: &TokenStream, ret_val: &TokenStream, as_ref: TokenStream) -> TokenStream {
+    quote!(
+        let this = match env.get_field(object, "ptr", "J")
+            .and_then(|e| e.j())
+            .into_jni_result()
+            .and_then(|ptr| unsafe {
+                jni_bindgen::conversion::class_convert::#as_ref::<#struct_name>(ptr)
+            }) {
+                Ok(this) => this,
+                Err(e) => {
+                    e.throw(&mut env);
+                    return #ret_val;
+                }
+            };
+    )
+}
+
+pub fn base_name(namespace: &str, struct_name: &str) -> String {
+    [
+        "Java".to_string(),
+        namespace.to_string(),
+        struct_name.to_string(),
+        format!("00024{}Native", struct_name),
+    ]
+    .join("_")
+}
+
+/// Call an `async` method, moving `this` into the returned future.
+pub fn call_async(
+    has_self: bool,
+    method_name: &Ident,
+    struct_name: &TokenStream,
+    call_args: TokenStream,
+) -> TokenStream {
+    if has_self {
+        quote!(let res = async move { this.#method_name(#call_args).await })
+    } else {
+        quote!(let res = #struct_name::#method_name(#call_args))
+    }
+}
+
+pub fn call(
+    has_self: bool,
+    has_ret: bool,
+    method_name: &Ident,
+    struct_name: &TokenStream,
+    call_args: TokenStream,
+) -> TokenStream {
+    if has_self && has_ret {
+        quote!(let res = this.#method_name(#call_args))
+    } else if has_self {
+        quote!(this.#method_name(#call_args))
+    } else if has_ret {
+        quote!(let res = #struct_name::#method_name(#call_args))
+    } else {
+        quote!(#struct_name::#method_name(#call_args))
+    }
+}
+
+/// Catch panics of the body of a generated JNI function. `receiver` is the
+/// class or object the function is called on, whose class loader is stored
+/// in order to find the generated classes on threads attached by Rust.
+pub fn catch_panic(
+    receiver: TokenStream,
+    ret: &TokenStream,
+    ret_val: &TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    let ret_val = if ret.is_empty() {
+        quote!()
+    } else {
+        quote!(#ret_val)
+    };
+
+    quote!(
+        jni_bindgen::vm::store(&mut env, &#receiver);
+        match jni_bindgen::errors::panic::catch_unwind(|| #ret {
+            #body
+        }) {
+            Ok(res) => res,
+            Err(e) => {
+                let _ = env.exception_clear();
+                e.throw(&mut env);
+                #ret_val
+            }
+        }
+    )
+}
+
+/// Whether the native methods are registered in a generated `JNI_OnLoad`
+/// instead of being looked up by the JVM. This is set by the build script
+/// using the `JNI_BINDGEN_REGISTER_NATIVES` environment variable.
+pub fn register_natives() -> bool {
+    std::env::var("JNI_BINDGEN_REGISTER_NATIVES").is_ok_and(|v| v == "1" || v == "true")
+}
+
+/// The attributes of a generated JNI function. The function is only
+/// exported if the JVM looks it up by its name.
+pub fn export() -> TokenStream {
+    if register_natives() {
+        quote!(#[allow(non_snake_case)])
+    } else {
+        quote!(#[no_mangle])
+    }
+}
+
+/// Generate `JNI_OnLoad`, calling the given function once the library is
+/// loaded. If the native methods are registered on load, the function is
+/// called by the `JNI_OnLoad` function generated by the build script instead,
+/// through a public wrapper, as the function itself may be private.
+pub fn on_load(func: &Ident) -> TokenStream {
+    if register_natives() {
+        return quote!(
+            #[doc(hidden)]
+            pub fn jni_bindgen_on_load(
+                vm: &jni::JavaVM,
+                env: &mut jni::JNIEnv,
+            ) -> jni_bindgen::Result<()> {
+                #func(vm, env)
+            }
+        );
+    }
+
+    quote!(
+        #[no_mangle]
+        pub extern "system" fn JNI_OnLoad(
+            vm: jni::JavaVM,
+            _reserved: *mut std::ffi::c_void,
+        ) -> jni::sys::jint {
+            jni_bindgen::vm::on_load(vm, #func)
+        }
+    )
+}
+
+/// Generate `JNI_OnUnload`, calling the given function once the library is unloaded.
+pub fn on_unload(func: &Ident) -> TokenStream {
+    quote!(
+        #[no_mangle]
+        pub extern "system" fn JNI_OnUnload(vm: jni::JavaVM, _reserved: *mut std::ffi::c_void) {
+            jni_bindgen::vm::on_unload(vm, #func)
+        }
+    )
+}
+
+pub fn drop_struct(base_name: TokenStream, struct_name: String) -> TokenStream {
+    let drop_name: TokenStream = format!("{base_name}_drop").parse().unwrap();
+    let struct_name: TokenStream = struct_name.parse().unwrap();
+    let body = catch_panic(
+        quote!(class),
+        &quote!(),
+        &quote!(),
+        quote!(unsafe {
+            jni_bindgen::conversion::class_convert::drop_ptr::<#struct_name>(ptr);
+        }),
+    );
+
+    let export = export();
+
+    quote!(
+        #export
+        pub extern "system" fn #drop_name<'local>(
+            mut env: jni::JNIEnv<'local>,
+            class: jni::objects::JClass<'local>,
+            ptr: jni::sys::jlong
+        ) {
+            #body
+        }
+    )
+}
+
+pub fn get_type_hash(base_name: TokenStream, struct_name: String) -> TokenStream {
+    let get_type_hash: TokenStream = format!("{base_name}_getTypeHash").parse().unwrap();
+    let struct_name: TokenStream = struct_name.parse().unwrap();
+    let body = catch_panic(
+        quote!(class),
+        &quote!(-> jni::sys::jlong),
+        &quote!(0),
+        quote! {
+            static CELL: std::sync::OnceLock<jni::sys::jlong> = std::sync::OnceLock::new();
+            *CELL.get_or_init(|| {
+                use std::hash::{Hash, Hasher};
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                std::any::TypeId::of::<#struct_name>().hash(&mut hasher);
+
+                hasher.finish() as jni::sys::jlong
+            })
+        },
+    );
+
+    let export = export();
+
+    quote!(
+        #export
+        pub extern "system" fn #get_type_hash<'local>(
+            mut env: jni::JNIEnv<'local>,
+            class: jni::objects::JClass<'local>,
+        ) -> jni::sys::jlong {
+            #body
+        }
+    )
+}
+
+pub fn from_jni(struct_name: String, unchecked: bool) -> TokenStream {
+    let struct_name: TokenStream = struct_name.parse().unwrap();
+    let get_struct = if unchecked {
+        quote!(unsafe { jni_bindgen::conversion::class_convert::get_struct_unchecked(env, obj) })
+    } else {
+        quote!(jni_bindgen::conversion::class_convert::get_struct(env, obj))
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl<'local> FromJNI<'local> for &'local #struct_name {
+            fn from_jni(
+                env: &mut jni::JNIEnv<'local>,
+                obj: jni::objects::JObject
+            ) -> jni_bindgen::Result<Self> {
+                #get_struct
+            }
+        }
+
+        #[automatically_derived]
+        impl<'local> ObjectFromJNI<'local> for &'local #struct_name {}
+    }
+}
+
+pub fn into_jni(struct_name: String, class_name: String) -> TokenStream {
+    let struct_name: TokenStream = struct_name.parse().unwrap();
+    quote! {
+        #[automatically_derived]
+        impl jni_bindgen::objects::traits::ClassName for #struct_name {
+            const CLASS_NAME: &'static str = #class_name;
+        }
+
+        #[automatically_derived]
+        impl jni_bindgen::objects::traits::NativeClass for #struct_name {
+            fn native_fields() -> &'static jni_bindgen::conversion::class_convert::NativeFields {
+                static FIELDS: jni_bindgen::conversion::class_convert::NativeFields =
+                    jni_bindgen::conversion::class_convert::NativeFields::new(#class_name);
+                &FIELDS
+            }
+        }
+
+        #[automatically_derived]
+        impl jni_bindgen::objects::traits::IntoJNI for #struct_name {
+            fn into_jni<'a>(
+                self,
+                env: &mut jni::JNIEnv<'a>
+            ) -> jni_bindgen::Result<jni::objects::JObject<'a>> {
+                jni_bindgen::conversion::class_convert::wrap_struct(env, std::sync::Arc::new(self))
+            }
+        }
+    }
+}
+
+/// Generate a `&'static str` expression of the JNI descriptor consisting
+/// of the given parts. Descriptors containing the class names of types
+/// are concatenated at compile time.
+pub fn descriptor(parts: impl IntoIterator<Item = DescriptorPart>) -> TokenStream {
+    let mut res = Vec::new();
+    let mut literal = String::new();
+    for part in parts {
+        match part {
+            DescriptorPart::Literal(part) => literal.push_str(&part),
+            DescriptorPart::Class(class) => {
+                if !literal.is_empty() {
+                    res.push(std::mem::take(&mut literal).into_token_stream());
+                }
+                res.push(class);
+            }
+        }
+    }
+
+    if res.is_empty() {
+        return literal.into_token_stream();
+    } else if !literal.is_empty() {
+        res.push(literal.into_token_stream());
+    }
+
+    quote!(jni_bindgen::const_concat!(#(#res),*))
+}
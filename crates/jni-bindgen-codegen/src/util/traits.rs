@@ -1,6 +1,6 @@
 use crate::util::attrs::BindgenAttrs;
 use quote::ToTokens;
-use syn::{Attribute, Expr, ImplItemFn, Lit, Meta, TraitItemFn};
+use syn::{Attribute, Expr, ImplItemFn, ItemFn, Lit, Meta, TraitItemFn};
 
 pub trait JniMethodAttrs {
     fn attrs(&self) -> &Vec<Attribute>;
@@ -45,6 +45,12 @@ impl JniMethodAttrs for TraitItemFn {
     }
 }
 
+impl JniMethodAttrs for ItemFn {
+    fn attrs(&self) -> &Vec<Attribute> {
+        &self.attrs
+    }
+}
+
 impl JniMethodAttrs for &Vec<Attribute> {
     fn attrs(&self) -> &Vec<Attribute> {
         self
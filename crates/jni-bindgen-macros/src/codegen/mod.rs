@@ -1,6 +0,0 @@
-pub mod code;
-pub mod java_class;
-pub mod java_interface;
-pub mod java_method;
-pub mod java_type;
-pub mod traits;
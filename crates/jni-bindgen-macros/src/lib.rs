@@ -1,13 +1,11 @@
 extern crate proc_macro;
 
-use crate::util::expand;
+use jni_bindgen_codegen::util::expand;
 use proc_macro::TokenStream;
 
-mod codegen;
-mod util;
-
 /// This macro will generate the JNI bindings for the given class.
-/// It can be used on `impl` blocks and its methods plus on `trait`s.
+/// It can be used on `impl` blocks and its methods plus on `trait`s, `enum`s,
+/// data `struct`s and free functions.
 ///
 /// If an `impl` block has this macro, jni methods for all methods
 /// inside that block also annotated with `#[jni]` will be generated.
@@ -19,29 +17,89 @@ mod util;
 /// If a `trait` has this macro, a java interface will be generated.
 /// The methods inside the `trait` are not required to be annotated with `#[jni]`
 /// as an interface containing all methods will be generated. The `trait` must
-/// not have any associated types. All methods must have a `&mut JNIEnv` parameter
-/// and a `&self` parameter. The `self` parameter must not be mutable.
-/// All methods must return a [`Result`], preferably a [`jni_bindgen::Result`]
-/// as the method may throw an exception.
+/// not have any associated types. All methods must have a `&self` parameter.
+/// The `self` parameter must not be mutable. All methods must return a [`Result`],
+/// preferably a [`jni_bindgen::Result`] as the method may throw an exception.
+/// A `Box<dyn Trait + Send + Sync>` holds a global reference to the java object
+/// and may be called from any thread. Methods may take a `&mut JNIEnv` parameter,
+/// otherwise the current thread is attached to the JVM on every call and any
+/// `Box<dyn Trait>` will hold a global reference.
+///
+/// If an `enum` has this macro, a java enum will be generated. The `enum` must
+/// not have any fields. The variants will be converted to `UPPER_SNAKE_CASE`
+/// unless renamed using `#[jni(rename = "...")]`. The enum may then be used as
+/// an argument or return value of any other `#[jni]` method. Values are
+/// converted from java using their ordinal and into java using their name.
+///
+/// If a `struct` has this macro with the `data` attribute, a java record
+/// will be generated. The `struct` must have named fields of supported types.
+/// Values of the struct are copied field by field when passed to or returned
+/// from java, no native handle will be allocated.
+///
+/// If a `struct` has this macro with the `error` attribute, a java exception
+/// class extending `com.github.markusjx.jnibindgen.NativeExecutionException`
+/// will be generated, with a field and an accessor for every struct field.
+/// The struct may then be returned as the error of a [`Result`], filling the
+/// fields of the thrown exception. The message of the exception is the
+/// [`Display`](std::fmt::Display) representation of the struct. The struct
+/// must implement [`Debug`], [`Send`] and [`Sync`] and its fields [`Clone`].
+///
+/// If any other `struct` has this macro, its fields may be annotated with
+/// `#[jni(getter)]` or `#[jni(setter)]`, adding accessors for the fields to
+/// the java class generated for the `impl` block of the struct. The struct must
+/// have the same `package` as the `impl` block and the classes must be generated
+/// by `jni_bindgen_build::Builder`. Getters return a clone of the field, thus
+/// the field type must implement [`Clone`].
+///
+/// If a free function has this macro with the `class` attribute, a static
+/// method of a java utility class will be generated. All functions with the
+/// same `package` and `class` are gathered into the same class, even if they
+/// are defined in different modules. The function must not take `self`.
+/// The utility class is generated by `jni_bindgen_build::Builder`, using the
+/// `class` attribute without generating the classes from a build script fails.
 ///
 /// # Arguments
 /// * `package` - *Required* The package of the class.
 /// * `load_lib` - The name of the library to load. If this is specified,
-///               the library will be loaded using `System.loadLibrary`.
-///              If this is not specified, the library will not be loaded automatically.
+///   the library will be loaded using `System.loadLibrary`.
+///   If this is not specified, the library will not be loaded automatically.
 /// * `rename` - The name of the class. If this is specified, the (java) class will be renamed.
 /// * `constructor` - If this is specified, the constructor will be renamed.
-/// * `class_name` - May be used on trait method parameters. If this is specified,
-///                the parameter will be of type `class_name` instead of the trait name.
+/// * `class_name` - May be used on trait method parameters and enum or data class
+///   parameters. If this is specified, the parameter will be of type `class_name`
+///   instead of the trait, enum or data class name.
+/// * `data` - Generate a java record for a `struct`.
+/// * `error` - Generate a java exception class for a `struct`.
+/// * `class` - The name of the java utility class a free function is added to.
+/// * `language` - The language of the generated class, either `"java"` or `"kotlin"`.
+///   Defaults to the `JNI_BINDGEN_LANGUAGE` environment variable or java. All
+///   functions of a utility class must use the same language.
+/// * `unchecked` - Convert java objects into references to the struct without
+///   checking their class. Only use this if the generated class is never
+///   subclassed or passed through untyped code, e.g. `Object` parameters.
+/// * `on_load` - Call a free function taking `&JavaVM` and `&mut JNIEnv` once the
+///   library is loaded. An error returned by the function is thrown by `System.loadLibrary`.
+/// * `on_unload` - Call a free function taking `&JavaVM` once the library is unloaded.
+/// * `getter` - Generate a getter for a struct field.
+/// * `setter` - Generate a setter for a struct field.
 ///
 /// # Supported types
 /// | Rust type | Java type |
 /// |-----------|-----------|
 /// | [`String`] | `java.lang.String` |
 /// | [`Vec`] | `java.util.List` |
+/// | [`Vec<u8>`], [`Vec<i8>`], `&[u8]`, `&[i8]` | `byte[]` |
+/// | [`Vec<i32>`], `&[i32]` | `int[]` |
+/// | [`Vec<i64>`], `&[i64]` | `long[]` |
+/// | [`Vec<f32>`], `&[f32]` | `float[]` |
+/// | [`Vec<f64>`], `&[f64]` | `double[]` |
+/// | [`Vec<bool>`], `&[bool]` | `boolean[]` |
+/// | [`Vec<i16>`], `&[i16]` | `short[]` |
+/// | [`Vec<u16>`], `&[u16]` | `char[]` |
 /// | [`HashMap`] | `java.util.Map` |
 /// | [`Wrapped`](jni_bindgen::objects::wrapped::Wrapped) | The wrapped type |
 /// | [`JObject`](jni::objects::JObject) | `java.lang.Object` |
+/// | [`DirectBuffer`](jni_bindgen::objects::direct_buffer::DirectBuffer) | `java.nio.ByteBuffer` |
 /// | [`&JNIEnv`](jni::JNIEnv) | N/A |
 /// | [`i32`] | `int` |
 /// | [`i64`] | `long` |
@@ -51,6 +109,9 @@ mod util;
 /// | [`i16`] | `short` |
 /// | [`i8`] | `byte` |
 /// | [`u16`] | `char` |
+/// | [`u8`] | `short` |
+/// | [`u32`], [`u64`], [`usize`], [`isize`] | `long` |
+/// | [`i128`], [`u128`] | `java.math.BigInteger` |
 /// | [`Option<i32>`] | `java.lang.Integer` |
 /// | [`Option<i64>`] | `java.lang.Long` |
 /// | [`Option<f32>`] | `java.lang.Float` |
@@ -60,9 +121,53 @@ mod util;
 /// | [`Option<i8>`] | `java.lang.Byte` |
 /// | [`Option<u16>`] | `java.lang.Character` |
 /// | [`Option<String>`] | `java.lang.String` |
+/// | [`Option<u8>`] | `java.lang.Short` |
+/// | [`Option<u32>`], [`Option<u64>`], [`Option<usize>`], [`Option<isize>`] | `java.lang.Long` |
 /// | [`Box<dyn Trait + 'lifetime>`] | A java interface |
+/// | Any `enum` annotated with `#[jni]` | A java enum |
+/// | Any `struct` annotated with `#[jni(data)]` | A java record |
+/// | `Self`, `&T`, `T` or `Arc<T>` of a `struct` exported using `#[jni]` | The generated java class |
 /// | Any other [`Option`] | The wrapped type |
 ///
+/// Vectors of primitive types are copied in bulk into and from java
+/// primitive arrays. Slices may only be used as arguments. Vectors of
+/// primitive types nested inside another [`Vec`] or [`HashMap`] are
+/// converted into a `java.util.List` of the boxed type instead.
+///
+/// Integer types without a matching java primitive type are converted into
+/// a wider or signed java type. A value which does not fit into the target
+/// type, e.g. a negative `long` passed as `u32` or a `u64` larger than
+/// `Long.MAX_VALUE`, throws a `java.lang.ArithmeticException`. Note that
+/// `Vec<u8>` and `&[u8]` are still converted into a `byte[]`, reinterpreting
+/// the bits of each value.
+///
+/// A `DirectBuffer` gives access to the memory of a direct `java.nio.ByteBuffer`
+/// without copying it. Passing a buffer which is not direct will throw a
+/// `java.lang.IllegalArgumentException`. Rust owned memory may be returned
+/// as a direct buffer using `DirectBuffer::from_owned`, the memory will be
+/// dropped once the java buffer has been garbage collected.
+///
+/// Structs exported using `#[jni]` may be returned by value or as an
+/// [`Arc`](std::sync::Arc), the value is wrapped in a new instance of the
+/// generated java class. References may only be used as arguments. A struct
+/// is dropped once all java objects wrapping it have been destroyed and all
+/// other `Arc`s have been dropped. Methods taking `&mut self` throw a
+/// `java.lang.IllegalStateException` if the struct is shared.
+///
+/// # Async methods
+/// An `async` method returns a `java.util.concurrent.CompletableFuture` of the
+/// boxed return type, e.g. `CompletableFuture<Integer>` for `i32` or
+/// `CompletableFuture<Void>` if the method returns nothing. The future is run
+/// on the executor set using `jni_bindgen::future::set_executor`, by default
+/// every future is run on a new thread. A `tokio::runtime::Handle` may be used
+/// as an executor if the `tokio` feature of `jni-bindgen` is enabled.
+/// Errors and panics complete the java future exceptionally.
+///
+/// As the future may outlive the JNI call, async methods may only take owned
+/// arguments and `&self`, which is kept alive until the future completes.
+/// Async methods cannot take `&mut self`, references, slices, a `JNIEnv`,
+/// a `JObject`, a `DirectBuffer` or an interface.
+///
 /// # Returning errors
 /// If a method returns a [`Result`], the error will be converted into a JNI error.
 /// You can return any error which can be converted into a [`String`]. In this case,
@@ -71,6 +176,21 @@ mod util;
 ///
 /// If you want to throw a custom exception, you can use the [`bail_class!`](jni_bindgen::bail_class)
 /// or [`error_class!`](jni_bindgen::error_class) macros while returning a [`jni_bindgen::Result<T>`].
+/// The sources of an error, e.g. the context chain of an [`anyhow::Error`], are thrown as
+/// the causes of the exception. Their classes may be set using `register_error_class`.
+/// Error enums deriving [`JniError`](macro@JniError) map each variant to an exception class,
+/// which will be declared by methods returning them instead.
+///
+/// If a java implementation of an exported trait throws an exception, the exception
+/// is caught and returned as a [`jni_bindgen::errors::jni_error::JNIError`] holding the
+/// original exception, which can be inspected using `JNIError::java_exception`.
+/// If this error is returned to java, the original exception is rethrown as-is.
+///
+/// # Panics
+/// All generated JNI methods catch panics instead of unwinding across the
+/// FFI boundary, which would abort the JVM. A caught panic will be thrown as a
+/// `com.github.markusjx.jnibindgen.NativePanicException` containing the panic
+/// message and the location of the panic.
 ///
 /// # Examples
 /// ## Export a struct to java
@@ -126,7 +246,62 @@ mod util;
 /// ```
 #[proc_macro_attribute]
 pub fn jni(args: TokenStream, input: TokenStream) -> TokenStream {
-    expand::expand(args, input)
-        .map_err(|e| e.to_compile_error())
-        .unwrap_or_else(|e| e.into())
+    expand::expand(args.into(), input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derive a conversion of an error `enum` into a [`jni_bindgen::errors::jni_error::JNIError`],
+/// throwing a specific java exception for every variant.
+///
+/// The exception class of a variant is set using `#[jni(exception = "...")]`.
+/// Class names without a package are resolved relative to the `package`
+/// set on the enum. Variants without an exception class throw a
+/// `com.github.markusjx.jnibindgen.NativeExecutionException`.
+/// If a variant is annotated with `#[jni(generate)]`, its exception class
+/// will be generated, extending `NativeExecutionException`.
+/// The message of the exception is the [`Display`](std::fmt::Display)
+/// representation of the error, so the enum must implement `Display`.
+///
+/// Methods returning a `Result<T, E>` where `E` derives `JniError` declare
+/// the exception classes of `E` instead of `NativeExecutionException`.
+/// As the declared classes are looked up by the name of the error type,
+/// this requires the java classes to be generated from a build script
+/// using `jni-bindgen-build`. Otherwise, `NativeExecutionException` is declared.
+///
+/// # Arguments
+/// * `package` - The package of exception classes without a package.
+/// * `exception` - The exception class of a variant.
+/// * `generate` - Generate the exception class of a variant.
+///
+/// # Example
+/// ```ignore
+/// use jni_bindgen::JniError;
+///
+/// #[derive(Debug, JniError)]
+/// #[jni(package = "com.example")]
+/// enum StoreError {
+///     /// Thrown if a key does not exist
+///     #[jni(exception = "NotFoundException", generate)]
+///     NotFound(String),
+///     #[jni(exception = "java.lang.IllegalArgumentException")]
+///     Invalid { reason: String },
+///     Other,
+/// }
+///
+/// impl std::fmt::Display for StoreError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             StoreError::NotFound(key) => write!(f, "Key {key} not found"),
+///             StoreError::Invalid { reason } => write!(f, "Invalid value: {reason}"),
+///             StoreError::Other => write!(f, "Unknown error"),
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro_derive(JniError, attributes(jni))]
+pub fn derive_jni_error(input: TokenStream) -> TokenStream {
+    expand::expand_error(input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
 }
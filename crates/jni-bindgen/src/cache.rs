@@ -0,0 +1,297 @@
+//! Caches for class references and method ids.
+//!
+//! Looking up a class or method by its name is expensive compared to
+//! calling it, so the generated code and the conversion helpers store
+//! the references they need in statics. The statics are filled the first
+//! time they are used; call [`CachedClass::get`] or [`CachedMethod::get`]
+//! in `JNI_OnLoad` in order to resolve them upfront. Classes are looked up
+//! using the class loader of the generated classes once a generated native
+//! method has been called, and methods called on an object look up their
+//! class using the class loader of the object, so they can be resolved
+//! first on threads attached by Rust.
+//!
+//! # Example
+//! ```
+//! use jni::objects::JObject;
+//! use jni::JNIEnv;
+//! use jni_bindgen::cache::{CachedClass, CachedMethod};
+//!
+//! static RUNNABLE: CachedClass = CachedClass::new("java/lang/Runnable");
+//! static RUN: CachedMethod = CachedMethod::new(&RUNNABLE, "run", "()V");
+//!
+//! fn run(env: &mut JNIEnv, obj: &JObject) -> jni_bindgen::Result<()> {
+//!     // Safety: run takes no arguments
+//!     unsafe { RUN.call(env, obj, &[])? };
+//!     Ok(())
+//! }
+//! ```
+use crate::natives::descriptor;
+use jni::errors::{Error, Result};
+use jni::objects::{GlobalRef, JClass, JMethodID, JObject, JStaticMethodID, JValueOwned};
+use jni::signature::{ReturnType, TypeSignature};
+use jni::sys::jvalue;
+use jni::JNIEnv;
+use std::sync::OnceLock;
+
+/// The class loader of the generated classes.
+static CLASS_LOADER: OnceLock<GlobalRef> = OnceLock::new();
+
+/// Store the class loader of the class of the given object, or of the given
+/// class itself, if no class loader has been stored yet. This is called by
+/// every generated native method, as `FindClass` uses the system class loader
+/// on threads attached by Rust, which may not find the generated classes,
+/// e.g. on Android.
+#[doc(hidden)]
+pub fn store_class_loader(env: &mut JNIEnv, obj: &JObject) {
+    if CLASS_LOADER.get().is_some() {
+        return;
+    }
+
+    match class_loader(env, obj).and_then(|loader| env.new_global_ref(loader)) {
+        Ok(loader) => {
+            let _ = CLASS_LOADER.set(loader);
+        }
+        Err(_) => {
+            let _ = env.exception_clear();
+        }
+    }
+}
+
+/// Get the class loader of the class of the given object,
+/// or of the given class if the object is a class.
+fn class_loader<'local>(env: &mut JNIEnv<'local>, obj: &JObject) -> Result<JObject<'local>> {
+    let class = if env.is_instance_of(obj, "java/lang/Class")? {
+        JClass::from(env.new_local_ref(obj)?)
+    } else {
+        env.get_object_class(obj)?
+    };
+
+    env.call_method(&class, "getClassLoader", "()Ljava/lang/ClassLoader;", &[])?
+        .l()
+}
+
+/// Load the class with the given binary name using the given class loader.
+/// The bootstrap class loader is used if the loader is null.
+fn load_class<'local>(
+    env: &mut JNIEnv<'local>,
+    name: &str,
+    loader: &JObject,
+) -> Result<JClass<'local>> {
+    let name = env.new_string(name.replace('/', "."))?;
+    let class = env
+        .call_static_method(
+            "java/lang/Class",
+            "forName",
+            "(Ljava/lang/String;ZLjava/lang/ClassLoader;)Ljava/lang/Class;",
+            &[(&name).into(), false.into(), loader.into()],
+        )?
+        .l()?;
+
+    Ok(class.into())
+}
+
+/// Look up the class with the given binary name, e.g. `com/example/Foo`,
+/// using the class loader of the generated classes if a generated native
+/// method has been called before, otherwise using `FindClass`. Unlike
+/// `FindClass`, this finds the generated classes on threads attached by Rust.
+/// The name is not changed by [`set_class_names`](crate::natives::set_class_names).
+pub fn find_class<'local>(env: &mut JNIEnv<'local>, name: &str) -> Result<JClass<'local>> {
+    match CLASS_LOADER.get() {
+        Some(loader) => load_class(env, name, loader.as_obj()),
+        None => env.find_class(name),
+    }
+}
+
+/// A class which is looked up once and kept
+/// alive by a global reference afterwards.
+pub struct CachedClass {
+    name: &'static str,
+    class: OnceLock<GlobalRef>,
+}
+
+impl CachedClass {
+    /// Create a new cache for the class with the given binary name,
+    /// e.g. `java/util/HashMap`. The class is looked up using the
+    /// name set by [`set_class_names`](crate::natives::set_class_names).
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            class: OnceLock::new(),
+        }
+    }
+
+    /// The binary name of the class.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the class, looking it up using [`find_class`] if this is the first call.
+    pub fn get(&self, env: &mut JNIEnv) -> Result<&JClass<'static>> {
+        match self.class.get() {
+            Some(class) => Ok(class.as_obj().into()),
+            None => {
+                let class = find_class(env, crate::natives::class_name(self.name).as_ref())?;
+                self.store(env, class)
+            }
+        }
+    }
+
+    /// Get the class, looking it up using the class loader of the given
+    /// object if this is the first call. Unlike [`get`](Self::get), this
+    /// finds classes of the application on threads attached by Rust,
+    /// as long as the object's class is loaded by the same class loader.
+    pub fn get_for(&self, env: &mut JNIEnv, obj: &JObject) -> Result<&JClass<'static>> {
+        if let Some(class) = self.class.get() {
+            return Ok(class.as_obj().into());
+        }
+
+        let loader = class_loader(env, obj)?;
+        let class = load_class(env, &crate::natives::class_name(self.name), &loader)?;
+        self.store(env, class)
+    }
+
+    fn store(&self, env: &mut JNIEnv, class: JClass) -> Result<&JClass<'static>> {
+        let class = env.new_global_ref(class)?;
+        Ok(self.class.get_or_init(|| class).as_obj().into())
+    }
+}
+
+/// An instance method or constructor of a [`CachedClass`].
+pub struct CachedMethod {
+    class: &'static CachedClass,
+    name: &'static str,
+    sig: &'static str,
+    id: OnceLock<(JMethodID, ReturnType)>,
+}
+
+impl CachedMethod {
+    /// Create a new cache for the method with the given name and
+    /// JNI descriptor. Use `<init>` as the name of a constructor.
+    pub const fn new(class: &'static CachedClass, name: &'static str, sig: &'static str) -> Self {
+        Self {
+            class,
+            name,
+            sig,
+            id: OnceLock::new(),
+        }
+    }
+
+    /// Get the method id, looking it up if this is the first call.
+    pub fn get(&self, env: &mut JNIEnv) -> Result<JMethodID> {
+        self.resolve(env, None).map(|(id, _)| *id)
+    }
+
+    /// Get the method id, looking the class up using the class loader
+    /// of `obj` if given. See [`CachedClass::get_for`].
+    fn resolve(&self, env: &mut JNIEnv, obj: Option<&JObject>) -> Result<&(JMethodID, ReturnType)> {
+        if let Some(id) = self.id.get() {
+            return Ok(id);
+        }
+
+        let class = match obj {
+            Some(obj) => self.class.get_for(env, obj)?,
+            None => self.class.get(env)?,
+        };
+        let id = env.get_method_id(class, self.name, descriptor(self.sig).as_ref())?;
+        let ret = TypeSignature::from_str(self.sig)?.ret;
+        Ok(self.id.get_or_init(|| (id, ret)))
+    }
+
+    /// Call the method on the given object. On the first call, the
+    /// class is looked up using the class loader of the object.
+    /// If the object is not an instance of the class, a
+    /// `ClassCastException` is thrown, as the object may be
+    /// the element of a generic container, e.g. a `List<Integer>`,
+    /// whose type is not checked by java.
+    ///
+    /// # Safety
+    /// The arguments must match the descriptor of the method.
+    pub unsafe fn call<'local>(
+        &self,
+        env: &mut JNIEnv<'local>,
+        obj: &JObject,
+        args: &[jvalue],
+    ) -> Result<JValueOwned<'local>> {
+        if obj.is_null() {
+            return Err(Error::NullPtr("call_method obj argument"));
+        }
+
+        let (id, ret) = self.resolve(env, Some(obj))?;
+        let class = self.class.get_for(env, obj)?;
+        if !env.is_instance_of(obj, class)? {
+            let name = crate::natives::class_name(self.class.name).replace('/', ".");
+            env.throw_new(
+                "java/lang/ClassCastException",
+                format!("Expected an instance of {name}"),
+            )?;
+            return Err(Error::JavaException);
+        }
+
+        env.call_method_unchecked(obj, *id, ret.clone(), args)
+    }
+
+    /// Create a new instance of the class using this constructor.
+    ///
+    /// # Safety
+    /// The arguments must match the descriptor of the constructor.
+    pub unsafe fn new_object<'local>(
+        &self,
+        env: &mut JNIEnv<'local>,
+        args: &[jvalue],
+    ) -> Result<JObject<'local>> {
+        let (id, _) = self.resolve(env, None)?;
+        let class = self.class.get(env)?;
+        env.new_object_unchecked(class, *id, args)
+    }
+}
+
+/// A static method of a [`CachedClass`].
+pub struct CachedStaticMethod {
+    class: &'static CachedClass,
+    name: &'static str,
+    sig: &'static str,
+    id: OnceLock<(JStaticMethodID, ReturnType)>,
+}
+
+impl CachedStaticMethod {
+    /// Create a new cache for the static method
+    /// with the given name and JNI descriptor.
+    pub const fn new(class: &'static CachedClass, name: &'static str, sig: &'static str) -> Self {
+        Self {
+            class,
+            name,
+            sig,
+            id: OnceLock::new(),
+        }
+    }
+
+    /// Get the method id, looking it up if this is the first call.
+    pub fn get(&self, env: &mut JNIEnv) -> Result<JStaticMethodID> {
+        self.resolve(env).map(|(id, _)| *id)
+    }
+
+    fn resolve(&self, env: &mut JNIEnv) -> Result<&(JStaticMethodID, ReturnType)> {
+        if let Some(id) = self.id.get() {
+            return Ok(id);
+        }
+
+        let class = self.class.get(env)?;
+        let id = env.get_static_method_id(class, self.name, descriptor(self.sig).as_ref())?;
+        let ret = TypeSignature::from_str(self.sig)?.ret;
+        Ok(self.id.get_or_init(|| (id, ret)))
+    }
+
+    /// Call the static method.
+    ///
+    /// # Safety
+    /// The arguments must match the descriptor of the method.
+    pub unsafe fn call<'local>(
+        &self,
+        env: &mut JNIEnv<'local>,
+        args: &[jvalue],
+    ) -> Result<JValueOwned<'local>> {
+        let (id, ret) = self.resolve(env)?;
+        let class = self.class.get(env)?;
+        env.call_static_method_unchecked(class, *id, ret.clone(), args)
+    }
+}
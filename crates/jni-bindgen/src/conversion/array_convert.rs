@@ -0,0 +1,160 @@
+use crate::bail_class;
+use crate::errors::jni_error::ErrorClass;
+use jni::objects::{
+    JBooleanArray, JByteArray, JCharArray, JDoubleArray, JFloatArray, JIntArray, JLongArray,
+    JObject, JShortArray,
+};
+use jni::sys::{jobject, jsize};
+use jni::JNIEnv;
+
+/// A primitive type which can be stored in a Java primitive array.
+/// The values are copied in bulk using the `Get<Type>ArrayRegion`
+/// and `Set<Type>ArrayRegion` JNI functions.
+pub trait ArrayElement: Sized {
+    /// Copy the contents of a Java primitive array into a Rust [`Vec`].
+    fn from_array(env: &mut JNIEnv, obj: JObject) -> crate::Result<Vec<Self>>;
+
+    /// Copy the contents of a Rust slice into a new Java primitive array.
+    fn into_array(env: &mut JNIEnv, data: &[Self]) -> crate::Result<jobject>;
+}
+
+fn array_len(len: usize) -> crate::Result<jsize> {
+    if len > jsize::MAX as usize {
+        bail_class!(
+            ErrorClass::IllegalArgument,
+            "The slice is too large to be converted into a Java array: {}",
+            len
+        );
+    }
+
+    Ok(len as jsize)
+}
+
+macro_rules! impl_array_element {
+    ($t: ty, $array: ident, $new: ident, $get: ident, $set: ident) => {
+        impl ArrayElement for $t {
+            fn from_array(env: &mut JNIEnv, obj: JObject) -> crate::Result<Vec<Self>> {
+                let array = $array::from(obj);
+                let mut buf = vec![Self::default(); env.get_array_length(&array)? as usize];
+                env.$get(&array, 0, &mut buf)?;
+
+                Ok(buf)
+            }
+
+            fn into_array(env: &mut JNIEnv, data: &[Self]) -> crate::Result<jobject> {
+                let array = env.$new(array_len(data.len())?)?;
+                env.$set(&array, 0, data)?;
+
+                Ok(array.into_raw())
+            }
+        }
+    };
+}
+
+impl_array_element!(
+    i8,
+    JByteArray,
+    new_byte_array,
+    get_byte_array_region,
+    set_byte_array_region
+);
+impl_array_element!(
+    i16,
+    JShortArray,
+    new_short_array,
+    get_short_array_region,
+    set_short_array_region
+);
+impl_array_element!(
+    u16,
+    JCharArray,
+    new_char_array,
+    get_char_array_region,
+    set_char_array_region
+);
+impl_array_element!(
+    i32,
+    JIntArray,
+    new_int_array,
+    get_int_array_region,
+    set_int_array_region
+);
+impl_array_element!(
+    i64,
+    JLongArray,
+    new_long_array,
+    get_long_array_region,
+    set_long_array_region
+);
+impl_array_element!(
+    f32,
+    JFloatArray,
+    new_float_array,
+    get_float_array_region,
+    set_float_array_region
+);
+impl_array_element!(
+    f64,
+    JDoubleArray,
+    new_double_array,
+    get_double_array_region,
+    set_double_array_region
+);
+
+impl ArrayElement for u8 {
+    fn from_array(env: &mut JNIEnv, obj: JObject) -> crate::Result<Vec<Self>> {
+        env.convert_byte_array(JByteArray::from(obj))
+            .map_err(Into::into)
+    }
+
+    fn into_array(env: &mut JNIEnv, data: &[Self]) -> crate::Result<jobject> {
+        array_len(data.len())?;
+        Ok(env.byte_array_from_slice(data)?.into_raw())
+    }
+}
+
+impl ArrayElement for bool {
+    fn from_array(env: &mut JNIEnv, obj: JObject) -> crate::Result<Vec<Self>> {
+        let array = JBooleanArray::from(obj);
+        let mut buf = vec![0; env.get_array_length(&array)? as usize];
+        env.get_boolean_array_region(&array, 0, &mut buf)?;
+
+        Ok(buf.into_iter().map(|b| b == jni::sys::JNI_TRUE).collect())
+    }
+
+    fn into_array(env: &mut JNIEnv, data: &[Self]) -> crate::Result<jobject> {
+        let array = env.new_boolean_array(array_len(data.len())?)?;
+        let buf = data.iter().map(|b| *b as u8).collect::<Vec<_>>();
+        env.set_boolean_array_region(&array, 0, &buf)?;
+
+        Ok(array.into_raw())
+    }
+}
+
+/// Convert a Java primitive array into a Rust [`Vec`].
+///
+/// # Arguments
+/// * `env` - The JNI environment.
+/// * `obj` - The Java array.
+///
+/// # Returns
+/// The Rust [`Vec`].
+pub fn into_vec<T: ArrayElement>(env: &mut JNIEnv, obj: JObject) -> crate::Result<Vec<T>> {
+    if obj.is_null() {
+        bail_class!(ErrorClass::NullPointer, "The array is null");
+    }
+
+    T::from_array(env, obj)
+}
+
+/// Convert a Rust slice into a Java primitive array.
+///
+/// # Arguments
+/// * `env` - The JNI environment.
+/// * `data` - The Rust slice.
+///
+/// # Returns
+/// The Java array.
+pub fn from_slice<T: ArrayElement>(env: &mut JNIEnv, data: &[T]) -> crate::Result<jobject> {
+    T::into_array(env, data)
+}
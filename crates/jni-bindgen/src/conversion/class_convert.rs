@@ -1,10 +1,21 @@
 use crate::bail_class;
+use crate::cache::{CachedClass, CachedMethod};
 use crate::errors::jni_error::ErrorClass;
-use jni::objects::JObject;
+use crate::objects::traits::{ClassName, FromJNI, IntoJNI, NativeClass};
+use jni::objects::{JClass, JFieldID, JObject, JValue};
+use jni::signature::{Primitive, ReturnType};
 use jni::sys::jlong;
 use jni::JNIEnv;
 use std::any::TypeId;
 use std::hash::{Hash, Hasher};
+use std::mem::ManuallyDrop;
+use std::sync::{Arc, OnceLock};
+
+const NATIVE_POINTER_CLASS: &str = "com/github/markusjx/jnibindgen/NativePointer";
+
+static NATIVE_POINTER: CachedClass = CachedClass::new(NATIVE_POINTER_CLASS);
+
+static NATIVE_CLASS: CachedClass = CachedClass::new("com/github/markusjx/jnibindgen/NativeClass");
 
 fn hash_type<T: 'static>() -> jlong {
     let mut hasher = std::collections::hash_map::DefaultHasher::new();
@@ -12,17 +23,64 @@ fn hash_type<T: 'static>() -> jlong {
     hasher.finish() as jlong
 }
 
-fn check_ptr<T: 'static>(env: &mut JNIEnv, obj: &JObject, ptr: *const T) -> crate::Result<()> {
-    if ptr.is_null() {
-        bail_class!(ErrorClass::NullPointer, "The pointer is null");
+/// The generated Java class of a struct and the id of the field holding
+/// its native object. The generated code stores this in a static, so the
+/// class and fields are only looked up and checked once.
+pub struct NativeFields {
+    class: CachedClass,
+    inner: OnceLock<JFieldID>,
+}
+
+impl NativeFields {
+    /// Create a new cache for the generated Java class with the given name.
+    pub const fn new(class_name: &'static str) -> Self {
+        Self {
+            class: CachedClass::new(class_name),
+            inner: OnceLock::new(),
+        }
     }
 
-    let cls = env.get_object_class(obj)?;
-    let type_id = env
-        .call_static_method(cls, "getTypeHash", "()J", &[])?
-        .j()?;
+    /// Get the class and the id of its `inner` field, checking
+    /// that the class belongs to `T` if this is the first call.
+    fn resolve<T: 'static>(&self, env: &mut JNIEnv) -> crate::Result<(&JClass<'static>, JFieldID)> {
+        if let Some(inner) = self.inner.get() {
+            return Ok((self.class.get(env)?, *inner));
+        }
+
+        let class = self.class.get(env)?;
+        let type_id = env
+            .call_static_method(class, "getTypeHash", "()J", &[])?
+            .j()?;
+
+        if type_id != hash_type::<T>() {
+            bail_class!(
+                ErrorClass::IllegalArgument,
+                "The class {} does not belong to {}",
+                self.class.name(),
+                std::any::type_name::<T>()
+            );
+        }
+
+        let name = self.class.name();
+        let simple_name = name.rsplit('/').next().unwrap_or(name);
+        let sig = format!("L{name}${simple_name}Native;");
+        let inner = env.get_field_id(class, "inner", crate::natives::descriptor(&sig).as_ref())?;
+
+        Ok((class, *self.inner.get_or_init(|| inner)))
+    }
+}
+
+/// Read the pointer stored in an instance of the generated Java class of `T`.
+/// The class of the object is only checked if `checked` is true.
+fn get_ptr<T: NativeClass>(env: &mut JNIEnv, obj: &JObject, checked: bool) -> crate::Result<jlong> {
+    static PTR: OnceLock<JFieldID> = OnceLock::new();
+
+    if obj.is_null() {
+        bail_class!(ErrorClass::NullPointer, "The value is null");
+    }
 
-    if type_id != hash_type::<T>() {
+    let (class, inner) = T::native_fields().resolve::<T>(env)?;
+    if checked && !env.is_instance_of(obj, class)? {
         bail_class!(
             ErrorClass::IllegalArgument,
             "Expected object of type {}",
@@ -30,24 +88,177 @@ fn check_ptr<T: 'static>(env: &mut JNIEnv, obj: &JObject, ptr: *const T) -> crat
         );
     }
 
-    Ok(())
+    let ptr = match PTR.get() {
+        Some(ptr) => *ptr,
+        None => {
+            let class = NATIVE_CLASS.get(env)?;
+            let ptr = env.get_field_id(class, "ptr", "J")?;
+            *PTR.get_or_init(|| ptr)
+        }
+    };
+
+    let inner = env
+        .get_field_unchecked(obj, inner, ReturnType::Object)?
+        .l()?;
+    if inner.is_null() {
+        bail_class!(ErrorClass::NullPointer, "The pointer is null");
+    }
+
+    let res = env
+        .get_field_unchecked(&inner, ptr, ReturnType::Primitive(Primitive::Long))?
+        .j()?;
+    env.delete_local_ref(inner)?;
+
+    Ok(res)
+}
+
+/// Get a reference to a Rust struct from the pointer
+/// stored in an instance of the generated Java class.
+///
+/// # Safety
+/// The pointer must either be null or point to a live `T`
+/// which has been created by [`into_ptr`].
+pub unsafe fn ptr_as_ref<'a, T>(ptr: jlong) -> crate::Result<&'a T> {
+    if ptr == 0 {
+        bail_class!(ErrorClass::NullPointer, "The pointer is null");
+    }
+
+    Ok(&*(ptr as *const T))
+}
+
+/// Get a mutable reference to a Rust struct from the pointer
+/// stored in an instance of the generated Java class.
+/// This fails if the struct is shared with other Java objects
+/// or Rust code using an [`Arc`].
+///
+/// # Safety
+/// The pointer must either be null or point to a live `T`
+/// which has been created by [`into_ptr`].
+pub unsafe fn ptr_as_mut<'a, T>(ptr: jlong) -> crate::Result<&'a mut T> {
+    if ptr == 0 {
+        bail_class!(ErrorClass::NullPointer, "The pointer is null");
+    }
+
+    let shared = ManuallyDrop::new(Arc::from_raw(ptr as *const T));
+    if Arc::strong_count(&shared) > 1 {
+        bail_class!(
+            ErrorClass::IllegalState,
+            "The object is shared and cannot be borrowed mutably"
+        );
+    }
+
+    Ok(&mut *(ptr as *mut T))
+}
+
+/// Get a shared reference to a Rust struct from the pointer
+/// stored in an instance of the generated Java class.
+/// The struct stays alive as long as the returned [`Arc`] exists.
+///
+/// # Safety
+/// The pointer must either be null or point to a live `T`
+/// which has been created by [`into_ptr`].
+pub unsafe fn ptr_as_arc<T>(ptr: jlong) -> crate::Result<Arc<T>> {
+    if ptr == 0 {
+        bail_class!(ErrorClass::NullPointer, "The pointer is null");
+    }
+
+    Arc::increment_strong_count(ptr as *const T);
+    Ok(Arc::from_raw(ptr as *const T))
+}
+
+/// Convert a Rust struct into a pointer which can
+/// be stored in an instance of the generated Java class.
+pub fn into_ptr<T>(value: Arc<T>) -> jlong {
+    Arc::into_raw(value) as jlong
+}
+
+/// Drop a pointer created by [`into_ptr`].
+///
+/// # Safety
+/// The pointer must either be null or have been created by
+/// [`into_ptr`] and must not be used after calling this.
+pub unsafe fn drop_ptr<T>(ptr: jlong) {
+    if ptr != 0 {
+        drop(Arc::from_raw(ptr as *const T));
+    }
 }
 
 /// Get a pointer to a Rust struct from a Java object.
-pub fn get_struct<'a, T: 'static>(env: &mut JNIEnv<'a>, obj: JObject) -> crate::Result<&'a T> {
-    let ptr = env.call_method(&obj, "getPtr", "()J", &[])?.j()? as *const T;
-    check_ptr(env, &obj, ptr)?;
+pub fn get_struct<'a, T: NativeClass>(env: &mut JNIEnv<'a>, obj: JObject) -> crate::Result<&'a T> {
+    let ptr = get_ptr::<T>(env, &obj, true)?;
+    unsafe { ptr_as_ref(ptr) }
+}
 
-    unsafe { Ok(&*ptr) }
+/// Get a pointer to a Rust struct from a Java object
+/// without checking the class of the object.
+/// This is used for classes with the `unchecked` attribute.
+///
+/// # Safety
+/// The object must be null or an instance of the generated Java class of `T`.
+pub unsafe fn get_struct_unchecked<'a, T: NativeClass>(
+    env: &mut JNIEnv<'a>,
+    obj: JObject,
+) -> crate::Result<&'a T> {
+    let ptr = get_ptr::<T>(env, &obj, false)?;
+    ptr_as_ref(ptr)
 }
 
 /// Get a mutable pointer to a Rust struct from a Java object.
-pub fn get_struct_mut<'a, T: 'static>(
+pub fn get_struct_mut<'a, T: NativeClass>(
     env: &mut JNIEnv<'a>,
     obj: JObject,
 ) -> crate::Result<&'a mut T> {
-    let ptr = env.call_method(&obj, "getPtr", "()J", &[])?.j()? as *mut T;
-    check_ptr(env, &obj, ptr)?;
+    let ptr = get_ptr::<T>(env, &obj, true)?;
+    unsafe { ptr_as_mut(ptr) }
+}
+
+/// Get a shared reference to a Rust struct from a Java object.
+/// The struct will stay alive as long as the returned [`Arc`]
+/// exists, even if the Java object has been destroyed.
+pub fn get_struct_arc<T: NativeClass>(env: &mut JNIEnv, obj: JObject) -> crate::Result<Arc<T>> {
+    let ptr = get_ptr::<T>(env, &obj, true)?;
+    unsafe { ptr_as_arc(ptr) }
+}
 
-    unsafe { Ok(&mut *ptr) }
+/// Wrap a Rust struct in a new instance of its generated Java class.
+/// The struct is dropped once all Java objects wrapping it have been
+/// garbage collected or destroyed and all other [`Arc`]s have been dropped.
+pub fn wrap_struct<'a, T: NativeClass>(
+    env: &mut JNIEnv<'a>,
+    value: Arc<T>,
+) -> crate::Result<JObject<'a>> {
+    static NATIVE_POINTER_NEW: CachedMethod = CachedMethod::new(&NATIVE_POINTER, "<init>", "(J)V");
+
+    let class = T::native_fields().class.get(env)?;
+    let ptr = into_ptr(value);
+    // Safety: the arguments match the descriptor of the constructor
+    let res = unsafe { NATIVE_POINTER_NEW.new_object(env, &[JValue::Long(ptr).as_jni()]) }
+        .and_then(|native_ptr| {
+            env.new_object(
+                class,
+                crate::natives::descriptor(&format!("(L{NATIVE_POINTER_CLASS};)V")).as_ref(),
+                &[JValue::Object(&native_ptr)],
+            )
+        });
+
+    res.map_err(|e| {
+        unsafe { drop_ptr::<T>(ptr) };
+        e.into()
+    })
+}
+
+impl<T: NativeClass> ClassName for Arc<T> {
+    const CLASS_NAME: &'static str = T::CLASS_NAME;
+}
+
+impl<'local, T: NativeClass> FromJNI<'local> for Arc<T> {
+    fn from_jni(env: &mut JNIEnv<'local>, obj: JObject<'local>) -> crate::Result<Self> {
+        get_struct_arc(env, obj)
+    }
+}
+
+impl<T: NativeClass> IntoJNI for Arc<T> {
+    fn into_jni<'a>(self, env: &mut JNIEnv<'a>) -> crate::Result<JObject<'a>> {
+        wrap_struct(env, self)
+    }
 }
@@ -0,0 +1,78 @@
+use crate::error_class;
+use crate::errors::jni_error::ErrorClass;
+
+/// An integer type without a matching java primitive type.
+/// Such a type is converted into a wider or signed java type,
+/// e.g. `u32` into `long`. A value which does not fit into
+/// the target type is rejected with a `java.lang.ArithmeticException`.
+///
+/// | Rust type | Java type |
+/// |-----------|-----------|
+/// | [`u8`] | `short` |
+/// | [`u32`] | `long` |
+/// | [`u64`] | `long` |
+/// | [`usize`] | `long` |
+/// | [`isize`] | `long` |
+pub trait CheckedInt: Sized {
+    /// The java primitive type this type is converted into.
+    type Java;
+
+    /// Convert a java value into this type.
+    ///
+    /// # Example
+    /// ```
+    /// use jni_bindgen::conversion::int_convert::CheckedInt;
+    ///
+    /// assert_eq!(u32::from_java(42).unwrap(), 42);
+    /// assert!(u32::from_java(-1).is_err());
+    /// ```
+    fn from_java(value: Self::Java) -> crate::Result<Self>;
+
+    /// Convert this value into its java type.
+    ///
+    /// # Example
+    /// ```
+    /// use jni_bindgen::conversion::int_convert::CheckedInt;
+    ///
+    /// assert_eq!(42u64.into_java().unwrap(), 42);
+    /// assert!(u64::MAX.into_java().is_err());
+    /// ```
+    fn into_java(self) -> crate::Result<Self::Java>;
+}
+
+macro_rules! impl_checked_int {
+    ($ty: ty, $java: ty) => {
+        impl CheckedInt for $ty {
+            type Java = $java;
+
+            fn from_java(value: $java) -> crate::Result<Self> {
+                <$ty>::try_from(value).map_err(|_| {
+                    error_class!(
+                        ErrorClass::Arithmetic,
+                        "The value {} is out of range for {}",
+                        value,
+                        stringify!($ty)
+                    )
+                })
+            }
+
+            #[allow(clippy::useless_conversion)]
+            fn into_java(self) -> crate::Result<$java> {
+                <$java>::try_from(self).map_err(|_| {
+                    error_class!(
+                        ErrorClass::Arithmetic,
+                        "The value {} does not fit into a java {}",
+                        self,
+                        stringify!($java)
+                    )
+                })
+            }
+        }
+    };
+}
+
+impl_checked_int!(u8, i16);
+impl_checked_int!(u32, i64);
+impl_checked_int!(u64, i64);
+impl_checked_int!(usize, i64);
+impl_checked_int!(isize, i64);
@@ -1,3 +1,5 @@
+pub mod array_convert;
 pub mod class_convert;
+pub mod int_convert;
 pub mod object_convert;
 pub mod option_convert;
@@ -1,11 +1,24 @@
+use crate::cache::{CachedClass, CachedMethod};
 use crate::conversion::option_convert::*;
 use crate::objects::traits::{FromJNI, IntoJNI};
-use jni::objects::{JObject, JString};
+use jni::objects::{JObject, JString, JValue};
 use jni::sys::jobject;
 use jni::JNIEnv;
 use std::collections::HashMap;
 use std::hash::Hash;
 
+static HASH_MAP: CachedClass = CachedClass::new("java/util/HashMap");
+static HASH_MAP_NEW: CachedMethod = CachedMethod::new(&HASH_MAP, "<init>", "()V");
+static HASH_MAP_PUT: CachedMethod = CachedMethod::new(
+    &HASH_MAP,
+    "put",
+    "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
+);
+static ARRAY_LIST: CachedClass = CachedClass::new("java/util/ArrayList");
+static ARRAY_LIST_NEW: CachedMethod = CachedMethod::new(&ARRAY_LIST, "<init>", "()V");
+static ARRAY_LIST_ADD: CachedMethod =
+    CachedMethod::new(&ARRAY_LIST, "add", "(Ljava/lang/Object;)Z");
+
 macro_rules! impl_convert {
     ($ty: ty, $from: ident, $to: ident) => {
         impl FromJNI<'_> for $ty {
@@ -43,6 +56,13 @@ impl_convert!(bool, bool_from_jni, bool_into_jni);
 impl_convert!(i16, i16_from_jni, i16_into_jni);
 impl_convert!(i8, i8_from_jni, i8_into_jni);
 impl_convert!(u16, u16_from_jni, u16_into_jni);
+impl_convert!(u8, u8_from_jni, u8_into_jni);
+impl_convert!(u32, u32_from_jni, u32_into_jni);
+impl_convert!(u64, u64_from_jni, u64_into_jni);
+impl_convert!(usize, usize_from_jni, usize_into_jni);
+impl_convert!(isize, isize_from_jni, isize_into_jni);
+impl_convert!(i128, i128_from_jni, i128_into_jni);
+impl_convert!(u128, u128_from_jni, u128_into_jni);
 
 impl<'local> FromJNI<'local> for JObject<'local> {
     fn from_jni(_: &mut JNIEnv<'local>, obj: JObject) -> crate::Result<Self> {
@@ -165,17 +185,18 @@ pub fn from_hashmap<K: IntoJNI + Eq + Hash, V: IntoJNI>(
     env: &mut JNIEnv,
     map: HashMap<K, V>,
 ) -> crate::Result<jobject> {
-    let j_map = env.new_object("java/util/HashMap", "()V", &[])?;
+    let j_map = unsafe { HASH_MAP_NEW.new_object(env, &[])? };
 
     for (k, v) in map {
         let key = k.into_jni(env)?;
         let value = v.into_jni(env)?;
-        env.call_method(
-            &j_map,
-            "put",
-            "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;",
-            &[key.as_ref().into(), value.as_ref().into()],
-        )?;
+        unsafe {
+            HASH_MAP_PUT.call(
+                env,
+                &j_map,
+                &[JValue::from(&key).as_jni(), JValue::from(&value).as_jni()],
+            )?
+        };
     }
 
     Ok(j_map.into_raw())
@@ -213,16 +234,11 @@ pub fn into_vec<'local, T: FromJNI<'local>>(
 /// # Returns
 /// The Java object.
 pub fn from_vec<T: IntoJNI>(env: &mut JNIEnv, vec: Vec<T>) -> crate::Result<jobject> {
-    let j_vec = env.new_object("java/util/ArrayList", "()V", &[])?;
+    let j_vec = unsafe { ARRAY_LIST_NEW.new_object(env, &[])? };
 
     for v in vec {
         let value = v.into_jni(env)?;
-        env.call_method(
-            &j_vec,
-            "add",
-            "(Ljava/lang/Object;)Z",
-            &[value.as_ref().into()],
-        )?;
+        unsafe { ARRAY_LIST_ADD.call(env, &j_vec, &[JValue::from(&value).as_jni()])? };
     }
 
     Ok(j_vec.into_raw())
@@ -1,15 +1,19 @@
-use jni::objects::JString;
+use crate::cache::{CachedClass, CachedMethod, CachedStaticMethod};
+use crate::conversion::int_convert::CheckedInt;
+use crate::errors::jni_error::ErrorClass;
+use jni::objects::{JString, JValue};
 macro_rules! impl_option_into_jni {
-    ($t:ty, $to: ident, $from: ident, $cls: expr, $constructor: expr, $getter: expr, $get_fn: ident) => {
+    ($t:ty, $to: ident, $from: ident, $cls: literal, $constructor: literal, $getter: literal, $get_fn: ident) => {
         pub fn $to(env: &mut jni::JNIEnv, val: Option<$t>) -> crate::Result<jni::sys::jobject> {
+            static CLASS: CachedClass = CachedClass::new($cls);
+            static VALUE_OF: CachedStaticMethod = CachedStaticMethod::new(
+                &CLASS,
+                "valueOf",
+                concat!("(", $constructor, ")L", $cls, ";"),
+            );
+
             Ok(match val {
-                Some(val) => env
-                    .call_static_method(
-                        $cls,
-                        "valueOf",
-                        format!("({})L{};", $constructor, $cls),
-                        &[val.into()],
-                    )?
+                Some(val) => unsafe { VALUE_OF.call(env, &[JValue::from(val).as_jni()])? }
                     .l()?
                     .into_raw(),
                 None => std::ptr::null_mut(),
@@ -20,13 +24,14 @@ macro_rules! impl_option_into_jni {
             env: &mut jni::JNIEnv,
             val: jni::objects::JObject,
         ) -> crate::Result<Option<$t>> {
+            static CLASS: CachedClass = CachedClass::new($cls);
+            static GETTER: CachedMethod =
+                CachedMethod::new(&CLASS, $getter, concat!("()", $constructor));
+
             Ok(if val.is_null() {
                 None
             } else {
-                Some(
-                    env.call_method(val, $getter, format!("(){}", $constructor), &[])?
-                        .$get_fn()? as $t,
-                )
+                Some(unsafe { GETTER.call(env, &val, &[])? }.$get_fn()? as $t)
             })
         }
     };
@@ -125,3 +130,81 @@ pub fn string_from_jni(
         Some(env.get_string(&JString::from(val))?.into())
     })
 }
+
+macro_rules! impl_option_checked_into_jni {
+    ($t:ty, $to: ident, $from: ident, $java_to: ident, $java_from: ident) => {
+        pub fn $to(env: &mut jni::JNIEnv, val: Option<$t>) -> crate::Result<jni::sys::jobject> {
+            $java_to(env, val.map(CheckedInt::into_java).transpose()?)
+        }
+
+        pub fn $from(
+            env: &mut jni::JNIEnv,
+            val: jni::objects::JObject,
+        ) -> crate::Result<Option<$t>> {
+            $java_from(env, val)?.map(CheckedInt::from_java).transpose()
+        }
+    };
+}
+
+impl_option_checked_into_jni!(u8, u8_into_jni, u8_from_jni, i16_into_jni, i16_from_jni);
+impl_option_checked_into_jni!(u32, u32_into_jni, u32_from_jni, i64_into_jni, i64_from_jni);
+impl_option_checked_into_jni!(u64, u64_into_jni, u64_from_jni, i64_into_jni, i64_from_jni);
+impl_option_checked_into_jni!(
+    usize,
+    usize_into_jni,
+    usize_from_jni,
+    i64_into_jni,
+    i64_from_jni
+);
+impl_option_checked_into_jni!(
+    isize,
+    isize_into_jni,
+    isize_from_jni,
+    i64_into_jni,
+    i64_from_jni
+);
+
+static BIG_INTEGER: CachedClass = CachedClass::new("java/math/BigInteger");
+static BIG_INTEGER_NEW: CachedMethod =
+    CachedMethod::new(&BIG_INTEGER, "<init>", "(Ljava/lang/String;)V");
+static BIG_INTEGER_TO_STRING: CachedMethod =
+    CachedMethod::new(&BIG_INTEGER, "toString", "()Ljava/lang/String;");
+
+macro_rules! impl_option_big_integer_into_jni {
+    ($t:ty, $to: ident, $from: ident) => {
+        pub fn $to(env: &mut jni::JNIEnv, val: Option<$t>) -> crate::Result<jni::sys::jobject> {
+            Ok(match val {
+                Some(val) => {
+                    let str = env.new_string(val.to_string())?;
+                    unsafe { BIG_INTEGER_NEW.new_object(env, &[JValue::from(&str).as_jni()])? }
+                        .into_raw()
+                }
+                None => std::ptr::null_mut(),
+            })
+        }
+
+        pub fn $from(
+            env: &mut jni::JNIEnv,
+            val: jni::objects::JObject,
+        ) -> crate::Result<Option<$t>> {
+            if val.is_null() {
+                return Ok(None);
+            }
+
+            let str = unsafe { BIG_INTEGER_TO_STRING.call(env, &val, &[])? }.l()?;
+            let str: String = env.get_string(&JString::from(str))?.into();
+
+            str.parse().map(Some).map_err(|_| {
+                crate::error_class!(
+                    ErrorClass::Arithmetic,
+                    "The value {} is out of range for {}",
+                    str,
+                    stringify!($t)
+                )
+            })
+        }
+    };
+}
+
+impl_option_big_integer_into_jni!(i128, i128_into_jni, i128_from_jni);
+impl_option_big_integer_into_jni!(u128, u128_into_jni, u128_from_jni);
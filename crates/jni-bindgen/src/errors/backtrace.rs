@@ -0,0 +1,161 @@
+use crate::cache::CachedClass;
+use jni::objects::{JObject, JThrowable, JValue};
+use jni::JNIEnv;
+use std::backtrace::Backtrace;
+
+static NATIVE_BACKTRACE: CachedClass =
+    CachedClass::new("com/github/markusjx/jnibindgen/NativeBacktrace");
+
+/// A frame of a rust backtrace.
+struct Frame {
+    class: String,
+    method: String,
+    file: Option<String>,
+    line: i32,
+}
+
+/// Split a symbol into its path and the function name,
+/// ignoring any `::` inside of generic arguments.
+fn split_symbol(symbol: &str) -> (&str, &str) {
+    let mut depth = 0;
+    let mut split = None;
+    let bytes = symbol.as_bytes();
+
+    for (i, c) in bytes.iter().enumerate() {
+        match c {
+            b'<' => depth += 1,
+            b'>' => depth -= 1,
+            // Skip generic arguments of a function, e.g. `foo::<T>`
+            b':' if depth == 0
+                && bytes.get(i + 1) == Some(&b':')
+                && bytes.get(i + 2) != Some(&b'<') =>
+            {
+                split = Some(i);
+            }
+            _ => {}
+        }
+    }
+
+    match split {
+        Some(i) => (&symbol[..i], &symbol[i + 2..]),
+        None => ("", symbol),
+    }
+}
+
+/// Whether the given symbol creates or converts the error.
+fn is_conversion(symbol: &str) -> bool {
+    symbol.contains("jni_bindgen::errors::")
+        || symbol.contains("IntoJNIResult")
+        || symbol.starts_with("<T as core::convert::")
+        || symbol.starts_with("core::result::Result<")
+}
+
+/// Parse the frames of a backtrace from its `Display` representation,
+/// as the frames of a backtrace are not accessible on stable rust.
+/// The frames creating the error are skipped, and all frames
+/// after the JNI function which has been called from java.
+fn parse_frames(backtrace: &Backtrace) -> Vec<Frame> {
+    let mut frames = Vec::<Frame>::new();
+    for line in backtrace.to_string().lines() {
+        let line = line.trim();
+        if let Some(location) = line.strip_prefix("at ") {
+            if let Some(frame) = frames.last_mut() {
+                let mut parts = location.rsplitn(3, ':');
+                let _column = parts.next();
+                frame.line = parts.next().and_then(|l| l.parse().ok()).unwrap_or(-1);
+                frame.file = parts
+                    .next()
+                    .map(|f| f.rsplit(['/', '\\']).next().unwrap_or(f).to_string());
+            }
+
+            continue;
+        }
+
+        let Some((_, symbol)) = line.split_once(": ") else {
+            continue;
+        };
+
+        if frames.last().is_some_and(|f| f.method.starts_with("Java_")) {
+            break;
+        }
+
+        if frames.is_empty() && is_conversion(symbol) {
+            continue;
+        }
+
+        let (class, method) = split_symbol(symbol);
+        frames.push(Frame {
+            class: class.to_string(),
+            method: method.to_string(),
+            file: None,
+            line: -1,
+        });
+    }
+
+    frames
+}
+
+/// Add the given backtrace to an exception as a suppressed
+/// `com.github.markusjx.jnibindgen.NativeBacktrace` whose
+/// stack trace contains the frames of the backtrace.
+pub(crate) fn add_backtrace(
+    env: &mut JNIEnv,
+    exception: &JThrowable,
+    backtrace: &Backtrace,
+) -> crate::Result<()> {
+    let frames = parse_frames(backtrace);
+    if frames.is_empty() {
+        return Ok(());
+    }
+
+    let elements = env.new_object_array(
+        frames.len() as i32,
+        "java/lang/StackTraceElement",
+        JObject::null(),
+    )?;
+
+    for (i, frame) in frames.into_iter().enumerate() {
+        let class = env.new_string(frame.class)?;
+        let method = env.new_string(frame.method)?;
+        let file = match frame.file {
+            Some(file) => JObject::from(env.new_string(file)?),
+            None => JObject::null(),
+        };
+
+        let element = env.new_object(
+            "java/lang/StackTraceElement",
+            "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;I)V",
+            &[
+                JValue::Object(&class),
+                JValue::Object(&method),
+                JValue::Object(&file),
+                JValue::Int(frame.line),
+            ],
+        )?;
+        env.set_object_array_element(&elements, i as i32, &element)?;
+
+        env.delete_local_ref(element)?;
+        env.delete_local_ref(file)?;
+        env.delete_local_ref(method)?;
+        env.delete_local_ref(class)?;
+    }
+
+    let message = env.new_string("Rust backtrace")?;
+    let class = NATIVE_BACKTRACE.get(env)?;
+    let native = env.new_object(class, "(Ljava/lang/String;)V", &[JValue::Object(&message)])?;
+    env.call_method(
+        &native,
+        "setStackTrace",
+        "([Ljava/lang/StackTraceElement;)V",
+        &[JValue::Object(&elements)],
+    )?;
+
+    env.call_method(
+        exception,
+        "addSuppressed",
+        "(Ljava/lang/Throwable;)V",
+        &[JValue::Object(&native)],
+    )?;
+
+    Ok(())
+}
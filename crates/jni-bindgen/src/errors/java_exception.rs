@@ -0,0 +1,156 @@
+use crate::objects::traits::IntoJNIResult;
+use jni::objects::{GlobalRef, JObject, JString, JThrowable, JValue};
+use jni::JNIEnv;
+use std::fmt::{Debug, Formatter};
+
+/// A java exception caught while calling into java code.
+///
+/// The exception is cleared once caught. A global reference to
+/// the throwable is kept, so it can be rethrown as-is if the error
+/// is passed back to java. Use [`crate::errors::jni_error::JNIError::java_exception`]
+/// to retrieve the exception from an error.
+#[derive(Clone)]
+pub struct JavaException {
+    /// The fully qualified class name of the exception,
+    /// e.g. `java.lang.IllegalStateException`.
+    pub class_name: String,
+    /// The message of the exception, if any.
+    pub message: Option<String>,
+    /// The stack trace of the exception, as printed by `printStackTrace`.
+    pub stack_trace: String,
+    /// A global reference to the throwable.
+    pub throwable: GlobalRef,
+}
+
+impl JavaException {
+    /// Catch the exception currently pending in the given environment.
+    /// The exception is cleared if it could be caught.
+    ///
+    /// # Arguments
+    /// * `env` - The JNI environment.
+    ///
+    /// # Returns
+    /// The caught exception or `None` if no exception is pending.
+    pub fn catch(env: &mut JNIEnv) -> crate::Result<Option<Self>> {
+        if !env.exception_check()? {
+            return Ok(None);
+        }
+
+        let throwable = env.exception_occurred()?;
+        env.exception_clear()?;
+
+        Self::from_throwable(env, &throwable).map(Some)
+    }
+
+    /// Create a new exception from the given throwable.
+    ///
+    /// # Arguments
+    /// * `env` - The JNI environment.
+    /// * `throwable` - The throwable.
+    ///
+    /// # Returns
+    /// The exception.
+    pub fn from_throwable(env: &mut JNIEnv, throwable: &JThrowable) -> crate::Result<Self> {
+        let res = Self::read_details(env, throwable);
+        if res.is_err() {
+            let _ = env.exception_clear();
+        }
+
+        let (class_name, message, stack_trace) = res?;
+        Ok(Self {
+            class_name,
+            message,
+            stack_trace,
+            throwable: env.new_global_ref(throwable)?,
+        })
+    }
+
+    fn read_details(
+        env: &mut JNIEnv,
+        throwable: &JThrowable,
+    ) -> crate::Result<(String, Option<String>, String)> {
+        let class = env.get_object_class(throwable)?;
+        let class_name = env
+            .call_method(&class, "getName", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let class_name = get_string(env, class_name)?.unwrap_or_default();
+
+        let message = env
+            .call_method(throwable, "getMessage", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let message = get_string(env, message)?;
+
+        let writer = env.new_object("java/io/StringWriter", "()V", &[])?;
+        let print_writer = env.new_object(
+            "java/io/PrintWriter",
+            "(Ljava/io/Writer;)V",
+            &[JValue::Object(&writer)],
+        )?;
+        env.call_method(
+            throwable,
+            "printStackTrace",
+            "(Ljava/io/PrintWriter;)V",
+            &[JValue::Object(&print_writer)],
+        )?;
+        let stack_trace = env
+            .call_method(&writer, "toString", "()Ljava/lang/String;", &[])?
+            .l()?;
+        let stack_trace = get_string(env, stack_trace)?.unwrap_or_default();
+
+        Ok((class_name, message, stack_trace))
+    }
+
+    /// Check whether the exception is an instance of the given class.
+    ///
+    /// # Arguments
+    /// * `env` - The JNI environment.
+    /// * `class` - The class name, in the format `java.lang.String`
+    ///   or `java/lang/String`.
+    pub fn is_instance_of(&self, env: &mut JNIEnv, class: &str) -> crate::Result<bool> {
+        env.is_instance_of(&self.throwable, class.replace('.', "/"))
+            .into_jni_result()
+    }
+
+    /// Throw the original exception in java.
+    pub fn rethrow(&self, env: &mut JNIEnv) -> crate::Result<()> {
+        let throwable: &JThrowable = self.throwable.as_obj().into();
+        env.throw(throwable).into_jni_result()
+    }
+
+    /// Get a new local reference to the original throwable.
+    pub fn as_local<'a>(&self, env: &mut JNIEnv<'a>) -> crate::Result<JThrowable<'a>> {
+        Ok(JThrowable::from(env.new_local_ref(&self.throwable)?))
+    }
+
+    /// The error message to use for this exception.
+    /// This is the message of the exception or the class name
+    /// if the exception has no message.
+    pub fn description(&self) -> String {
+        match &self.message {
+            Some(message) => format!("{}: {}", self.class_name, message),
+            None => self.class_name.clone(),
+        }
+    }
+}
+
+fn get_string(env: &mut JNIEnv, obj: JObject) -> crate::Result<Option<String>> {
+    if obj.is_null() {
+        return Ok(None);
+    }
+
+    let string = JString::from(obj);
+    let res = env.get_string(&string)?.into();
+    env.delete_local_ref(string)?;
+
+    Ok(Some(res))
+}
+
+impl Debug for JavaException {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JavaException")
+            .field("class_name", &self.class_name)
+            .field("message", &self.message)
+            .field("stack_trace", &self.stack_trace)
+            .finish_non_exhaustive()
+    }
+}
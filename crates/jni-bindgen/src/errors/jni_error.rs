@@ -1,7 +1,10 @@
+use crate::errors::java_exception::JavaException;
 use jni::errors::Error as JNIErrorEnum;
+use jni::objects::{JThrowable, JValue};
 use jni::JNIEnv;
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
+use std::sync::{Arc, RwLock};
 use strum_macros::Display;
 
 /// The (java) class of an error.
@@ -17,6 +20,8 @@ pub enum ErrorClass {
     IllegalState,
     #[strum(serialize = "java/lang/UnsupportedOperationException")]
     UnsupportedOperation,
+    #[strum(serialize = "java/lang/ArithmeticException")]
+    Arithmetic,
     #[default]
     #[strum(serialize = "java/lang/RuntimeException")]
     Runtime,
@@ -28,6 +33,15 @@ pub enum ErrorClass {
     NoSuchField,
     #[strum(serialize = "com/github/markusjx/jnibindgen/NativeExecutionException")]
     NativeExecutionException,
+    #[strum(serialize = "com/github/markusjx/jnibindgen/NativePanicException")]
+    NativePanicException,
+    /// An exception thrown by java code.
+    /// The original exception is rethrown if this error is thrown in java.
+    Java(Box<JavaException>),
+    /// An error which creates its own exception object, e.g. an error
+    /// struct annotated with `#[jni(error)]` whose fields are copied
+    /// into the fields of the generated exception class.
+    Throwable(Arc<dyn ThrowableError>),
     /// Any other class.
     /// This is used to throw a custom exception.
     /// The string is the class name.
@@ -53,22 +67,96 @@ impl From<JNIErrorEnum> for ErrorClass {
 impl ErrorClass {
     /// Converts the class name into its JNI representation.
     pub fn into_class_name(self) -> String {
-        match self {
+        let name = match self {
+            ErrorClass::Java(e) => return e.class_name.replace('.', "/"),
             ErrorClass::Any(s) => s.replace('.', "/"),
+            ErrorClass::Throwable(e) => e.class_name().replace('.', "/"),
             e => e.to_string(),
-        }
+        };
+
+        crate::natives::class_name(&name).into_owned()
     }
 }
 
+/// An error which is converted into an instance of a java exception
+/// class by itself, instead of calling a constructor taking the message.
+/// This is implemented for error structs annotated with `#[jni(error)]`.
+pub trait ThrowableError: Debug + Send + Sync {
+    /// The class name of the exception,
+    /// in the format `java/lang/String`.
+    fn class_name(&self) -> &'static str;
+
+    /// Create the exception object.
+    ///
+    /// # Arguments
+    /// * `env` - The JNI environment.
+    /// * `message` - The message of the exception.
+    fn to_throwable<'a>(
+        &self,
+        env: &mut JNIEnv<'a>,
+        message: &str,
+    ) -> crate::Result<JThrowable<'a>>;
+}
+
+type ClassMapper = Box<dyn Fn(&(dyn Error + 'static)) -> Option<ErrorClass> + Send + Sync>;
+
+static ERROR_CLASSES: RwLock<Vec<ClassMapper>> = RwLock::new(Vec::new());
+
+/// Register the java exception class to use for errors of type `E`.
+/// This class is used if an error of type `E` is found in the error
+/// chain of an error converted using [`JNIError::from_error`],
+/// e.g. an [`anyhow::Error`] returned by a method.
+///
+/// # Example
+/// ```
+/// use jni_bindgen::errors::jni_error::{register_error_class, ErrorClass};
+///
+/// register_error_class::<std::num::ParseIntError>(ErrorClass::Any(
+///     "java.lang.NumberFormatException".to_string(),
+/// ));
+/// ```
+pub fn register_error_class<E: Error + 'static>(class: ErrorClass) {
+    ERROR_CLASSES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(Box::new(move |e| e.is::<E>().then(|| class.clone())));
+}
+
+fn registered_class(error: &(dyn Error + 'static)) -> Option<ErrorClass> {
+    ERROR_CLASSES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .find_map(|f| f(error))
+}
+
 /// An error that can be thrown in java.
 /// This is used to throw exceptions from rust.
 /// If the class is not known, the default is `java.lang.RuntimeException`.
-#[derive(Debug)]
+/// If the error has a cause, the cause is thrown as the cause of the
+/// java exception, so the whole error chain shows up in stack traces.
+#[derive(Debug, Clone)]
 pub struct JNIError {
     /// The error message.
     pub message: String,
     /// The class of the error.
     pub class: Option<ErrorClass>,
+    /// The error which caused this error.
+    pub cause: Option<Box<JNIError>>,
+    /// The backtrace of the location the error has been created at.
+    #[cfg(feature = "backtrace")]
+    pub backtrace: Option<Arc<std::backtrace::Backtrace>>,
+}
+
+/// Capture a backtrace if backtraces are enabled using
+/// the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` variables.
+#[cfg(feature = "backtrace")]
+fn capture_backtrace() -> Option<Arc<std::backtrace::Backtrace>> {
+    let backtrace = std::backtrace::Backtrace::capture();
+    match backtrace.status() {
+        std::backtrace::BacktraceStatus::Captured => Some(Arc::new(backtrace)),
+        _ => None,
+    }
 }
 
 impl JNIError {
@@ -105,9 +193,66 @@ impl JNIError {
         JNIError {
             message: message.to_string(),
             class,
+            cause: None,
+            #[cfg(feature = "backtrace")]
+            backtrace: capture_backtrace(),
+        }
+    }
+
+    /// Create a new JNI error from an error and its chain of sources.
+    /// Every error in the chain is converted into a separate error with
+    /// its own message, which is set as the cause of the previous error.
+    /// The class of each error is chosen using the classes registered
+    /// with [`register_error_class`], defaulting to `java.lang.RuntimeException`.
+    ///
+    /// # Example
+    /// ```
+    /// use jni_bindgen::errors::jni_error::JNIError;
+    ///
+    /// let error = "abc".parse::<i32>().unwrap_err();
+    /// let error = JNIError::from_error(&error);
+    /// assert_eq!(error.message, "invalid digit found in string");
+    /// ```
+    pub fn from_error(error: &(dyn Error + 'static)) -> Self {
+        if let Some(error) = error.downcast_ref::<JNIError>() {
+            return error.clone();
+        }
+
+        let cause = error.source().map(|e| {
+            #[allow(unused_mut)]
+            let mut cause = Self::from_error(e);
+            // The causes have been captured at the same location
+            #[cfg(feature = "backtrace")]
+            {
+                cause.backtrace = None;
+            }
+
+            Box::new(cause)
+        });
+
+        JNIError {
+            message: error.to_string(),
+            class: registered_class(error),
+            cause,
+            #[cfg(feature = "backtrace")]
+            backtrace: capture_backtrace(),
         }
     }
 
+    /// Set the cause of this error.
+    ///
+    /// # Example
+    /// ```
+    /// use jni_bindgen::error;
+    ///
+    /// let error = error!("Failed to load the config").with_cause(error!("File not found"));
+    /// assert_eq!(error.cause.unwrap().message, "File not found");
+    /// ```
+    pub fn with_cause<T: Into<JNIError>>(mut self, cause: T) -> Self {
+        self.cause = Some(Box::new(cause.into()));
+        self
+    }
+
     /// Create a new JNI error with the given message.
     /// The class of the error is `java.lang.RuntimeException`.
     /// This is a shortcut for `JNIError::new(message, None)`.
@@ -115,6 +260,50 @@ impl JNIError {
         JNIError::new(message, None)
     }
 
+    /// Create a new JNI error from an error returned by the jni crate.
+    /// If the error was caused by a java exception, the exception
+    /// is caught and cleared and the returned error holds the
+    /// original exception. See [`JNIError::java_exception`].
+    ///
+    /// # Arguments
+    /// * `env` - The JNI environment the error occurred in.
+    /// * `error` - The error.
+    ///
+    /// # Returns
+    /// The new JNI error.
+    ///
+    /// # Example
+    /// ```
+    /// use jni::objects::JObject;
+    /// use jni::JNIEnv;
+    /// use jni_bindgen::errors::jni_error::JNIError;
+    ///
+    /// fn call_run(env: &mut JNIEnv, obj: &JObject) -> jni_bindgen::Result<()> {
+    ///     env.call_method(obj, "run", "()V", &[])
+    ///         .map_err(|e| JNIError::from_jni_error(env, e))?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_jni_error(env: &mut JNIEnv, error: JNIErrorEnum) -> Self {
+        if !matches!(error, JNIErrorEnum::JavaException) {
+            return error.into();
+        }
+
+        match JavaException::catch(env) {
+            Ok(Some(exception)) => exception.into(),
+            Ok(None) => error.into(),
+            Err(e) => e,
+        }
+    }
+
+    /// Get the java exception which caused this error, if any.
+    pub fn java_exception(&self) -> Option<&JavaException> {
+        match &self.class {
+            Some(ErrorClass::Java(e)) => Some(e),
+            _ => None,
+        }
+    }
+
     /// Throw the error in java.
     /// This will throw the error as an exception in java.
     ///
@@ -144,10 +333,60 @@ impl JNIError {
     /// }
     /// ```
     pub fn throw(self, env: &mut JNIEnv) {
-        let _ = env.throw_new(
-            self.class.unwrap_or_default().into_class_name(),
-            self.message,
-        );
+        match self.into_exception(env) {
+            Ok(exception) => {
+                let _ = env.throw(exception);
+            }
+            Err(e) => {
+                // If the exception could not be created because of a java
+                // exception, e.g. as the class does not exist, throw that one
+                if !env.exception_check().unwrap_or(true) {
+                    let _ = env.throw_new(ErrorClass::Runtime.into_class_name(), e.message);
+                }
+            }
+        }
+    }
+
+    /// Create a java exception from this error without throwing it.
+    /// This is used to pass an error to java code, e.g. in order to
+    /// complete a `CompletableFuture` exceptionally.
+    pub fn into_exception<'a>(self, env: &mut JNIEnv<'a>) -> crate::Result<JThrowable<'a>> {
+        let exception = match self.class {
+            // Java exceptions are rethrown as-is including their own causes
+            Some(ErrorClass::Java(e)) => return e.as_local(env),
+            Some(ErrorClass::Throwable(e)) => e.to_throwable(env, &self.message)?,
+            class => {
+                let message = env.new_string(self.message)?;
+                let class =
+                    crate::cache::find_class(env, &class.unwrap_or_default().into_class_name())?;
+                let exception =
+                    env.new_object(&class, "(Ljava/lang/String;)V", &[JValue::Object(&message)])?;
+
+                JThrowable::from(exception)
+            }
+        };
+
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = &self.backtrace {
+            crate::errors::backtrace::add_backtrace(env, &exception, backtrace)?;
+        }
+
+        if let Some(cause) = self.cause {
+            let cause = cause.into_exception(env)?;
+            let res = env.call_method(
+                &exception,
+                "initCause",
+                "(Ljava/lang/Throwable;)Ljava/lang/Throwable;",
+                &[JValue::Object(&cause)],
+            );
+
+            // The constructor may have already set a cause
+            if res.is_err() {
+                env.exception_clear()?;
+            }
+        }
+
+        Ok(exception)
     }
 
     /// Set the class of the error if it is not already set.
@@ -166,11 +405,21 @@ impl Display for JNIError {
     }
 }
 
-impl Error for JNIError {}
+impl Error for JNIError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.cause.as_deref().map(|e| e as _)
+    }
+}
 
 impl From<anyhow::Error> for JNIError {
     fn from(value: anyhow::Error) -> Self {
-        JNIError::new(value, None)
+        JNIError::from_error(value.as_ref())
+    }
+}
+
+impl From<Box<dyn Error + Send + Sync>> for JNIError {
+    fn from(value: Box<dyn Error + Send + Sync>) -> Self {
+        JNIError::from_error(value.as_ref())
     }
 }
 
@@ -180,6 +429,12 @@ impl From<JNIErrorEnum> for JNIError {
     }
 }
 
+impl From<JavaException> for JNIError {
+    fn from(value: JavaException) -> Self {
+        JNIError::new(value.description(), Some(ErrorClass::Java(Box::new(value))))
+    }
+}
+
 impl From<String> for JNIError {
     fn from(value: String) -> Self {
         JNIError::new(value, None)
@@ -1,2 +1,6 @@
+#[cfg(feature = "backtrace")]
+mod backtrace;
+pub mod java_exception;
 pub mod jni_error;
+pub mod panic;
 pub mod result;
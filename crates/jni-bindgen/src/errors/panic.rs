@@ -0,0 +1,72 @@
+use crate::errors::jni_error::{ErrorClass, JNIError};
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
+use std::sync::Once;
+
+thread_local! {
+    /// The location of the last panic on this thread.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Install a panic hook which records the location of a panic
+/// before calling the previously installed hook.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let prev = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(|l| l.to_string());
+            PANIC_LOCATION.with(|l| *l.borrow_mut() = location);
+            prev(info);
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Run the given closure and catch any panic which occurs.
+/// This is used by the generated JNI methods in order to prevent
+/// a panic from unwinding across the FFI boundary, which would
+/// abort the JVM.
+///
+/// If the closure panics, the panic is converted into a [`JNIError`]
+/// of class `com.github.markusjx.jnibindgen.NativePanicException`.
+/// The error message contains the panic message and the location
+/// the panic occurred at, if known.
+///
+/// # Arguments
+/// * `f` - The closure to run.
+///
+/// # Returns
+/// The result of the closure or the converted panic.
+///
+/// # Example
+/// ```
+/// use jni_bindgen::errors::panic::catch_unwind;
+///
+/// let res = catch_unwind(|| -> i32 { panic!("Something went wrong") });
+/// assert!(res.unwrap_err().message.contains("Something went wrong"));
+/// ```
+pub fn catch_unwind<R, F: FnOnce() -> R>(f: F) -> crate::Result<R> {
+    install_hook();
+
+    std::panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = panic_message(payload.as_ref());
+        let message = match PANIC_LOCATION.with(|l| l.borrow_mut().take()) {
+            Some(location) => format!("Rust panicked at {location}: {message}"),
+            None => format!("Rust panicked: {message}"),
+        };
+
+        JNIError::new(message, Some(ErrorClass::NativePanicException))
+    })
+}
@@ -0,0 +1,213 @@
+use crate::bail_class;
+use crate::errors::jni_error::{ErrorClass, JNIError};
+use crate::errors::panic::catch_unwind;
+use jni::objects::{GlobalRef, JObject, JThrowable, JValue};
+use jni::{JNIEnv, JavaVM};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::Thread;
+
+/// A boxed future which can be spawned on an [`Executor`].
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Converts the output of a future into a java object.
+/// This is generated for every `async` method.
+pub type Converter<T> = for<'a> fn(&mut JNIEnv<'a>, T) -> crate::Result<JObject<'a>>;
+
+/// An executor driving the futures returned by `async` methods.
+///
+/// Any closure taking a [`BoxFuture`] is an executor. If the `tokio`
+/// feature is enabled, a `tokio::runtime::Handle` may be used as well.
+///
+/// # Example
+/// ```
+/// use jni_bindgen::future::{set_executor, BoxFuture};
+///
+/// set_executor(|future: BoxFuture| {
+///     std::thread::spawn(move || jni_bindgen::future::block_on(future));
+/// })
+/// .unwrap();
+/// ```
+pub trait Executor: Send + Sync + 'static {
+    /// Spawn the given future. The future must be polled to completion.
+    fn spawn(&self, future: BoxFuture);
+}
+
+impl<F: Fn(BoxFuture) + Send + Sync + 'static> Executor for F {
+    fn spawn(&self, future: BoxFuture) {
+        self(future)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl Executor for tokio::runtime::Handle {
+    fn spawn(&self, future: BoxFuture) {
+        drop(tokio::runtime::Handle::spawn(self, future));
+    }
+}
+
+/// The default executor, running every future on a new thread.
+struct ThreadExecutor;
+
+impl Executor for ThreadExecutor {
+    fn spawn(&self, future: BoxFuture) {
+        std::thread::Builder::new()
+            .name("jni-bindgen-future".to_string())
+            .spawn(move || block_on(future))
+            .expect("Failed to spawn a thread");
+    }
+}
+
+static EXECUTOR: OnceLock<Box<dyn Executor>> = OnceLock::new();
+
+/// Set the executor used to drive the futures returned by `async` methods.
+/// This must be called before the first `async` method is called, otherwise
+/// every future will be run on a new thread.
+///
+/// # Arguments
+/// * `executor` - The executor to use.
+///
+/// # Returns
+/// An error if an executor has already been set or a future has already
+/// been spawned using the default executor.
+pub fn set_executor<E: Executor>(executor: E) -> crate::Result<()> {
+    if EXECUTOR.set(Box::new(executor)).is_err() {
+        bail_class!(
+            ErrorClass::IllegalState,
+            "The executor has already been set"
+        );
+    }
+
+    Ok(())
+}
+
+fn executor() -> &'static dyn Executor {
+    EXECUTOR.get_or_init(|| Box::new(ThreadExecutor)).as_ref()
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run a future to completion on the current thread.
+///
+/// # Example
+/// ```
+/// use jni_bindgen::future::block_on;
+///
+/// assert_eq!(block_on(async { 1 + 2 }), 3);
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(res) => return res,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+/// Converts a panic while polling the inner future into an error.
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<T, F: Future<Output = crate::Result<T>>> Future for CatchUnwind<F> {
+    type Output = crate::Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match catch_unwind(|| self.0.as_mut().poll(cx)) {
+            Ok(poll) => poll,
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+fn into_exception<'a>(env: &mut JNIEnv<'a>, error: JNIError) -> crate::Result<JThrowable<'a>> {
+    if env.exception_check()? {
+        let exception = env.exception_occurred()?;
+        env.exception_clear()?;
+        return Ok(exception);
+    }
+
+    error.into_exception(env)
+}
+
+fn complete<T>(vm: &JavaVM, future: &GlobalRef, res: crate::Result<T>, convert: Converter<T>) {
+    // Worker threads stay attached, as they are usually reused by the executor
+    let Ok(mut env) = vm.attach_current_thread_as_daemon() else {
+        return;
+    };
+
+    let res = env.with_local_frame(8, |env| -> crate::Result<()> {
+        match res.and_then(|res| catch_unwind(|| convert(env, res))?) {
+            Ok(obj) => {
+                env.call_method(
+                    future,
+                    "complete",
+                    "(Ljava/lang/Object;)Z",
+                    &[JValue::Object(&obj)],
+                )?;
+            }
+            Err(e) => {
+                let exception = into_exception(env, e)?;
+                env.call_method(
+                    future,
+                    "completeExceptionally",
+                    "(Ljava/lang/Throwable;)Z",
+                    &[JValue::Object(&exception)],
+                )?;
+            }
+        }
+
+        Ok(())
+    });
+
+    if res.is_err() && env.exception_check().unwrap_or_default() {
+        let _ = env.exception_describe();
+        let _ = env.exception_clear();
+    }
+}
+
+/// Spawn a future on the configured [`Executor`] and return a
+/// `java.util.concurrent.CompletableFuture` completed with its output.
+/// The future is completed from the worker thread, which is attached
+/// to the JVM for this purpose. If the future returns an error or panics,
+/// the java future is completed exceptionally.
+///
+/// This is used by the generated code for `async` methods.
+///
+/// # Arguments
+/// * `env` - The JNI environment.
+/// * `future` - The future to spawn.
+/// * `convert` - Converts the output of the future into a java object.
+///
+/// # Returns
+/// The `CompletableFuture`.
+pub fn spawn<'local, T: Send + 'static, F>(
+    env: &mut JNIEnv<'local>,
+    future: F,
+    convert: Converter<T>,
+) -> crate::Result<JObject<'local>>
+where
+    F: Future<Output = crate::Result<T>> + Send + 'static,
+{
+    let completable = env.new_object("java/util/concurrent/CompletableFuture", "()V", &[])?;
+    let global = env.new_global_ref(&completable)?;
+    let vm = env.get_java_vm()?;
+
+    let future = CatchUnwind(Box::pin(future));
+    executor().spawn(Box::pin(async move {
+        let res = future.await;
+        complete(&vm, &global, res, convert);
+    }));
+
+    Ok(completable)
+}
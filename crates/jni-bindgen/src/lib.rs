@@ -1,6 +1,11 @@
+pub mod cache;
 pub mod conversion;
 pub mod errors;
+pub mod future;
+pub mod natives;
 pub mod objects;
+pub mod vm;
 
 pub use errors::result::Result;
-pub use jni_bindgen_macros::jni;
+pub use jni_bindgen_macros::{jni, JniError};
+pub use vm::vm;
@@ -0,0 +1,236 @@
+//! Registration of native methods using `RegisterNatives`.
+//!
+//! By default, the JVM finds the native methods of the generated classes
+//! by looking up exported functions named after the java class and method.
+//! If the classes are generated using `jni_bindgen_build::Builder` with
+//! `register_natives` enabled, the functions are not exported. Instead, a
+//! `JNI_OnLoad` function is generated, registering all native methods once
+//! the library is loaded. This allows the java classes to be renamed at
+//! load time using [`set_class_names`], e.g. if they are obfuscated.
+use crate::errors::jni_error::JNIError;
+use jni::objects::JClass;
+use jni::{JNIEnv, NativeMethod};
+use std::borrow::Cow;
+use std::ffi::c_void;
+use std::sync::OnceLock;
+
+/// A function mapping the binary name of a generated class,
+/// e.g. `com/example/Foo$FooNative`, to the name it is loaded with.
+pub type ClassNames = fn(&str) -> String;
+
+static CLASS_NAMES: OnceLock<ClassNames> = OnceLock::new();
+
+/// Set the function mapping the names of the generated classes to the
+/// names they are loaded with. This must be called before any native
+/// method is registered or any class is looked up, e.g. in `JNI_OnLoad`,
+/// and may only be called once.
+///
+/// # Example
+/// ```
+/// jni_bindgen::natives::set_class_names(|name| name.replace("com/example/", "a/"));
+/// assert_eq!(jni_bindgen::natives::class_name("com/example/Foo"), "a/Foo");
+/// ```
+pub fn set_class_names(class_names: ClassNames) -> crate::Result<()> {
+    CLASS_NAMES
+        .set(class_names)
+        .map_err(|_| crate::error!("The class names have already been set"))
+}
+
+/// Get the name the class with the given binary name is loaded with.
+pub fn class_name(name: &str) -> Cow<'_, str> {
+    match CLASS_NAMES.get() {
+        Some(class_names) => Cow::Owned(class_names(name)),
+        None => Cow::Borrowed(name),
+    }
+}
+
+/// Rename the classes in the given JNI descriptor, e.g.
+/// `(Lcom/example/Foo;)V`, using [`class_name`].
+///
+/// # Example
+/// ```
+/// use jni_bindgen::natives::{descriptor, set_class_names};
+///
+/// set_class_names(|name| name.replace("com/example/", "a/")).unwrap();
+/// assert_eq!(
+///     descriptor("(ILcom/example/Foo;[Ljava/lang/String;)Lcom/example/Bar;"),
+///     "(ILa/Foo;[Ljava/lang/String;)La/Bar;"
+/// );
+/// ```
+pub fn descriptor(sig: &str) -> Cow<'_, str> {
+    if CLASS_NAMES.get().is_none() || !sig.contains('L') {
+        return Cow::Borrowed(sig);
+    }
+
+    let mut res = String::with_capacity(sig.len());
+    let mut rest = sig;
+    while let Some(start) = rest.find('L') {
+        let Some(end) = rest[start..].find(';') else {
+            break;
+        };
+
+        res.push_str(&rest[..=start]);
+        res.push_str(&class_name(&rest[start + 1..start + end]));
+        res.push(';');
+        rest = &rest[start + end + 1..];
+    }
+
+    res.push_str(rest);
+    Cow::Owned(res)
+}
+
+/// Concatenate string constants into a `&'static str` at compile time.
+/// Used by the generated code to build the JNI descriptors of methods
+/// taking types whose class name is only known as a constant, e.g.
+/// `<Point as ClassName>::CLASS_NAME`.
+///
+/// # Example
+/// ```
+/// const SIG: &str = jni_bindgen::const_concat!("(L", "com/example/Point", ";)V");
+/// assert_eq!(SIG, "(Lcom/example/Point;)V");
+/// ```
+#[macro_export]
+macro_rules! const_concat {
+    ($($part: expr),* $(,)?) => {{
+        const PARTS: &[&str] = &[$($part),*];
+        const LEN: usize = $crate::natives::concat_len(PARTS);
+        const BYTES: [u8; LEN] = $crate::natives::concat_bytes(PARTS);
+        match std::str::from_utf8(&BYTES) {
+            Ok(res) => res,
+            Err(_) => panic!("The concatenated string is not valid UTF-8"),
+        }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn concat_len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+
+    len
+}
+
+#[doc(hidden)]
+pub const fn concat_bytes<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut res = [0; N];
+    let mut pos = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let bytes = parts[i].as_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            res[pos] = bytes[j];
+            pos += 1;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    res
+}
+
+/// The native methods of a generated class.
+pub struct Natives {
+    class_name: &'static str,
+    methods: Vec<(String, String, *mut c_void)>,
+}
+
+impl Natives {
+    /// Create an empty list of the native methods
+    /// of the class with the given binary name.
+    pub fn new(class_name: &'static str) -> Self {
+        Self {
+            class_name,
+            methods: Vec::new(),
+        }
+    }
+
+    /// Add a native method with the given name and JNI descriptor.
+    pub fn method(mut self, name: &str, sig: &str, fn_ptr: *mut c_void) -> Self {
+        self.methods.push((name.into(), sig.into(), fn_ptr));
+        self
+    }
+
+    /// The native methods, with the classes in their descriptors
+    /// renamed using the function set by [`set_class_names`].
+    fn native_methods(&self) -> Vec<NativeMethod> {
+        self.methods
+            .iter()
+            .map(|(name, sig, fn_ptr)| NativeMethod {
+                name: name.as_str().into(),
+                sig: descriptor(sig).as_ref().into(),
+                fn_ptr: *fn_ptr,
+            })
+            .collect()
+    }
+}
+
+/// Register the native methods of the given classes.
+/// The classes are renamed using the function set
+/// by [`set_class_names`], if any. Fails with the java
+/// exception thrown if a class or method does not exist.
+///
+/// If any class fails to register, the methods of the already registered
+/// classes are unregistered, as the library is unloaded by the JVM if
+/// `JNI_OnLoad` fails. This includes the class which failed to register,
+/// as `RegisterNatives` may have registered some of its methods already.
+pub fn register(env: &mut JNIEnv, natives: Vec<Natives>) -> crate::Result<()> {
+    let mut registered = Vec::new();
+    for natives in natives {
+        let res = env
+            .find_class(class_name(natives.class_name).as_ref())
+            .and_then(|class| env.new_global_ref(class))
+            .and_then(|class| {
+                let res = env.register_native_methods(
+                    <&JClass>::from(class.as_obj()),
+                    &natives.native_methods(),
+                );
+                registered.push(class);
+                res
+            });
+
+        if let Err(e) = res {
+            // Catch the exception before calling back into the JVM
+            let error = JNIError::from_jni_error(env, e);
+            for class in registered {
+                let _ = env.unregister_native_methods(<&JClass>::from(class.as_obj()));
+            }
+
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+/// The native methods of the classes of the jni-bindgen runtime.
+fn runtime_natives() -> Vec<Natives> {
+    vec![
+        Natives::new("com/github/markusjx/jnibindgen/DirectBufferDrop").method(
+            "drop",
+            "(J)V",
+            crate::objects::direct_buffer::drop_buffer as *mut c_void,
+        ),
+    ]
+}
+
+/// Set the class names and register the native methods of the given
+/// classes and of the jni-bindgen runtime. This is called by the
+/// generated `JNI_OnLoad` function.
+pub fn init(
+    env: &mut JNIEnv,
+    class_names: Option<ClassNames>,
+    mut natives: Vec<Natives>,
+) -> crate::Result<()> {
+    if let Some(class_names) = class_names {
+        set_class_names(class_names)?;
+    }
+
+    natives.extend(runtime_natives());
+    register(env, natives)
+}
@@ -0,0 +1,230 @@
+use crate::bail_class;
+use crate::cache::{CachedClass, CachedStaticMethod};
+use crate::errors::jni_error::ErrorClass;
+use crate::errors::panic::catch_unwind;
+use crate::objects::traits::{FromJNI, IntoJNI};
+use jni::objects::{JByteBuffer, JClass, JObject, JValue};
+use jni::sys::jlong;
+use jni::JNIEnv;
+use std::ops::{Deref, DerefMut};
+
+static DIRECT_BUFFER_DROP: CachedClass =
+    CachedClass::new("com/github/markusjx/jnibindgen/DirectBufferDrop");
+static DIRECT_BUFFER_DROP_REGISTER: CachedStaticMethod =
+    CachedStaticMethod::new(&DIRECT_BUFFER_DROP, "register", "(Ljava/nio/ByteBuffer;J)V");
+
+/// Rust owned memory backing a direct buffer returned to java.
+trait BufferOwner: Send {
+    fn bytes(&self) -> &[u8];
+
+    fn bytes_mut(&mut self) -> &mut [u8];
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]> + Send> BufferOwner for T {
+    fn bytes(&self) -> &[u8] {
+        self.as_ref()
+    }
+
+    fn bytes_mut(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+}
+
+enum Inner<'local> {
+    Java {
+        buffer: JByteBuffer<'local>,
+        ptr: *mut u8,
+        len: usize,
+    },
+    Owned(Box<dyn BufferOwner>),
+}
+
+/// A direct `java.nio.ByteBuffer`.
+/// The contents of the buffer are accessed without copying them,
+/// which makes this type suitable for passing large binary payloads
+/// between Rust and java.
+///
+/// A buffer received from java borrows the memory of the java buffer.
+/// The whole capacity of the buffer is accessible, the position and
+/// the limit of the buffer are ignored. Java code may access the
+/// memory concurrently, it is up to the caller to synchronize access.
+///
+/// A buffer created from Rust owned memory using [`DirectBuffer::from_owned`]
+/// or [`DirectBuffer::from_vec`] will be wrapped in a new direct buffer
+/// when passed to java. The memory is released once the java buffer
+/// has been garbage collected.
+///
+/// # Example
+/// ```
+/// use jni_bindgen::objects::direct_buffer::DirectBuffer;
+///
+/// let mut buffer = DirectBuffer::from_vec(vec![1, 2, 3]);
+/// buffer[0] = 4;
+///
+/// assert_eq!(buffer.as_slice(), &[4, 2, 3]);
+/// assert_eq!(buffer.len(), 3);
+/// ```
+pub struct DirectBuffer<'local> {
+    inner: Inner<'local>,
+}
+
+impl<'local> DirectBuffer<'local> {
+    /// Create a new buffer from Rust owned memory.
+    /// The memory will be dropped once the java buffer
+    /// has been garbage collected or, if the buffer is
+    /// never passed to java, when this buffer is dropped.
+    ///
+    /// # Arguments
+    /// * `data` - The memory to wrap.
+    pub fn from_owned<T: AsRef<[u8]> + AsMut<[u8]> + Send + 'static>(data: T) -> Self {
+        Self {
+            inner: Inner::Owned(Box::new(data)),
+        }
+    }
+
+    /// Create a new buffer from a [`Vec`].
+    /// This is a shortcut for [`DirectBuffer::from_owned`].
+    ///
+    /// # Arguments
+    /// * `data` - The data to wrap.
+    pub fn from_vec(data: Vec<u8>) -> Self {
+        Self::from_owned(data)
+    }
+
+    /// Get the contents of the buffer.
+    pub fn as_slice(&self) -> &[u8] {
+        match &self.inner {
+            Inner::Java { ptr, len, .. } => unsafe { std::slice::from_raw_parts(*ptr, *len) },
+            Inner::Owned(owner) => owner.bytes(),
+        }
+    }
+
+    /// Get the contents of the buffer mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        match &mut self.inner {
+            Inner::Java { ptr, len, .. } => unsafe { std::slice::from_raw_parts_mut(*ptr, *len) },
+            Inner::Owned(owner) => owner.bytes_mut(),
+        }
+    }
+
+    /// Get the length of the buffer in bytes.
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Check whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Deref for DirectBuffer<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for DirectBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<'local> FromJNI<'local> for DirectBuffer<'local> {
+    fn from_jni(env: &mut JNIEnv<'local>, obj: JObject<'local>) -> crate::Result<Self> {
+        if obj.is_null() {
+            bail_class!(ErrorClass::NullPointer, "The buffer is null");
+        }
+
+        if !env.call_method(&obj, "isDirect", "()Z", &[])?.z()? {
+            bail_class!(
+                ErrorClass::IllegalArgument,
+                "The buffer is not a direct buffer"
+            );
+        }
+
+        let buffer = JByteBuffer::from(obj);
+        let ptr = env.get_direct_buffer_address(&buffer)?;
+        let len = env.get_direct_buffer_capacity(&buffer)?;
+
+        Ok(Self {
+            inner: Inner::Java { buffer, ptr, len },
+        })
+    }
+}
+
+impl<'local> FromJNI<'local> for Option<DirectBuffer<'local>> {
+    fn from_jni(env: &mut JNIEnv<'local>, obj: JObject<'local>) -> crate::Result<Self> {
+        match obj.is_null() {
+            true => Ok(None),
+            false => Ok(Some(DirectBuffer::from_jni(env, obj)?)),
+        }
+    }
+}
+
+impl IntoJNI for DirectBuffer<'_> {
+    fn into_jni<'a>(self, env: &mut JNIEnv<'a>) -> crate::Result<JObject<'a>> {
+        match self.inner {
+            Inner::Java { buffer, .. } => Ok(unsafe { JObject::from_raw(buffer.into_raw()) }),
+            Inner::Owned(mut owner) => {
+                let bytes = owner.bytes_mut();
+                let (ptr, len) = (bytes.as_mut_ptr(), bytes.len());
+                let owner = Box::into_raw(Box::new(owner));
+
+                let buffer = match unsafe { env.new_direct_byte_buffer(ptr, len) } {
+                    Ok(buffer) => buffer,
+                    Err(e) => {
+                        drop(unsafe { Box::from_raw(owner) });
+                        return Err(e.into());
+                    }
+                };
+
+                // The owner is dropped by the cleaner once the buffer is garbage
+                // collected. If the registration fails, the buffer has not been
+                // handed out yet and the owner may be dropped right away.
+                let args = [
+                    JValue::from(&buffer).as_jni(),
+                    JValue::from(owner as jlong).as_jni(),
+                ];
+                // Safety: the arguments match the descriptor of the method
+                if let Err(e) = unsafe { DIRECT_BUFFER_DROP_REGISTER.call(env, &args) } {
+                    drop(unsafe { Box::from_raw(owner) });
+                    return Err(e.into());
+                }
+
+                Ok(JObject::from(buffer))
+            }
+        }
+    }
+}
+
+impl IntoJNI for Option<DirectBuffer<'_>> {
+    fn into_jni<'a>(self, env: &mut JNIEnv<'a>) -> crate::Result<JObject<'a>> {
+        match self {
+            Some(buffer) => buffer.into_jni(env),
+            None => Ok(JObject::null()),
+        }
+    }
+}
+
+/// Drop the Rust owned memory of a direct buffer.
+/// This is called by `com.github.markusjx.jnibindgen.DirectBufferDrop`
+/// once the java buffer has been garbage collected. The function is always
+/// exported, as another library loaded into the same JVM may not register
+/// its native methods. If it is registered by `JNI_OnLoad`, the registered
+/// function takes precedence.
+#[export_name = "Java_com_github_markusjx_jnibindgen_DirectBufferDrop_drop"]
+pub(crate) extern "system" fn drop_buffer(mut env: JNIEnv, _class: JClass, ptr: jlong) {
+    if ptr == 0 {
+        return;
+    }
+
+    // The owner may panic while being dropped
+    if let Err(e) =
+        catch_unwind(|| drop(unsafe { Box::from_raw(ptr as *mut Box<dyn BufferOwner>) }))
+    {
+        e.throw(&mut env);
+    }
+}
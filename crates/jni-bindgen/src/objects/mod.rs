@@ -1,2 +1,3 @@
+pub mod direct_buffer;
 pub mod traits;
 //pub mod wrapped;
@@ -1,3 +1,4 @@
+use crate::conversion::class_convert::NativeFields;
 use jni::objects::JObject;
 use jni::JNIEnv;
 
@@ -35,6 +36,26 @@ pub trait IntoJNI {
     fn into_jni<'a>(self, env: &mut JNIEnv<'a>) -> crate::Result<JObject<'a>>;
 }
 
+/// A Rust type converted to and from instances of a generated Java class,
+/// e.g. a struct, an enum or a data class annotated with `#[jni]`.
+/// This is implemented by the generated code. The JNI descriptors of
+/// methods taking the type by value use this name, as the macros do not
+/// know the package of types declared in other modules.
+pub trait ClassName {
+    /// The fully qualified name of the generated Java class,
+    /// using slashes as separators, e.g. `com/example/MyStruct`.
+    const CLASS_NAME: &'static str;
+}
+
+/// A Rust struct which has been exported to Java using `#[jni]`.
+/// This is implemented by the generated code and allows
+/// shared references to the struct to be wrapped in
+/// instances of the generated Java class.
+pub trait NativeClass: ClassName + 'static {
+    /// The cached class and field ids of the generated Java class.
+    fn native_fields() -> &'static NativeFields;
+}
+
 /// Convert any [`Result`] into a JNI result.
 pub trait IntoJNIResult<T> {
     fn into_jni_result(self) -> crate::Result<T>;
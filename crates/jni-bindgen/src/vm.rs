@@ -0,0 +1,81 @@
+//! The `JavaVM` the library has been loaded into.
+//!
+//! The virtual machine is stored by the generated `JNI_OnLoad` function,
+//! which is generated if a function is annotated with `#[jni(on_load)]`
+//! or the native methods are registered on load. Otherwise, it is
+//! stored the first time a generated native method is called.
+//!
+//! # Example
+//! ```
+//! use jni::{JNIEnv, JavaVM};
+//! use jni_bindgen::jni;
+//!
+//! #[jni(on_load)]
+//! fn init(vm: &JavaVM, env: &mut JNIEnv) -> jni_bindgen::Result<()> {
+//!     // Set up logging, a runtime, ...
+//!     Ok(())
+//! }
+//!
+//! #[jni(on_unload)]
+//! fn shutdown(vm: &JavaVM) {
+//!     // Clean up
+//! }
+//! ```
+use crate::errors::panic::catch_unwind;
+use jni::objects::JObject;
+use jni::sys::{jint, JNI_ERR, JNI_VERSION_1_8};
+use jni::{JNIEnv, JavaVM};
+use std::sync::OnceLock;
+
+static VM: OnceLock<JavaVM> = OnceLock::new();
+
+/// Get the `JavaVM` the library has been loaded into.
+/// Returns `None` if the library has not been loaded by a JVM
+/// or, without a generated `JNI_OnLoad` function, if no
+/// native method has been called yet.
+pub fn vm() -> Option<&'static JavaVM> {
+    VM.get()
+}
+
+/// Store the `JavaVM` of the given environment, if no `JavaVM`
+/// has been stored yet. This is called by every generated
+/// native method, so [`vm`] works without a `JNI_OnLoad` function.
+/// The class loader of the class or object the method has been
+/// called on is stored as well, see [`crate::cache::find_class`].
+#[doc(hidden)]
+pub fn store(env: &mut JNIEnv, receiver: &JObject) {
+    if VM.get().is_none() {
+        if let Ok(vm) = env.get_java_vm() {
+            let _ = VM.set(vm);
+        }
+    }
+
+    crate::cache::store_class_loader(env, receiver);
+}
+
+/// Store the `JavaVM` and run the given initialization, throwing any
+/// error from `System.loadLibrary`. This is called by the generated
+/// `JNI_OnLoad` function and returns the JNI version it must return.
+pub fn on_load<F>(vm: JavaVM, init: F) -> jint
+where
+    F: FnOnce(&JavaVM, &mut JNIEnv) -> crate::Result<()>,
+{
+    let vm = VM.get_or_init(|| vm);
+    let Ok(mut env) = vm.get_env() else {
+        return JNI_ERR;
+    };
+
+    if let Err(e) = catch_unwind(|| init(vm, &mut env)).and_then(|res| res) {
+        e.throw(&mut env);
+    }
+
+    JNI_VERSION_1_8
+}
+
+/// Run the given cleanup once the library is unloaded. This is
+/// called by the generated `JNI_OnUnload` function. Panics
+/// are caught, as there is no java code to throw them to.
+pub fn on_unload<F: FnOnce(&JavaVM)>(vm: JavaVM, cleanup: F) {
+    let vm = VM.get().unwrap_or(&vm);
+    let _ = catch_unwind(|| cleanup(vm));
+}
@@ -0,0 +1,88 @@
+package com.github.markusjx.example;
+
+import com.github.markusjx.generated.KeyNotFoundException;
+import com.github.markusjx.generated.KeyValueStore;
+import com.github.markusjx.generated.RateLimitException;
+import com.github.markusjx.generated.RateLimiter;
+import com.github.markusjx.jnibindgen.NativeExecutionException;
+import org.junit.jupiter.api.Assertions;
+import org.junit.jupiter.api.Test;
+
+public class TestErrors {
+    @Test
+    public void testPutGet() throws Exception {
+        KeyValueStore store = new KeyValueStore(2);
+        store.put("a", "b");
+
+        Assertions.assertEquals("b", store.get("a"));
+    }
+
+    @Test
+    public void testNotFound() {
+        KeyValueStore store = new KeyValueStore(2);
+
+        var message =
+                Assertions.assertThrows(KeyNotFoundException.class, () -> store.get("missing"))
+                        .getMessage();
+        Assertions.assertEquals("Key 'missing' not found", message);
+    }
+
+    @Test
+    public void testNotFoundIsNativeExecutionException() {
+        KeyValueStore store = new KeyValueStore(2);
+
+        Assertions.assertThrows(NativeExecutionException.class, () -> store.get("missing"));
+    }
+
+    @Test
+    public void testInvalid() {
+        KeyValueStore store = new KeyValueStore(2);
+
+        var message =
+                Assertions.assertThrows(IllegalArgumentException.class, () -> store.put("a", ""))
+                        .getMessage();
+        Assertions.assertEquals("Invalid value: the value must not be empty", message);
+    }
+
+    @Test
+    public void testDefaultException() throws Exception {
+        KeyValueStore store = new KeyValueStore(1);
+        store.put("a", "b");
+
+        var exception =
+                Assertions.assertThrows(
+                        NativeExecutionException.class, () -> store.put("c", "d"));
+        Assertions.assertEquals(NativeExecutionException.class, exception.getClass());
+        Assertions.assertEquals("The store is full", exception.getMessage());
+    }
+
+    @Test
+    public void testSourceIsCause() throws Exception {
+        KeyValueStore store = new KeyValueStore(1);
+        store.put("a", "b");
+
+        var exception =
+                Assertions.assertThrows(NumberFormatException.class, () -> store.getInt("a"));
+        Assertions.assertEquals("The value of 'a' is not a number", exception.getMessage());
+        Assertions.assertNotNull(exception.getCause());
+        Assertions.assertEquals(
+                "invalid digit found in string", exception.getCause().getMessage());
+    }
+
+    @Test
+    public void testRateLimitException() throws Exception {
+        RateLimiter limiter = new RateLimiter(1);
+        Assertions.assertEquals(1, limiter.acquire(null));
+
+        var exception =
+                Assertions.assertThrows(RateLimitException.class, () -> limiter.acquire("test"));
+        Assertions.assertEquals("Rate limit exceeded, retry after 1000ms", exception.getMessage());
+        Assertions.assertEquals(429, exception.code());
+        Assertions.assertEquals(1000, exception.retryAfter());
+        Assertions.assertEquals("test", exception.reason());
+
+        exception = Assertions.assertThrows(RateLimitException.class, () -> limiter.acquire(null));
+        Assertions.assertEquals(2000, exception.retryAfter());
+        Assertions.assertNull(exception.reason());
+    }
+}
@@ -0,0 +1,83 @@
+package com.github.markusjx.example;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import com.github.markusjx.generated.Integers;
+import com.github.markusjx.generated.Stats;
+import java.math.BigInteger;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+public class TestIntegers {
+    @Test
+    public void testU8() {
+        assertEquals(255, Integers.getU8((short) 255));
+        assertThrows(ArithmeticException.class, () -> Integers.getU8((short) 256));
+        assertThrows(ArithmeticException.class, () -> Integers.getU8((short) -1));
+    }
+
+    @Test
+    public void testU32() {
+        assertEquals(4294967295L, Integers.getU32(4294967295L));
+        assertThrows(ArithmeticException.class, () -> Integers.getU32(4294967296L));
+        assertThrows(ArithmeticException.class, () -> Integers.getU32(-1));
+    }
+
+    @Test
+    public void testU64() {
+        assertEquals(Long.MAX_VALUE, Integers.getU64(Long.MAX_VALUE));
+        assertThrows(ArithmeticException.class, () -> Integers.getU64(-1));
+        assertThrows(ArithmeticException.class, Integers::getU64Max);
+    }
+
+    @Test
+    public void testUsize() {
+        assertEquals(42, Integers.getUsize(42));
+        assertThrows(ArithmeticException.class, () -> Integers.getUsize(-1));
+        assertEquals(-42, Integers.getIsize(-42));
+    }
+
+    @Test
+    public void testBigInteger() {
+        BigInteger big = BigInteger.ONE.shiftLeft(100).negate();
+        assertEquals(big, Integers.getI128(big));
+
+        BigInteger max = BigInteger.ONE.shiftLeft(128).subtract(BigInteger.ONE);
+        assertEquals(max, Integers.getU128(max));
+        assertThrows(ArithmeticException.class, () -> Integers.getU128(max.add(BigInteger.ONE)));
+        assertThrows(ArithmeticException.class, () -> Integers.getU128(BigInteger.ONE.negate()));
+    }
+
+    @Test
+    public void testOptional() {
+        assertEquals(7L, Integers.getU32Opt(7L));
+        assertNull(Integers.getU32Opt(null));
+        assertThrows(ArithmeticException.class, () -> Integers.getU32Opt(-7L));
+        assertEquals(BigInteger.TEN, Integers.getU128Opt(BigInteger.TEN));
+        assertNull(Integers.getU128Opt(null));
+    }
+
+    @Test
+    public void testVec() {
+        assertEquals(List.of(1L, 2L, 3L), Integers.getU64Vec(List.of(1L, 2L, 3L)));
+        assertThrows(ArithmeticException.class, () -> Integers.getU64Vec(List.of(-1L)));
+    }
+
+    @Test
+    public void testStats() {
+        Stats stats = new Stats(10, BigInteger.valueOf(1000), (short) 3);
+        assertEquals(stats, Integers.getStats(stats));
+
+        Stats noFlags = new Stats(10, BigInteger.valueOf(1000), null);
+        assertEquals(noFlags, Integers.getStats(noFlags));
+        assertThrows(
+                ArithmeticException.class,
+                () -> Integers.getStats(new Stats(-1, BigInteger.ONE, null)));
+    }
+
+    @Test
+    public void testApplyU32() throws Exception {
+        assertEquals(43, Integers.useApplyU32(val -> val + 42));
+        assertThrows(ArithmeticException.class, () -> Integers.useApplyU32(val -> -val));
+    }
+}
@@ -1,6 +1,17 @@
 package com.github.markusjx.example;
 
+import com.github.markusjx.generated.ApplyInt;
+import com.github.markusjx.generated.ApplyIntImpl;
+import com.github.markusjx.generated.ApplyString;
+import com.github.markusjx.generated.Color;
+import com.github.markusjx.generated.EventEmitter;
+import com.github.markusjx.generated.Point;
 import com.github.markusjx.generated.StructUsingTrait;
+import com.github.markusjx.generated.ThreadRunner;
+import com.github.markusjx.jnibindgen.NativeExecutionException;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
 import java.util.concurrent.atomic.AtomicBoolean;
 import org.junit.jupiter.api.Assertions;
 import org.junit.jupiter.api.Test;
@@ -336,4 +347,249 @@ public class TestInterface {
                         }));
         Assertions.assertTrue(called.get());
     }
+
+    @Test
+    public void testUseApplyColor() throws Exception {
+        AtomicBoolean called = new AtomicBoolean(false);
+
+        Assertions.assertEquals(
+                Color.RED,
+                StructUsingTrait.useApplyColor(
+                        val -> {
+                            called.set(true);
+                            Assertions.assertEquals(Color.GREEN, val);
+                            return Color.RED;
+                        }));
+        Assertions.assertTrue(called.get());
+    }
+
+    @Test
+    public void testUseApplyColorOpt() throws Exception {
+        AtomicBoolean called = new AtomicBoolean(false);
+
+        Assertions.assertNull(
+                StructUsingTrait.useApplyColorOpt(
+                        val -> {
+                            called.set(true);
+                            Assertions.assertEquals(Color.LIGHT_BLUE, val);
+                            return null;
+                        }));
+        Assertions.assertTrue(called.get());
+    }
+
+    @Test
+    public void testUseApplyPoint() throws Exception {
+        Assertions.assertEquals(
+                new Point(2.0, 4.0),
+                StructUsingTrait.useApplyPoint(val -> new Point(val.x() * 2, val.y() * 2)));
+    }
+
+    @Test
+    public void testUseApplyBytes() throws Exception {
+        Assertions.assertArrayEquals(
+                new byte[] {2, 3, 0},
+                StructUsingTrait.useApplyBytes(
+                        val -> {
+                            Assertions.assertArrayEquals(new byte[] {1, 2, -1}, val);
+                            var res = new byte[val.length];
+                            for (int i = 0; i < val.length; i++) {
+                                res[i] = (byte) (val[i] + 1);
+                            }
+                            return res;
+                        }));
+    }
+
+    @Test
+    public void testUseApplyIntsOpt() throws Exception {
+        Assertions.assertArrayEquals(
+                new int[] {1, 2, 3}, StructUsingTrait.useApplyIntsOpt(val -> val));
+        Assertions.assertNull(StructUsingTrait.useApplyIntsOpt(val -> null));
+    }
+
+    @Test
+    public void testUseApplyBuffer() throws Exception {
+        Assertions.assertEquals(
+                6,
+                StructUsingTrait.useApplyBuffer(
+                        val -> {
+                            Assertions.assertTrue(val.isDirect());
+                            long sum = 0;
+                            for (int i = 0; i < val.capacity(); i++) {
+                                sum += val.get(i);
+                            }
+                            return sum;
+                        }));
+    }
+
+    @Test
+    public void testCreateMultiplier() throws Exception {
+        ApplyInt multiplier = StructUsingTrait.createMultiplier(3);
+        Assertions.assertTrue(multiplier instanceof ApplyIntImpl);
+        Assertions.assertEquals(6, multiplier.apply(2));
+        Assertions.assertEquals(-9, multiplier.apply(-3));
+    }
+
+    @Test
+    public void testCreateMultiplierError() {
+        ApplyInt multiplier = StructUsingTrait.createMultiplier(2);
+        var msg =
+                Assertions.assertThrows(
+                                NativeExecutionException.class,
+                                () -> multiplier.apply(Integer.MAX_VALUE))
+                        .getMessage();
+        Assertions.assertEquals("Integer overflow", msg);
+    }
+
+    @Test
+    public void testCreateMultiplierPassedBack() throws Exception {
+        Assertions.assertEquals(
+                5, StructUsingTrait.useApplyInt(StructUsingTrait.createMultiplier(5)));
+    }
+
+    @Test
+    public void testCreateMultiplierDestroyed() {
+        var multiplier = (ApplyIntImpl) StructUsingTrait.createMultiplier(2);
+        long dropped = StructUsingTrait.getDroppedTraitObjects();
+
+        multiplier.destroyNative();
+        Assertions.assertFalse(multiplier.isValid());
+        Assertions.assertTrue(StructUsingTrait.getDroppedTraitObjects() > dropped);
+
+        var msg =
+                Assertions.assertThrows(NullPointerException.class, () -> multiplier.apply(1))
+                        .getMessage();
+        Assertions.assertEquals("The pointer is null", msg);
+    }
+
+    @Test
+    public void testCreateMultiplierDropped() throws InterruptedException {
+        long dropped = StructUsingTrait.getDroppedTraitObjects();
+        var multiplier = StructUsingTrait.createMultiplier(2);
+        Assertions.assertEquals(4, multiplier.apply(2));
+
+        multiplier = null;
+        for (int i = 0;
+                i < 100 && StructUsingTrait.getDroppedTraitObjects() == dropped;
+                i++) {
+            System.gc();
+            Thread.sleep(10);
+        }
+
+        Assertions.assertTrue(StructUsingTrait.getDroppedTraitObjects() > dropped);
+    }
+
+    @Test
+    public void testCreatePrefix() throws Exception {
+        ApplyString prefix = StructUsingTrait.createPrefix("hello ");
+        Assertions.assertEquals("hello world", prefix.apply("world"));
+        Assertions.assertNull(StructUsingTrait.createPrefix(null));
+        Assertions.assertEquals(
+                "hello test", StructUsingTrait.useApplyString(prefix));
+    }
+
+    @Test
+    public void testUseApplyIntSync() throws Exception {
+        Assertions.assertEquals(4, StructUsingTrait.useApplyIntSync(val -> val * 2));
+    }
+
+    @Test
+    public void testEventEmitter() throws Exception {
+        List<String> events = Collections.synchronizedList(new ArrayList<>());
+        EventEmitter emitter = new EventEmitter();
+        emitter.addListener((name, value) -> events.add(name + "=" + value));
+        emitter.addListener((name, value) -> events.add(name + ":" + value));
+
+        emitter.emit("a", 1);
+        Assertions.assertEquals(List.of("a=1", "a:1"), events);
+    }
+
+    @Test
+    public void testEventEmitterFromThread() throws Exception {
+        Thread current = Thread.currentThread();
+        List<Thread> threads = Collections.synchronizedList(new ArrayList<>());
+        List<String> events = Collections.synchronizedList(new ArrayList<>());
+        EventEmitter emitter = new EventEmitter();
+        emitter.addListener(
+                (name, value) -> {
+                    threads.add(Thread.currentThread());
+                    events.add(name + "=" + value);
+                });
+
+        emitter.emitFromThread("b", 2);
+        emitter.emitFromThread("c", 3);
+        Assertions.assertEquals(List.of("b=2", "c=3"), events);
+        Assertions.assertEquals(2, threads.size());
+        Assertions.assertNotSame(current, threads.get(0));
+    }
+
+    @Test
+    public void testCallbackFirstCalledFromRustThread() throws Exception {
+        Thread current = Thread.currentThread();
+        List<Thread> threads = Collections.synchronizedList(new ArrayList<>());
+
+        Assertions.assertEquals(
+                42,
+                ThreadRunner.runOnThread(
+                        value -> {
+                            threads.add(Thread.currentThread());
+                            return value * 2;
+                        },
+                        21));
+        Assertions.assertEquals(1, threads.size());
+        Assertions.assertNotSame(current, threads.get(0));
+    }
+
+    @Test
+    public void testEventListenerThrows() {
+        EventEmitter emitter = new EventEmitter();
+        emitter.addListener(
+                (name, value) -> {
+                    throw new IllegalStateException(name);
+                });
+
+        var message =
+                Assertions.assertThrows(IllegalStateException.class, () -> emitter.emit("d", 4))
+                        .getMessage();
+        Assertions.assertEquals("d", message);
+    }
+
+    @Test
+    public void testEventListenerRethrowsSameException() {
+        EventEmitter emitter = new EventEmitter();
+        var exception = new UnsupportedOperationException("unsupported");
+        emitter.addListener(
+                (name, value) -> {
+                    throw exception;
+                });
+
+        Assertions.assertSame(
+                exception,
+                Assertions.assertThrows(
+                        UnsupportedOperationException.class, () -> emitter.emit("e", 5)));
+        Assertions.assertSame(
+                exception,
+                Assertions.assertThrows(
+                        UnsupportedOperationException.class,
+                        () -> emitter.emitFromThread("e", 5)));
+    }
+
+    @Test
+    public void testEventListenerCollectErrors() throws Exception {
+        EventEmitter emitter = new EventEmitter();
+        emitter.addListener(
+                (name, value) -> {
+                    throw new IllegalStateException(name + value);
+                });
+        emitter.addListener((name, value) -> {});
+        emitter.addListener(
+                (name, value) -> {
+                    throw new NullPointerException();
+                });
+
+        Assertions.assertEquals(
+                List.of(
+                        "java.lang.IllegalStateException|f6|java.lang.IllegalStateException: f6",
+                        "java.lang.NullPointerException|null|java.lang.NullPointerException"),
+                emitter.emitCollectErrors("f", 6));
+    }
 }
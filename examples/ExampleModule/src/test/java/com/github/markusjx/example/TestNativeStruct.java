@@ -2,8 +2,13 @@ package com.github.markusjx.example;
 
 import static org.junit.jupiter.api.Assertions.*;
 
+import com.github.markusjx.generated.Color;
 import com.github.markusjx.generated.NativeStruct;
+import com.github.markusjx.generated.Packet;
+import com.github.markusjx.generated.Person;
+import com.github.markusjx.generated.Point;
 import com.github.markusjx.generated.RustStruct;
+import java.nio.ByteBuffer;
 import java.util.List;
 import java.util.Map;
 import org.junit.jupiter.api.Test;
@@ -117,4 +122,243 @@ public class TestNativeStruct {
     public void testGetTypeHash() {
         assertDoesNotThrow(NativeStruct::getTypeHash);
     }
+
+    @Test
+    public void testGetColor() {
+        assertEquals(Color.RED, NativeStruct.getColor(Color.RED));
+        assertEquals(Color.LIGHT_BLUE, NativeStruct.getColor(Color.LIGHT_BLUE));
+    }
+
+    @Test
+    public void testGetColorNull() {
+        var msg =
+                assertThrows(NullPointerException.class, () -> NativeStruct.getColor(null))
+                        .getMessage();
+        assertEquals("The value is null", msg);
+    }
+
+    @Test
+    public void testGetColorOpt() {
+        assertEquals(Color.GREEN, NativeStruct.getColorOpt(Color.GREEN));
+        assertNull(NativeStruct.getColorOpt(null));
+    }
+
+    @Test
+    public void testGetColorOrdinal() {
+        assertEquals(0, NativeStruct.getColorOrdinal(Color.RED));
+        assertEquals(2, NativeStruct.getColorOrdinal(Color.LIGHT_BLUE));
+    }
+
+    @Test
+    public void testGetColorVec() {
+        var vec = NativeStruct.getColorVec(List.of(Color.GREEN, Color.RED));
+        assertEquals(List.of(Color.GREEN, Color.RED), vec);
+    }
+
+    @Test
+    public void testMovePoint() {
+        var point = NativeStruct.movePoint(new Point(1.0, 2.0), 0.5, -1.0);
+        assertEquals(new Point(1.5, 1.0), point);
+    }
+
+    @Test
+    public void testMovePointNull() {
+        var msg =
+                assertThrows(NullPointerException.class, () -> NativeStruct.movePoint(null, 0, 0))
+                        .getMessage();
+        assertEquals("The value is null", msg);
+    }
+
+    @Test
+    public void testGetPointsOpt() {
+        var points = List.of(new Point(1.0, 2.0), new Point(3.0, 4.0));
+        assertEquals(points, NativeStruct.getPointsOpt(points));
+        assertNull(NativeStruct.getPointsOpt(null));
+    }
+
+    @Test
+    public void testGetPerson() {
+        var person =
+                new Person("test", 42, null, List.of("a", "b"), Color.GREEN, new Point(1.0, 2.0));
+        assertEquals(person, NativeStruct.getPerson(person));
+
+        var other = new Person("other", 0, "nick", List.of(), null, new Point(0.0, 0.0));
+        assertEquals(other, NativeStruct.getPerson(other));
+    }
+
+    @Test
+    public void testGetPersonOpt() {
+        var person = new Person("test", 42, "nick", List.of(), null, new Point(1.0, 2.0));
+        assertEquals(person, NativeStruct.getPersonOpt(person));
+        assertNull(NativeStruct.getPersonOpt(null));
+    }
+
+    @Test
+    public void testGetBytes() {
+        byte[] bytes = {1, 2, 3, -1};
+        assertArrayEquals(bytes, NativeStruct.getBytes(bytes));
+        assertArrayEquals(new byte[0], NativeStruct.getBytes(new byte[0]));
+    }
+
+    @Test
+    public void testGetBytesNull() {
+        var msg =
+                assertThrows(NullPointerException.class, () -> NativeStruct.getBytes(null))
+                        .getMessage();
+        assertEquals("The array is null", msg);
+    }
+
+    @Test
+    public void testGetBytesOpt() {
+        byte[] bytes = {1, 2, 3};
+        assertArrayEquals(bytes, NativeStruct.getBytesOpt(bytes));
+        assertNull(NativeStruct.getBytesOpt(null));
+    }
+
+    @Test
+    public void testSumBytes() {
+        assertEquals(1 + 2 + 255, NativeStruct.sumBytes(new byte[] {1, 2, -1}));
+    }
+
+    @Test
+    public void testGetSignedBytes() {
+        byte[] bytes = {Byte.MIN_VALUE, -1, 0, Byte.MAX_VALUE};
+        assertArrayEquals(bytes, NativeStruct.getSignedBytes(bytes));
+    }
+
+    @Test
+    public void testGetInts() {
+        int[] values = {Integer.MIN_VALUE, -1, 0, 1, Integer.MAX_VALUE};
+        assertArrayEquals(values, NativeStruct.getInts(values));
+        assertEquals(6, NativeStruct.sumInts(new int[] {1, 2, 3}));
+    }
+
+    @Test
+    public void testGetPrimitiveArrays() {
+        long[] longs = {Long.MIN_VALUE, 0, Long.MAX_VALUE};
+        assertArrayEquals(longs, NativeStruct.getLongs(longs));
+
+        short[] shorts = {Short.MIN_VALUE, 0, Short.MAX_VALUE};
+        assertArrayEquals(shorts, NativeStruct.getShorts(shorts));
+
+        char[] chars = {'a', 'b', '\uffff'};
+        assertArrayEquals(chars, NativeStruct.getChars(chars));
+
+        float[] floats = {-1.5f, 0.0f, 2.25f};
+        assertArrayEquals(floats, NativeStruct.getFloats(floats));
+
+        double[] doubles = {-1.5, 0.0, 2.25};
+        assertArrayEquals(doubles, NativeStruct.getDoubles(doubles));
+
+        boolean[] bools = {true, false, true};
+        assertArrayEquals(bools, NativeStruct.getBools(bools));
+    }
+
+    @Test
+    public void testGetNestedInts() {
+        List<List<Integer>> values = List.of(List.of(1, 2), List.of(), List.of(3));
+        assertEquals(values, NativeStruct.getNestedInts(values));
+    }
+
+    @Test
+    public void testGetPacket() {
+        var packet = new Packet(1, new byte[] {1, 2, 3}, new long[] {4, 5});
+        var res = NativeStruct.getPacket(packet);
+        assertEquals(1, res.id());
+        assertArrayEquals(packet.payload(), res.payload());
+        assertArrayEquals(packet.checksums(), res.checksums());
+
+        var empty = NativeStruct.getPacket(new Packet(2, new byte[0], null));
+        assertEquals(2, empty.id());
+        assertArrayEquals(new byte[0], empty.payload());
+        assertNull(empty.checksums());
+    }
+
+    @Test
+    public void testSumBuffer() {
+        var buffer = ByteBuffer.allocateDirect(4);
+        buffer.put(new byte[] {1, 2, 3, -1});
+        assertEquals(1 + 2 + 3 + 255, NativeStruct.sumBuffer(buffer));
+    }
+
+    @Test
+    public void testSumBufferNotDirect() {
+        var msg =
+                assertThrows(
+                                IllegalArgumentException.class,
+                                () -> NativeStruct.sumBuffer(ByteBuffer.allocate(4)))
+                        .getMessage();
+        assertEquals("The buffer is not a direct buffer", msg);
+    }
+
+    @Test
+    public void testSumBufferNull() {
+        var msg =
+                assertThrows(NullPointerException.class, () -> NativeStruct.sumBuffer(null))
+                        .getMessage();
+        assertEquals("The buffer is null", msg);
+    }
+
+    @Test
+    public void testFillBuffer() {
+        var buffer = ByteBuffer.allocateDirect(16);
+        NativeStruct.fillBuffer(buffer, (byte) 7);
+        for (int i = 0; i < buffer.capacity(); i++) {
+            assertEquals(7, buffer.get(i));
+        }
+    }
+
+    @Test
+    public void testGetBufferOpt() {
+        var buffer = ByteBuffer.allocateDirect(4);
+        assertSame(buffer, NativeStruct.getBufferOpt(buffer));
+        assertNull(NativeStruct.getBufferOpt(null));
+    }
+
+    @Test
+    public void testCreateBuffer() {
+        var buffer = NativeStruct.createBuffer(256);
+        assertTrue(buffer.isDirect());
+        assertEquals(256, buffer.capacity());
+        for (int i = 0; i < buffer.capacity(); i++) {
+            assertEquals((byte) i, buffer.get(i));
+        }
+
+        buffer.put(0, (byte) 42);
+        assertEquals(42 + 255 * 256 / 2, NativeStruct.sumBuffer(buffer));
+    }
+
+    @Test
+    public void testCreateBufferDropped() throws InterruptedException {
+        long dropped = NativeStruct.getDroppedBuffers();
+        var buffer = NativeStruct.createCountedBuffer(1024);
+        assertEquals(1024, buffer.capacity());
+        assertEquals(dropped, NativeStruct.getDroppedBuffers());
+
+        buffer = null;
+        for (int i = 0; i < 100 && NativeStruct.getDroppedBuffers() == dropped; i++) {
+            System.gc();
+            Thread.sleep(10);
+        }
+
+        assertEquals(dropped + 1, NativeStruct.getDroppedBuffers());
+    }
+
+    @Test
+    public void testPanickingBufferDropped() throws InterruptedException {
+        long dropped = NativeStruct.getDroppedBuffers();
+        var buffer = NativeStruct.createPanickingBuffer(16);
+        assertEquals(16, buffer.capacity());
+
+        buffer = null;
+        for (int i = 0; i < 100 && NativeStruct.getDroppedBuffers() == dropped; i++) {
+            System.gc();
+            Thread.sleep(10);
+        }
+
+        assertEquals(dropped + 1, NativeStruct.getDroppedBuffers());
+
+        // Other buffers are still dropped after the panic
+        testCreateBufferDropped();
+    }
 }
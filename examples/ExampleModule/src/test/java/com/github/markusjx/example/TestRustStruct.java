@@ -1,9 +1,13 @@
 package com.github.markusjx.example;
 
 import static org.junit.jupiter.api.Assertions.*;
+import static org.junit.jupiter.api.Assumptions.assumeTrue;
 
 import com.github.markusjx.generated.RustStruct;
+import com.github.markusjx.generated.Util;
+import com.github.markusjx.jnibindgen.NativeBacktrace;
 import com.github.markusjx.jnibindgen.NativeExecutionException;
+import com.github.markusjx.jnibindgen.NativePanicException;
 import org.junit.jupiter.api.Test;
 
 public class TestRustStruct {
@@ -99,4 +103,87 @@ public class TestRustStruct {
                         .getMessage();
         assertEquals("test", msg);
     }
+
+    @Test
+    public void testPanic() {
+        var msg =
+                assertThrows(NativePanicException.class, () -> RustStruct.panic("test"))
+                        .getMessage();
+        assertTrue(msg.startsWith("Rust panicked at "));
+        assertTrue(msg.endsWith(": test"));
+    }
+
+    @Test
+    public void testWithSuffix() {
+        RustStruct rs = new RustStruct("test");
+        RustStruct other = rs.withSuffix("-suffix");
+
+        assertNotSame(rs, other);
+        assertEquals("test-suffix", other.getValue());
+        assertEquals("test", rs.getValue());
+
+        other.setValue("changed");
+        assertEquals("changed", other.getValue());
+        assertEquals("test", rs.getValue());
+    }
+
+    @Test
+    public void testStaticFactory() {
+        RustStruct rs = RustStruct.create("test");
+        assertTrue(rs.isValid());
+        assertEquals("test", rs.getValue());
+
+        rs.destroyNative();
+        assertFalse(rs.isValid());
+    }
+
+    @Test
+    public void testTryCreate() throws Exception {
+        assertEquals("test", RustStruct.tryCreate("test").getValue());
+
+        var msg =
+                assertThrows(NativeExecutionException.class, () -> RustStruct.tryCreate(""))
+                        .getMessage();
+        assertEquals("The value must not be empty", msg);
+    }
+
+    @Test
+    public void testCreateOpt() {
+        assertNull(RustStruct.createOpt(null));
+        assertEquals("test", RustStruct.createOpt("test").getValue());
+    }
+
+    @Test
+    public void testThrowErrorBacktrace() {
+        assumeTrue(Util.hasBacktraces());
+        var exception =
+                assertThrows(
+                        IllegalStateException.class,
+                        () ->
+                                RustStruct.throwOtherError(
+                                        "java/lang/IllegalStateException", "test"));
+
+        var suppressed = exception.getSuppressed();
+        assertEquals(1, suppressed.length);
+        assertInstanceOf(NativeBacktrace.class, suppressed[0]);
+
+        var frames = suppressed[0].getStackTrace();
+        assertTrue(frames.length > 0);
+        assertEquals("example_lib::RustStruct", frames[0].getClassName());
+        assertEquals("throw_other_error", frames[0].getMethodName());
+        assertEquals("lib.rs", frames[0].getFileName());
+        assertTrue(frames[0].getLineNumber() > 0);
+        assertTrue(frames[frames.length - 1].getMethodName().startsWith("Java_"));
+    }
+
+    @Test
+    public void testConvertedErrorBacktrace() {
+        assumeTrue(Util.hasBacktraces());
+        var exception =
+                assertThrows(NativeExecutionException.class, () -> RustStruct.throwError("test"));
+
+        var frames = exception.getSuppressed()[0].getStackTrace();
+        assertTrue(frames[0].getClassName().contains("Java_"));
+        assertEquals("lib.rs", frames[0].getFileName());
+    }
 }
@@ -0,0 +1,51 @@
+package com.github.markusjx.example;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import com.github.markusjx.generated.Settings;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+public class TestSettings {
+    @Test
+    public void testGetters() {
+        Settings settings = new Settings("test", 3);
+        assertEquals("test", settings.getName());
+        assertEquals(3, settings.getRetries());
+        assertEquals(List.of(), settings.getTags());
+        assertNull(settings.getTimeout());
+    }
+
+    @Test
+    public void testSetters() {
+        Settings settings = new Settings("test", 3);
+        settings.setName("other");
+        assertEquals("other", settings.getName());
+
+        settings.setTags(List.of("a", "b"));
+        assertEquals(List.of("a", "b"), settings.getTags());
+
+        settings.setTimeout(10L);
+        assertEquals(10L, settings.getTimeout());
+        settings.setTimeout(null);
+        assertNull(settings.getTimeout());
+
+        assertFalse(settings.isVerbose());
+        settings.setVerbose(true);
+        assertTrue(settings.isVerbose());
+    }
+
+    @Test
+    public void testMethodUpdatesField() {
+        Settings settings = new Settings("test", 3);
+        assertEquals(4, settings.retry());
+        assertEquals(4, settings.getRetries());
+    }
+
+    @Test
+    public void testSetNull() {
+        Settings settings = new Settings("test", 3);
+        assertThrows(NullPointerException.class, () -> settings.setName(null));
+        assertEquals("test", settings.getName());
+    }
+}
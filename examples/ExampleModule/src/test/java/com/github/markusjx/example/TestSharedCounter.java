@@ -0,0 +1,114 @@
+package com.github.markusjx.example;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import com.github.markusjx.generated.CounterGroup;
+import com.github.markusjx.generated.SharedCounter;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+public class TestSharedCounter {
+    @Test
+    public void testCreate() {
+        SharedCounter counter = new SharedCounter(5);
+        assertEquals(6, counter.increment());
+        assertEquals(6, counter.get());
+
+        counter.reset();
+        assertEquals(0, counter.get());
+    }
+
+    @Test
+    public void testShare() {
+        SharedCounter counter = new SharedCounter(0);
+        SharedCounter shared = SharedCounter.share(counter);
+
+        assertNotSame(counter, shared);
+        assertEquals(counter.getPtr(), shared.getPtr());
+
+        shared.increment();
+        assertEquals(1, counter.get());
+    }
+
+    @Test
+    public void testResetShared() {
+        SharedCounter counter = new SharedCounter(1);
+        SharedCounter shared = SharedCounter.share(counter);
+
+        var msg = assertThrows(IllegalStateException.class, counter::reset).getMessage();
+        assertEquals("The object is shared and cannot be borrowed mutably", msg);
+
+        shared.destroyNative();
+        counter.reset();
+        assertEquals(0, counter.get());
+    }
+
+    @Test
+    public void testDestroyShared() {
+        SharedCounter counter = new SharedCounter(1);
+        SharedCounter shared = SharedCounter.share(counter);
+
+        counter.destroyNative();
+        assertFalse(counter.isValid());
+        assertEquals(2, shared.increment());
+    }
+
+    @Test
+    public void testGetGlobal() {
+        SharedCounter first = SharedCounter.getGlobal();
+        SharedCounter second = SharedCounter.getGlobal();
+
+        assertEquals(first.getPtr(), second.getPtr());
+        long value = first.increment();
+        assertEquals(value, second.get());
+
+        first.destroyNative();
+        assertEquals(value + 1, SharedCounter.getGlobal().increment());
+    }
+
+    @Test
+    public void testGetChild() {
+        CounterGroup group = new CounterGroup(3);
+        SharedCounter counter = group.getCounter(1);
+
+        counter.increment();
+        counter.increment();
+        assertEquals(2, group.sum());
+        assertNull(group.getCounter(3));
+    }
+
+    @Test
+    public void testChildOutlivesParent() {
+        CounterGroup group = new CounterGroup(1);
+        SharedCounter counter = group.getCounter(0);
+        long dropped = SharedCounter.getDroppedCounters();
+
+        group.destroyNative();
+        assertEquals(dropped, SharedCounter.getDroppedCounters());
+        assertEquals(1, counter.increment());
+    }
+
+    @Test
+    public void testGetChildren() {
+        CounterGroup group = new CounterGroup(2);
+        List<SharedCounter> counters = group.getCounters();
+
+        assertEquals(2, counters.size());
+        counters.get(0).increment();
+        counters.get(1).increment();
+        assertEquals(2, group.sum());
+    }
+
+    @Test
+    public void testDropped() {
+        long dropped = SharedCounter.getDroppedCounters();
+        SharedCounter counter = new SharedCounter(0);
+        SharedCounter shared = SharedCounter.share(counter);
+
+        counter.destroyNative();
+        assertEquals(dropped, SharedCounter.getDroppedCounters());
+
+        shared.destroyNative();
+        assertTrue(SharedCounter.getDroppedCounters() > dropped);
+    }
+}
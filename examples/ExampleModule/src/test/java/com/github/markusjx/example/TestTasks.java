@@ -0,0 +1,108 @@
+package com.github.markusjx.example;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import com.github.markusjx.generated.KeyNotFoundException;
+import com.github.markusjx.generated.Point;
+import com.github.markusjx.generated.Tasks;
+import com.github.markusjx.generated.Util;
+import com.github.markusjx.jnibindgen.NativeExecutionException;
+import com.github.markusjx.jnibindgen.NativePanicException;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.ExecutionException;
+import org.junit.jupiter.api.Test;
+
+public class TestTasks {
+    @Test
+    public void testGreet() throws Exception {
+        Tasks tasks = new Tasks("Hello, ");
+        assertEquals("Hello, test", tasks.greet("test").get());
+    }
+
+    @Test
+    public void testAdd() throws Exception {
+        assertEquals(5, Tasks.add(2, 3).get());
+    }
+
+    @Test
+    public void testParseLong() throws Exception {
+        assertEquals(42L, Tasks.parseLong(" 42 ").get());
+
+        ExecutionException e =
+                assertThrows(ExecutionException.class, () -> Tasks.parseLong("abc").get());
+        assertInstanceOf(NativeExecutionException.class, e.getCause());
+    }
+
+    @Test
+    public void testFail() {
+        ExecutionException e =
+                assertThrows(ExecutionException.class, () -> Tasks.fail("test").get());
+        assertInstanceOf(NativeExecutionException.class, e.getCause());
+        assertEquals("test", e.getCause().getMessage());
+    }
+
+    @Test
+    public void testPanic() {
+        ExecutionException e =
+                assertThrows(ExecutionException.class, () -> Tasks.panic("test").get());
+        assertInstanceOf(NativePanicException.class, e.getCause());
+    }
+
+    @Test
+    public void testGetBytes() throws Exception {
+        assertArrayEquals(new byte[] {0, 1, 2}, Tasks.getBytes(3).get());
+    }
+
+    @Test
+    public void testGetNames() throws Exception {
+        Tasks tasks = new Tasks("n_");
+        assertEquals(List.of("n_a", "n_b"), tasks.getNames(List.of("a", "b")).get());
+    }
+
+    @Test
+    public void testFind() throws Exception {
+        Tasks tasks = new Tasks("f_");
+        assertEquals("f_a", tasks.find("a").get());
+        assertNull(tasks.find(null).get());
+    }
+
+    @Test
+    public void testSleep() throws Exception {
+        CompletableFuture<Void> future = Tasks.sleep(20);
+        assertNull(future.get());
+        assertTrue(future.isDone());
+    }
+
+    @Test
+    public void testCreate() throws Exception {
+        Tasks tasks = Tasks.create("c_").get();
+        assertEquals("c_", tasks.getPrefix());
+    }
+
+    @Test
+    public void testDestroyedWhileRunning() throws Exception {
+        Tasks tasks = new Tasks("d_");
+        CompletableFuture<String> future = tasks.greet("x");
+        tasks.destroyNative();
+        assertEquals("d_x", future.get());
+    }
+
+    @Test
+    public void testMultiply() throws Exception {
+        assertEquals(42L, Util.multiply(6, 7).get());
+    }
+
+    @Test
+    public void testOrigin() throws Exception {
+        assertEquals(new Point(0, 0), Tasks.origin().get());
+    }
+
+    @Test
+    public void testLookup() {
+        ExecutionException e =
+                assertThrows(ExecutionException.class, () -> Tasks.lookup("missing").get());
+        assertInstanceOf(KeyNotFoundException.class, e.getCause());
+        assertEquals("Key 'missing' not found", e.getCause().getMessage());
+    }
+}
@@ -0,0 +1,111 @@
+package com.github.markusjx.example;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import com.github.markusjx.generated.Point;
+import com.github.markusjx.generated.Rectangle;
+import com.github.markusjx.generated.Util;
+import com.github.markusjx.generated.geometry.Size;
+import com.github.markusjx.jnibindgen.NativeExecutionException;
+import java.util.Arrays;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+public class TestUtil {
+    @Test
+    public void testAdd() {
+        assertEquals(5, Util.add(2, 3));
+    }
+
+    @Test
+    public void testOnLoad() {
+        assertTrue(Util.isLoaded());
+        assertTrue(Util.hasVm());
+    }
+
+    @Test
+    public void testGreet() {
+        assertEquals("Hello, World!", Util.greet(null));
+        assertEquals("Hello, test!", Util.greet("test"));
+    }
+
+    @Test
+    public void testParseInt() throws Exception {
+        assertEquals(42, Util.parseInt(" 42 "));
+        assertThrows(NativeExecutionException.class, () -> Util.parseInt("abc"));
+    }
+
+    @Test
+    @SuppressWarnings("unchecked")
+    public void testSumNonNull() {
+        assertEquals(6, Util.sumNonNull(Arrays.asList(1, null, 2, 3)));
+
+        // The type of the elements is only checked by the native code
+        var strings = (List<Integer>) (List<?>) List.of("a");
+        var exception = assertThrows(ClassCastException.class, () -> Util.sumNonNull(strings));
+        assertEquals("Expected an instance of java.lang.Integer", exception.getMessage());
+    }
+
+    @Test
+    public void testDistance() {
+        assertEquals(5.0, Util.distance(new Point(0, 0), new Point(3, 4)));
+    }
+
+    @Test
+    public void testOtherPackage() {
+        assertEquals(6.0, Util.area(new Size(2, 3)));
+        assertEquals(new Size(2, 2), Util.square(2));
+    }
+
+    @Test
+    public void testDataClassOfOtherPackage() {
+        assertEquals(
+                new Rectangle(3, 5, new Size(2, 3)),
+                Util.translate(new Rectangle(1, 2, new Size(2, 3)), 2, 3));
+    }
+
+    @Test
+    public void testTraitUsingOtherPackage() throws Exception {
+        assertEquals(
+                new Size(4, 6),
+                Util.resize(
+                        new Size(2, 3), size -> new Size(size.width() * 2, size.height() * 2)));
+    }
+
+    @Test
+    public void testJoinStrings() {
+        assertEquals("a, b, c", Util.joinStrings(List.of("a", "b", "c"), ", "));
+    }
+
+    @Test
+    public void testGetDroppedBufferCount() {
+        assertTrue(Util.getDroppedBufferCount() >= 0);
+    }
+
+    @Test
+    public void testParsePort() throws Exception {
+        assertEquals(8080, Util.parsePort("8080"));
+    }
+
+    @Test
+    public void testParsePortCause() {
+        var exception = assertThrows(NativeExecutionException.class, () -> Util.parsePort("abc"));
+        assertEquals("Invalid port 'abc'", exception.getMessage());
+
+        var cause = exception.getCause();
+        assertEquals(RuntimeException.class, cause.getClass());
+        assertEquals("invalid digit found in string", cause.getMessage());
+        assertNull(cause.getCause());
+    }
+
+    @Test
+    public void testParsePortRegisteredCause() {
+        var exception =
+                assertThrows(NativeExecutionException.class, () -> Util.parsePort("70000"));
+        assertEquals("Invalid port '70000'", exception.getMessage());
+
+        var cause = exception.getCause();
+        assertInstanceOf(ArithmeticException.class, cause);
+        assertEquals("out of range integral type conversion attempted", cause.getMessage());
+    }
+}
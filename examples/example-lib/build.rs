@@ -0,0 +1,10 @@
+fn main() {
+    let mut builder = jni_bindgen_build::Builder::new().out_dir("../ExampleModule/src/main/java");
+    if std::env::var("CARGO_FEATURE_REGISTER_NATIVES").is_ok() {
+        builder = builder.register_natives().class_names("crate::class_name");
+    }
+
+    builder
+        .generate()
+        .expect("Failed to generate the java classes");
+}
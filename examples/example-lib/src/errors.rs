@@ -0,0 +1,143 @@
+use jni_bindgen::objects::traits::{FromJNI, ObjectFromJNI};
+use jni_bindgen::{jni, JniError};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::num::ParseIntError;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, JniError)]
+#[jni(package = "com.github.markusjx.generated")]
+/// An error returned by the [`KeyValueStore`]
+pub enum StoreError {
+    /// Thrown if a key does not exist
+    #[jni(exception = "KeyNotFoundException", generate)]
+    NotFound(String),
+    #[jni(exception = "java.lang.IllegalArgumentException")]
+    Invalid {
+        reason: String,
+    },
+    Full,
+    #[jni(exception = "java.lang.NumberFormatException")]
+    NotANumber(String, ParseIntError),
+}
+
+impl Display for StoreError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoreError::NotFound(key) => write!(f, "Key '{key}' not found"),
+            StoreError::Invalid { reason } => write!(f, "Invalid value: {reason}"),
+            StoreError::Full => write!(f, "The store is full"),
+            StoreError::NotANumber(key, _) => write!(f, "The value of '{key}' is not a number"),
+        }
+    }
+}
+
+impl Error for StoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StoreError::NotANumber(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A store with a limited capacity
+pub struct KeyValueStore {
+    capacity: usize,
+    values: Mutex<HashMap<String, String>>,
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl KeyValueStore {
+    #[jni(constructor, rename = "init")]
+    fn new(capacity: i32) -> Self {
+        Self {
+            capacity: capacity as usize,
+            values: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[jni]
+    fn get(&self, key: String) -> Result<String, StoreError> {
+        self.values
+            .lock()
+            .unwrap()
+            .get(&key)
+            .cloned()
+            .ok_or(StoreError::NotFound(key))
+    }
+
+    #[jni]
+    fn get_int(&self, key: String) -> Result<i32, StoreError> {
+        let value = self.get(key.clone())?;
+        value.parse().map_err(|e| StoreError::NotANumber(key, e))
+    }
+
+    #[jni]
+    fn put(&self, key: String, value: String) -> Result<(), StoreError> {
+        if value.is_empty() {
+            return Err(StoreError::Invalid {
+                reason: "the value must not be empty".to_string(),
+            });
+        }
+
+        let mut values = self.values.lock().unwrap();
+        if values.len() >= self.capacity && !values.contains_key(&key) {
+            return Err(StoreError::Full);
+        }
+
+        values.insert(key, value);
+        Ok(())
+    }
+}
+
+#[jni(package = "com.github.markusjx.generated", error)]
+#[derive(Debug)]
+/// Thrown if too many calls have been made
+pub struct RateLimitException {
+    code: i32,
+    retry_after: u64,
+    reason: Option<String>,
+}
+
+impl Display for RateLimitException {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Rate limit exceeded, retry after {}ms", self.retry_after)
+    }
+}
+
+/// Limits the number of calls
+pub struct RateLimiter {
+    limit: i32,
+    calls: AtomicI32,
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl RateLimiter {
+    #[jni(constructor, rename = "init")]
+    fn new(limit: i32) -> Self {
+        Self {
+            limit,
+            calls: AtomicI32::new(0),
+        }
+    }
+
+    #[jni]
+    /// Acquire a permit
+    ///
+    /// @return the number of calls made so far
+    fn acquire(&self, reason: Option<String>) -> Result<i32, RateLimitException> {
+        let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        if calls > self.limit {
+            return Err(RateLimitException {
+                code: 429,
+                retry_after: (calls - self.limit) as u64 * 1000,
+                reason,
+            });
+        }
+
+        Ok(calls)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::Point;
+use anyhow::Context;
+use jni::{JNIEnv, JavaVM};
+use jni_bindgen::errors::jni_error::{register_error_class, ErrorClass};
+use jni_bindgen::objects::traits::FromJNI;
+use std::num::TryFromIntError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+static LOADED: AtomicBool = AtomicBool::new(false);
+
+#[jni(on_load)]
+fn on_load(_vm: &JavaVM, _env: &mut JNIEnv) -> jni_bindgen::Result<()> {
+    LOADED.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+#[jni(
+    package = "com.github.markusjx.generated",
+    class = "Util",
+    load_lib = "example_lib"
+)]
+/// Add two integers
+///
+/// @param a The first integer
+/// @param b The second integer
+/// @return The sum of both integers
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+fn greet(name: Option<String>) -> String {
+    format!("Hello, {}!", name.as_deref().unwrap_or("World"))
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+fn parse_int(value: String) -> anyhow::Result<i32> {
+    Ok(value.trim().parse()?)
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Parse a port number, keeping the cause of the error
+fn parse_port(value: String) -> anyhow::Result<i32> {
+    static REGISTER: Once = Once::new();
+    REGISTER.call_once(|| {
+        register_error_class::<TryFromIntError>(ErrorClass::Arithmetic);
+    });
+
+    let port = value
+        .parse::<i64>()
+        .map_err(anyhow::Error::from)
+        .and_then(|port| Ok(u16::try_from(port)?))
+        .with_context(|| format!("Invalid port '{value}'"))?;
+
+    Ok(port as i32)
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+fn distance(a: Point, b: Point) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+#[jni(
+    package = "com.github.markusjx.generated",
+    class = "Util",
+    rename = "joinStrings"
+)]
+fn join(values: Vec<String>, separator: String) -> String {
+    values.join(&separator)
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+async fn multiply(a: i64, b: i64) -> i64 {
+    a * b
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Whether the `on_load` hook has been called
+fn is_loaded() -> bool {
+    LOADED.load(Ordering::SeqCst)
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Whether the `JavaVM` the library has been loaded into is available
+fn has_vm() -> bool {
+    jni_bindgen::vm().is_some()
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Whether backtraces are attached to the thrown exceptions
+fn has_backtraces() -> bool {
+    cfg!(feature = "backtrace")
+        && std::backtrace::Backtrace::capture().status()
+            == std::backtrace::BacktraceStatus::Captured
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Sum all values which are not null
+fn sum_non_null(values: Vec<Option<i32>>) -> i32 {
+    values.into_iter().flatten().sum()
+}
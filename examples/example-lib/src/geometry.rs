@@ -0,0 +1,58 @@
+use jni::JNIEnv;
+use jni_bindgen::objects::traits::FromJNI;
+
+#[jni(package = "com.github.markusjx.generated.geometry", data)]
+/// A data class in another package than the class using it
+pub struct Size {
+    width: f64,
+    height: f64,
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Get the area of a size
+fn area(size: Size) -> f64 {
+    size.width * size.height
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Get a square with the given side length
+fn square(side: f64) -> Size {
+    Size {
+        width: side,
+        height: side,
+    }
+}
+
+#[jni(package = "com.github.markusjx.generated", data)]
+/// A data class with a field of a data class in another package
+pub struct Rectangle {
+    x: f64,
+    y: f64,
+    size: Size,
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+/// A trait using a data class in another package
+pub trait Resize {
+    fn resize(&self, env: &mut JNIEnv, size: Size) -> jni_bindgen::Result<Size>;
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Move a rectangle by the given offset
+fn translate(rect: Rectangle, dx: f64, dy: f64) -> Rectangle {
+    Rectangle {
+        x: rect.x + dx,
+        y: rect.y + dy,
+        size: rect.size,
+    }
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Resize a size using the given trait
+fn resize<'a>(
+    size: Size,
+    resize: Box<dyn Resize + 'a>,
+    env: &mut JNIEnv<'a>,
+) -> jni_bindgen::Result<Size> {
+    resize.resize(env, size)
+}
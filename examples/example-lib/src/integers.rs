@@ -0,0 +1,87 @@
+use jni::JNIEnv;
+use jni_bindgen::objects::traits::FromJNI;
+
+#[jni(package = "com.github.markusjx.generated", data)]
+/// A data class using unsigned and wide integers
+pub struct Stats {
+    count: u32,
+    total: u128,
+    flags: Option<u8>,
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyU32 {
+    fn apply(&self, env: &mut JNIEnv, val: u32) -> jni_bindgen::Result<u32>;
+}
+
+#[jni(
+    package = "com.github.markusjx.generated",
+    class = "Integers",
+    load_lib = "example_lib"
+)]
+fn get_u8(value: u8) -> u8 {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u32(value: u32) -> u32 {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u64(value: u64) -> u64 {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_usize(value: usize) -> usize {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_isize(value: isize) -> isize {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_i128(value: i128) -> i128 {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u128(value: u128) -> u128 {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u32_opt(value: Option<u32>) -> Option<u32> {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u128_opt(value: Option<u128>) -> Option<u128> {
+    value
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u64_vec(values: Vec<u64>) -> Vec<u64> {
+    values
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_u64_max() -> u64 {
+    u64::MAX
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn get_stats(stats: Stats) -> Stats {
+    stats
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Integers")]
+fn use_apply_u32<'a>(
+    trait_obj: Box<dyn ApplyU32 + 'a>,
+    env: &mut JNIEnv<'a>,
+) -> jni_bindgen::Result<u32> {
+    trait_obj.apply(env, 1)
+}
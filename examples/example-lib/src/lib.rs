@@ -1,15 +1,37 @@
+mod errors;
+mod functions;
+mod geometry;
+mod integers;
 mod structs;
 
 use anyhow::bail;
 use jni::objects::JObject;
 use jni_bindgen::errors::jni_error::ErrorClass;
 use jni_bindgen::jni;
+use jni_bindgen::objects::direct_buffer::DirectBuffer;
 use jni_bindgen::objects::traits::{FromJNI, ObjectFromJNI};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
 
 #[macro_use]
 extern crate jni_bindgen;
 
+#[cfg(feature = "register_natives")]
+include!(concat!(env!("OUT_DIR"), "/jni_bindgen_natives.rs"));
+
+/// Move the generated classes into the package set by
+/// `EXAMPLE_RENAMED_PACKAGE` at compile time, if any
+#[cfg(feature = "register_natives")]
+fn class_name(name: &str) -> String {
+    match option_env!("EXAMPLE_RENAMED_PACKAGE") {
+        Some(package) => name.replace(
+            "com/github/markusjx/generated/",
+            &format!("{}/", package.replace('.', "/")),
+        ),
+        None => name.to_string(),
+    }
+}
+
 struct RustStruct {
     value: String,
 }
@@ -20,6 +42,91 @@ impl Drop for RustStruct {
     }
 }
 
+#[jni(package = "com.github.markusjx.generated")]
+/// An enum used for testing
+pub enum Color {
+    Red,
+    Green,
+    /// The color blue
+    #[jni(rename = "LIGHT_BLUE")]
+    Blue,
+}
+
+#[jni(package = "com.github.markusjx.generated", data)]
+/// A data class used for testing
+pub struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[jni(package = "com.github.markusjx.generated", data)]
+pub struct Person {
+    name: String,
+    age: i32,
+    nickname: Option<String>,
+    tags: Vec<String>,
+    favorite_color: Option<Color>,
+    location: Point,
+}
+
+#[jni(package = "com.github.markusjx.generated", data)]
+pub struct Packet {
+    id: i32,
+    payload: Vec<u8>,
+    checksums: Option<Vec<i64>>,
+}
+
+static DROPPED_BUFFERS: AtomicI64 = AtomicI64::new(0);
+
+/// Memory which counts how often it has been dropped
+struct CountedBytes(Vec<u8>);
+
+impl AsRef<[u8]> for CountedBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for CountedBytes {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Drop for CountedBytes {
+    fn drop(&mut self) {
+        DROPPED_BUFFERS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Bytes panicking once they are dropped.
+struct PanickingBytes(Vec<u8>);
+
+impl AsRef<[u8]> for PanickingBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for PanickingBytes {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Drop for PanickingBytes {
+    fn drop(&mut self) {
+        DROPPED_BUFFERS.fetch_add(1, Ordering::SeqCst);
+        panic!("Dropping the buffer failed");
+    }
+}
+
+#[jni(package = "com.github.markusjx.generated", class = "Util")]
+/// Get the number of dropped direct buffers
+fn get_dropped_buffer_count() -> i64 {
+    DROPPED_BUFFERS.load(Ordering::SeqCst)
+}
+
 struct NativeStruct;
 
 #[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
@@ -63,6 +170,154 @@ impl NativeStruct {
     fn get_vec_values(vec: Vec<&RustStruct>) -> Vec<String> {
         vec.into_iter().map(|s| s.get_value()).collect()
     }
+
+    #[jni]
+    fn get_color(color: Color) -> Color {
+        color
+    }
+
+    #[jni]
+    fn get_color_opt(color: Option<Color>) -> Option<Color> {
+        color
+    }
+
+    #[jni]
+    fn get_color_ordinal(color: Color) -> i32 {
+        color as i32
+    }
+
+    #[jni]
+    fn get_color_vec(colors: Vec<Color>) -> Vec<Color> {
+        colors
+    }
+
+    #[jni]
+    fn move_point(point: Point, dx: f64, dy: f64) -> Point {
+        Point {
+            x: point.x + dx,
+            y: point.y + dy,
+        }
+    }
+
+    #[jni]
+    fn get_points_opt(points: Option<Vec<Point>>) -> Option<Vec<Point>> {
+        points
+    }
+
+    #[jni]
+    fn get_person(person: Person) -> Person {
+        person
+    }
+
+    #[jni]
+    fn get_person_opt(person: Option<Person>) -> Option<Person> {
+        person
+    }
+
+    #[jni]
+    fn get_bytes(bytes: Vec<u8>) -> Vec<u8> {
+        bytes
+    }
+
+    #[jni]
+    fn get_bytes_opt(bytes: Option<Vec<u8>>) -> Option<Vec<u8>> {
+        bytes
+    }
+
+    #[jni]
+    fn sum_bytes(bytes: &[u8]) -> i64 {
+        bytes.iter().map(|b| *b as i64).sum()
+    }
+
+    #[jni]
+    fn get_signed_bytes(bytes: Vec<i8>) -> Vec<i8> {
+        bytes
+    }
+
+    #[jni]
+    fn get_ints(values: Vec<i32>) -> Vec<i32> {
+        values
+    }
+
+    #[jni]
+    fn sum_ints(values: &[i32]) -> i32 {
+        values.iter().sum()
+    }
+
+    #[jni]
+    fn get_longs(values: Vec<i64>) -> Vec<i64> {
+        values
+    }
+
+    #[jni]
+    fn get_shorts(values: Vec<i16>) -> Vec<i16> {
+        values
+    }
+
+    #[jni]
+    fn get_chars(values: Vec<u16>) -> Vec<u16> {
+        values
+    }
+
+    #[jni]
+    fn get_floats(values: Vec<f32>) -> Vec<f32> {
+        values
+    }
+
+    #[jni]
+    fn get_doubles(values: Vec<f64>) -> Vec<f64> {
+        values
+    }
+
+    #[jni]
+    fn get_bools(values: Vec<bool>) -> Vec<bool> {
+        values
+    }
+
+    #[jni]
+    fn get_nested_ints(values: Vec<Vec<i32>>) -> Vec<Vec<i32>> {
+        values
+    }
+
+    #[jni]
+    fn get_packet(packet: Packet) -> Packet {
+        packet
+    }
+
+    #[jni]
+    fn sum_buffer(buffer: DirectBuffer) -> i64 {
+        buffer.iter().map(|b| *b as i64).sum()
+    }
+
+    #[jni]
+    fn fill_buffer(mut buffer: DirectBuffer, value: i8) {
+        buffer.fill(value as u8);
+    }
+
+    #[jni]
+    fn get_buffer_opt(buffer: Option<DirectBuffer>) -> Option<DirectBuffer> {
+        buffer
+    }
+
+    #[jni]
+    fn create_buffer(size: i32) -> DirectBuffer<'static> {
+        DirectBuffer::from_vec((0..size).map(|i| i as u8).collect())
+    }
+
+    #[jni]
+    fn create_counted_buffer(size: i32) -> DirectBuffer<'static> {
+        DirectBuffer::from_owned(CountedBytes(vec![0; size as usize]))
+    }
+
+    #[jni]
+    fn create_panicking_buffer(size: i32) -> DirectBuffer<'static> {
+        DirectBuffer::from_owned(PanickingBytes(vec![0; size as usize]))
+    }
+
+    #[jni]
+    fn get_dropped_buffers() -> i64 {
+        DROPPED_BUFFERS.load(Ordering::SeqCst)
+    }
 }
 
 #[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
@@ -85,6 +340,34 @@ impl RustStruct {
         self.value = value;
     }
 
+    #[jni]
+    /// Create a new RustStruct with the value of this
+    /// struct followed by the given suffix
+    fn with_suffix(&self, suffix: String) -> Self {
+        Self {
+            value: format!("{}{}", self.value, suffix),
+        }
+    }
+
+    #[jni]
+    fn create(value: String) -> Self {
+        Self { value }
+    }
+
+    #[jni]
+    fn try_create(value: String) -> anyhow::Result<Self> {
+        if value.is_empty() {
+            bail!("The value must not be empty");
+        }
+
+        Ok(Self { value })
+    }
+
+    #[jni]
+    fn create_opt(value: Option<String>) -> Option<Self> {
+        value.map(|value| Self { value })
+    }
+
     #[jni]
     fn get_string(opt: Option<String>) -> Option<String> {
         opt
@@ -139,4 +422,9 @@ impl RustStruct {
     fn throw_other_error(err: String, msg: String) -> jni_bindgen::Result<()> {
         jni_bindgen::bail_class!(ErrorClass::Any(err), "{}", msg)
     }
+
+    #[jni]
+    fn panic(msg: String) -> i32 {
+        panic!("{msg}")
+    }
 }
@@ -1,6 +1,10 @@
+use crate::{Color, Point};
 use jni::JNIEnv;
+use jni_bindgen::objects::direct_buffer::DirectBuffer;
 use jni_bindgen::objects::traits::{FromJNI, ObjectFromJNI};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Mutex;
 
 #[jni(package = "com.github.markusjx.generated")]
 /// Trait used for testing
@@ -129,6 +133,169 @@ pub trait ApplyShortOpt {
     fn apply(&self, env: &mut JNIEnv, val: Option<i16>) -> jni_bindgen::Result<Option<i16>>;
 }
 
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyColor {
+    fn apply(&self, env: &mut JNIEnv, val: Color) -> jni_bindgen::Result<Color>;
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyColorOpt {
+    fn apply(&self, env: &mut JNIEnv, val: Option<Color>) -> jni_bindgen::Result<Option<Color>>;
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyPoint {
+    fn apply(&self, env: &mut JNIEnv, val: Point) -> jni_bindgen::Result<Point>;
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyBytes {
+    fn apply(&self, env: &mut JNIEnv, val: &[u8]) -> jni_bindgen::Result<Vec<u8>>;
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyIntsOpt {
+    fn apply(
+        &self,
+        env: &mut JNIEnv,
+        val: Option<Vec<i32>>,
+    ) -> jni_bindgen::Result<Option<Vec<i32>>>;
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+pub trait ApplyBuffer {
+    fn apply(&self, env: &mut JNIEnv, val: DirectBuffer) -> jni_bindgen::Result<i64>;
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+/// A listener which may be called from any thread
+pub trait EventListener {
+    /// Called once an event has been emitted
+    ///
+    /// @param name The name of the event
+    /// @param value The value of the event
+    fn on_event(&self, name: String, value: i32) -> jni_bindgen::Result<()>;
+}
+
+/// Emits events to java listeners from any thread
+pub struct EventEmitter {
+    listeners: Mutex<Vec<Box<dyn EventListener + Send + Sync>>>,
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl EventEmitter {
+    #[jni(constructor, rename = "init")]
+    fn new() -> Self {
+        Self {
+            listeners: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[jni]
+    fn add_listener(&self, listener: Box<dyn EventListener + Send + Sync + 'static>) {
+        self.listeners.lock().unwrap().push(listener);
+    }
+
+    #[jni]
+    fn emit(&self, name: String, value: i32) -> jni_bindgen::Result<()> {
+        for listener in self.listeners.lock().unwrap().iter() {
+            listener.on_event(name.clone(), value)?;
+        }
+
+        Ok(())
+    }
+
+    #[jni]
+    /// Emit an event to all listeners, collecting the exceptions thrown
+    ///
+    /// @return the class name, message and first stack trace line of every exception
+    fn emit_collect_errors(&self, name: String, value: i32) -> jni_bindgen::Result<Vec<String>> {
+        let mut errors = Vec::new();
+        for listener in self.listeners.lock().unwrap().iter() {
+            if let Err(e) = listener.on_event(name.clone(), value) {
+                let Some(ex) = e.java_exception() else {
+                    return Err(e);
+                };
+
+                errors.push(format!(
+                    "{}|{}|{}",
+                    ex.class_name,
+                    ex.message.as_deref().unwrap_or("null"),
+                    ex.stack_trace.lines().next().unwrap_or_default()
+                ));
+            }
+        }
+
+        Ok(errors)
+    }
+
+    #[jni]
+    /// Emit an event from a new thread and wait for all listeners
+    fn emit_from_thread(&self, name: String, value: i32) -> jni_bindgen::Result<()> {
+        std::thread::scope(|s| {
+            s.spawn(|| self.emit(name, value))
+                .join()
+                .unwrap_or_else(|_| Err(jni_bindgen::error!("The thread panicked")))
+        })
+    }
+}
+
+#[jni(package = "com.github.markusjx.generated")]
+/// A callback which is only ever called from threads created by Rust
+pub trait ThreadCallback {
+    /// Called on a thread created by Rust
+    ///
+    /// @param value The value passed to the thread
+    /// @return The result of the callback
+    fn call(&self, value: i32) -> jni_bindgen::Result<i32>;
+}
+
+/// Calls callbacks on threads created by Rust
+pub struct ThreadRunner;
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl ThreadRunner {
+    #[jni]
+    /// Call the callback on a new thread and return its result.
+    /// The callback's class must not be looked up on the calling
+    /// thread, as threads created by Rust use the system class loader.
+    fn run_on_thread(
+        callback: Box<dyn ThreadCallback + Send + Sync + 'static>,
+        value: i32,
+    ) -> jni_bindgen::Result<i32> {
+        std::thread::spawn(move || callback.call(value))
+            .join()
+            .unwrap_or_else(|_| Err(jni_bindgen::error!("The thread panicked")))
+    }
+}
+
+static DROPPED_TRAIT_OBJECTS: AtomicI64 = AtomicI64::new(0);
+
+/// A Rust implementation of [`ApplyInt`]
+struct Multiplier(i32);
+
+impl ApplyInt for Multiplier {
+    fn apply(&self, _env: &mut JNIEnv, val: i32) -> jni_bindgen::Result<i32> {
+        val.checked_mul(self.0)
+            .ok_or_else(|| jni_bindgen::error!("Integer overflow"))
+    }
+}
+
+impl Drop for Multiplier {
+    fn drop(&mut self) {
+        DROPPED_TRAIT_OBJECTS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// A Rust implementation of [`ApplyString`]
+struct Prefix(String);
+
+impl ApplyString for Prefix {
+    fn apply(&self, _env: &mut JNIEnv, val: String) -> jni_bindgen::Result<String> {
+        Ok(format!("{}{}", self.0, val))
+    }
+}
+
 struct StructUsingTrait;
 
 #[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
@@ -317,4 +484,75 @@ impl StructUsingTrait {
     ) -> jni_bindgen::Result<Option<i16>> {
         trait_obj.apply(env, Some(1))
     }
+
+    #[jni]
+    fn use_apply_color<'a>(
+        trait_obj: Box<dyn ApplyColor + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<Color> {
+        trait_obj.apply(env, Color::Green)
+    }
+
+    #[jni]
+    fn use_apply_color_opt<'a>(
+        trait_obj: Box<dyn ApplyColorOpt + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<Option<Color>> {
+        trait_obj.apply(env, Some(Color::Blue))
+    }
+
+    #[jni]
+    fn use_apply_point<'a>(
+        trait_obj: Box<dyn ApplyPoint + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<Point> {
+        trait_obj.apply(env, Point { x: 1.0, y: 2.0 })
+    }
+
+    #[jni]
+    fn use_apply_bytes<'a>(
+        trait_obj: Box<dyn ApplyBytes + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<Vec<u8>> {
+        trait_obj.apply(env, &[1, 2, 255])
+    }
+
+    #[jni]
+    fn use_apply_int_sync<'a>(
+        trait_obj: Box<dyn ApplyInt + Send + Sync + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<i32> {
+        trait_obj.apply(env, 2)
+    }
+
+    #[jni]
+    fn create_multiplier(factor: i32) -> Box<dyn ApplyInt + 'static> {
+        Box::new(Multiplier(factor))
+    }
+
+    #[jni]
+    fn create_prefix(prefix: Option<String>) -> Option<Box<dyn ApplyString + 'static>> {
+        prefix.map(|p| Box::new(Prefix(p)) as Box<dyn ApplyString>)
+    }
+
+    #[jni]
+    fn get_dropped_trait_objects() -> i64 {
+        DROPPED_TRAIT_OBJECTS.load(Ordering::SeqCst)
+    }
+
+    #[jni]
+    fn use_apply_buffer<'a>(
+        trait_obj: Box<dyn ApplyBuffer + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<i64> {
+        trait_obj.apply(env, DirectBuffer::from_vec(vec![1, 2, 3]))
+    }
+
+    #[jni]
+    fn use_apply_ints_opt<'a>(
+        trait_obj: Box<dyn ApplyIntsOpt + 'a>,
+        env: &mut JNIEnv<'a>,
+    ) -> jni_bindgen::Result<Option<Vec<i32>>> {
+        trait_obj.apply(env, Some(vec![1, 2, 3]))
+    }
 }
@@ -1 +1,4 @@
-mod interface;
+pub(crate) mod interface;
+pub(crate) mod settings;
+pub(crate) mod shared;
+pub(crate) mod tasks;
@@ -0,0 +1,44 @@
+use jni_bindgen::jni;
+use jni_bindgen::objects::traits::{FromJNI, ObjectFromJNI};
+
+/// Settings exposing their fields using
+/// generated getters and setters
+#[jni(package = "com.github.markusjx.generated")]
+pub struct Settings {
+    /// The name of the settings
+    #[jni(getter, setter)]
+    name: String,
+    #[jni(getter)]
+    retries: i32,
+    #[jni(getter, setter, rename = "tags")]
+    labels: Vec<String>,
+    #[jni(setter)]
+    verbose: bool,
+    #[jni(getter, setter)]
+    timeout: Option<i64>,
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl Settings {
+    #[jni(constructor, rename = "init")]
+    fn new(name: String, retries: i32) -> Self {
+        Self {
+            name,
+            retries,
+            labels: vec![],
+            verbose: false,
+            timeout: None,
+        }
+    }
+
+    #[jni]
+    fn is_verbose(&self) -> bool {
+        self.verbose
+    }
+
+    #[jni]
+    fn retry(&mut self) -> i32 {
+        self.retries += 1;
+        self.retries
+    }
+}
@@ -0,0 +1,92 @@
+use jni_bindgen::objects::traits::{FromJNI, ObjectFromJNI};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+static DROPPED_COUNTERS: AtomicI64 = AtomicI64::new(0);
+static GLOBAL_COUNTER: OnceLock<Arc<SharedCounter>> = OnceLock::new();
+
+/// A counter which may be shared between
+/// multiple java objects and Rust code
+pub struct SharedCounter {
+    value: AtomicI64,
+}
+
+impl Drop for SharedCounter {
+    fn drop(&mut self) {
+        DROPPED_COUNTERS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl SharedCounter {
+    #[jni(constructor, rename = "init")]
+    fn new(value: i64) -> Self {
+        Self {
+            value: AtomicI64::new(value),
+        }
+    }
+
+    #[jni]
+    fn increment(&self) -> i64 {
+        self.value.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    #[jni]
+    fn get(&self) -> i64 {
+        self.value.load(Ordering::SeqCst)
+    }
+
+    #[jni]
+    fn reset(&mut self) {
+        *self.value.get_mut() = 0;
+    }
+
+    #[jni]
+    /// Get the counter shared by all callers
+    fn get_global() -> Arc<Self> {
+        GLOBAL_COUNTER
+            .get_or_init(|| Arc::new(Self::new(0)))
+            .clone()
+    }
+
+    #[jni]
+    fn share(counter: Arc<SharedCounter>) -> Arc<SharedCounter> {
+        counter
+    }
+
+    #[jni]
+    fn get_dropped_counters() -> i64 {
+        DROPPED_COUNTERS.load(Ordering::SeqCst)
+    }
+}
+
+/// A group of counters returning its
+/// counters as child objects
+pub struct CounterGroup {
+    counters: Vec<Arc<SharedCounter>>,
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl CounterGroup {
+    #[jni(constructor, rename = "init")]
+    fn new(size: i32) -> Self {
+        Self {
+            counters: (0..size).map(|_| Arc::new(SharedCounter::new(0))).collect(),
+        }
+    }
+
+    #[jni]
+    fn get_counter(&self, index: i32) -> Option<Arc<SharedCounter>> {
+        self.counters.get(index as usize).cloned()
+    }
+
+    #[jni]
+    fn get_counters(&self) -> Vec<Arc<SharedCounter>> {
+        self.counters.clone()
+    }
+
+    #[jni]
+    fn sum(&self) -> i64 {
+        self.counters.iter().map(|c| c.get()).sum()
+    }
+}
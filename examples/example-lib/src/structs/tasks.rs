@@ -0,0 +1,135 @@
+use crate::errors::StoreError;
+use crate::Point;
+use anyhow::bail;
+use jni_bindgen::objects::traits::{FromJNI, ObjectFromJNI};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+/// A future which completes after the given
+/// duration has elapsed on another thread
+struct Delay {
+    state: Arc<Mutex<(bool, Option<Waker>)>>,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let thread_state = state.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(duration);
+            let mut state = thread_state.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+
+        Self { state }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// A struct with async methods which are
+/// exposed as CompletableFutures
+pub struct Tasks {
+    prefix: String,
+}
+
+#[jni(package = "com.github.markusjx.generated", load_lib = "example_lib")]
+impl Tasks {
+    #[jni(constructor, rename = "init")]
+    fn new(prefix: String) -> Self {
+        Self { prefix }
+    }
+
+    #[jni]
+    /// Greet the given name after a short delay
+    async fn greet(&self, name: String) -> String {
+        Delay::new(Duration::from_millis(10)).await;
+        format!("{}{}", self.prefix, name)
+    }
+
+    #[jni]
+    async fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    #[jni]
+    async fn parse_long(value: String) -> anyhow::Result<i64> {
+        Delay::new(Duration::from_millis(10)).await;
+        Ok(value.trim().parse()?)
+    }
+
+    #[jni]
+    async fn fail(msg: String) -> anyhow::Result<()> {
+        bail!("{msg}")
+    }
+
+    #[jni]
+    async fn panic(msg: String) -> i32 {
+        panic!("{msg}")
+    }
+
+    #[jni]
+    async fn get_bytes(size: i32) -> Vec<u8> {
+        (0..size).map(|i| i as u8).collect()
+    }
+
+    #[jni]
+    async fn get_names(&self, names: Vec<String>) -> Vec<String> {
+        names
+            .into_iter()
+            .map(|n| format!("{}{}", self.prefix, n))
+            .collect()
+    }
+
+    #[jni]
+    async fn find(&self, value: Option<String>) -> Option<String> {
+        value.map(|v| format!("{}{}", self.prefix, v))
+    }
+
+    #[jni]
+    async fn sleep(millis: i64) {
+        Delay::new(Duration::from_millis(millis as u64)).await;
+    }
+
+    #[jni]
+    async fn create(prefix: String) -> Self {
+        Self { prefix }
+    }
+
+    #[jni]
+    /// Get the origin after a short delay, creating
+    /// the point on the thread driving the future
+    async fn origin() -> Point {
+        Delay::new(Duration::from_millis(10)).await;
+        Point { x: 0.0, y: 0.0 }
+    }
+
+    #[jni]
+    async fn lookup(key: String) -> Result<String, StoreError> {
+        Delay::new(Duration::from_millis(10)).await;
+        Err(StoreError::NotFound(key))
+    }
+
+    #[jni]
+    fn get_prefix(&self) -> String {
+        self.prefix.clone()
+    }
+}